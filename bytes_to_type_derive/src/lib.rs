@@ -0,0 +1,237 @@
+//! Derive macro for the [`bytes_to_type`](https://docs.rs/bytes_to_type) crate.
+//!
+//! This crate is not meant to be used directly. Enable the `derive` feature of `bytes_to_type`
+//! and use `#[derive(bytes_to_type::FromBytes)]` instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote};
+use syn::{parenthesized, parse_macro_input, token, Data, DeriveInput, Fields};
+
+/// Derives `FromBytes` for a `#[repr(C)]` struct and generates conversion functions for it.
+///
+/// For a struct named `MyRecord`, the derive generates four conversion functions with the
+/// visibility of the struct: `bytes_to_my_record`, `bytes_to_my_record_le`,
+/// `bytes_to_my_record_be` and `bytes_to_my_record_slice`. The endian-aware variants decode every
+/// field in the requested byte order. The other variants of `bytes_to_type!` are not generated;
+/// invoke it with a distinct name, such as `bytes_to_type!(MyRecord as records)`, to add them.
+///
+/// The struct must be `#[repr(C)]`, must derive `Clone` and `Copy`, and every field must itself
+/// implement `FromBytes`. The struct must not contain padding, which is checked at compile time:
+///
+/// ```compile_fail
+/// use bytes_to_type::FromBytes;
+///
+/// #[derive(Clone, Copy, FromBytes)]
+/// #[repr(C)]
+/// struct Padded {
+///     a: u8,
+///     b: u32,
+/// }
+/// ```
+///
+/// Fields that are not plain old data are rejected as well:
+///
+/// ```compile_fail
+/// use bytes_to_type::FromBytes;
+///
+/// #[derive(Clone, Copy, FromBytes)]
+/// #[repr(C)]
+/// struct WithReference {
+///     a: &'static u32,
+/// }
+/// ```
+#[proc_macro_derive(FromBytes)]
+pub fn derive_from_bytes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "FromBytes cannot be derived for generic structs",
+        ));
+    }
+
+    if !has_repr_c(&input)? {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "FromBytes can only be derived for #[repr(C)] structs",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "FromBytes can only be derived for structs",
+            ))
+        }
+    };
+
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            fields,
+            "FromBytes cannot be derived for structs without fields",
+        ));
+    }
+
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let bindings: Vec<_> = (0..types.len())
+        .map(|index| format_ident!("__field{}", index))
+        .collect();
+
+    let construct = match fields {
+        Fields::Named(_) => {
+            let idents = fields.iter().map(|field| &field.ident);
+            quote! { Self { #(#idents: #bindings),* } }
+        }
+        _ => quote! { Self(#(#bindings),*) },
+    };
+
+    let decode = |method: proc_macro2::TokenStream| {
+        quote! {
+            #(
+                let (#bindings, bytes) = bytes.split_at(::core::mem::size_of::<#types>());
+                let #bindings = <#types as ::bytes_to_type::FromBytes>::#method(#bindings);
+            )*
+            let _ = bytes;
+
+            #construct
+        }
+    };
+    let decode_le = decode(quote!(from_le_slice));
+    let decode_be = decode(quote!(from_be_slice));
+
+    let padding_message = format!("{} must not contain padding", name);
+    let snake = to_snake_case(&name.to_string());
+    let fn_native = format_ident!("bytes_to_{}", snake);
+    let fn_le = format_ident!("bytes_to_{}_le", snake);
+    let fn_be = format_ident!("bytes_to_{}_be", snake);
+    let fn_slice = format_ident!("bytes_to_{}_slice", snake);
+
+    Ok(quote! {
+        const _: () = {
+            fn assert_from_bytes<T: ::bytes_to_type::FromBytes>() {}
+
+            fn assert_fields() {
+                #(assert_from_bytes::<#types>();)*
+            }
+
+            assert!(
+                ::core::mem::size_of::<#name>() == 0 #(+ ::core::mem::size_of::<#types>())*,
+                #padding_message
+            );
+        };
+
+        // SAFETY: the struct is `#[repr(C)]` without padding, and made of `FromBytes` fields for
+        // which every bit pattern is valid.
+        unsafe impl ::bytes_to_type::FromBytes for #name {
+            #[inline]
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                #decode_le
+            }
+
+            #[inline]
+            fn from_be_slice(bytes: &[u8]) -> Self {
+                #decode_be
+            }
+        }
+
+        #vis fn #fn_native(
+            bytes: &[u8],
+        ) -> ::bytes_to_type::Result<::bytes_to_type::__private::Vec<#name>> {
+            ::bytes_to_type::bytes_to_vec::<#name>(bytes)
+        }
+
+        #vis fn #fn_le(
+            bytes: &[u8],
+        ) -> ::bytes_to_type::Result<::bytes_to_type::__private::Vec<#name>> {
+            ::bytes_to_type::bytes_to_vec_le::<#name>(bytes)
+        }
+
+        #vis fn #fn_be(
+            bytes: &[u8],
+        ) -> ::bytes_to_type::Result<::bytes_to_type::__private::Vec<#name>> {
+            ::bytes_to_type::bytes_to_vec_be::<#name>(bytes)
+        }
+
+        #vis fn #fn_slice(bytes: &[u8]) -> ::bytes_to_type::Result<&[#name]> {
+            ::bytes_to_type::bytes_to_slice::<#name>(bytes)
+        }
+    })
+}
+
+/// Returns whether the struct is annotated with `#[repr(C)]` or `#[repr(transparent)]`.
+fn has_repr_c(input: &DeriveInput) -> syn::Result<bool> {
+    let mut found = false;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("transparent") {
+                found = true;
+            }
+
+            // Skip the arguments of hints such as `align(8)` and `packed(2)`.
+            if meta.input.peek(token::Paren) {
+                let arguments;
+                parenthesized!(arguments in meta.input);
+                arguments.parse::<proc_macro2::TokenStream>()?;
+            }
+
+            Ok(())
+        })?;
+    }
+
+    Ok(found)
+}
+
+/// Converts a `CamelCase` type name into a `snake_case` identifier fragment.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+
+    for (index, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_names_to_snake_case() {
+        assert_eq!(to_snake_case("MyRecord"), "my_record");
+        assert_eq!(to_snake_case("Record"), "record");
+        assert_eq!(to_snake_case("HTTPHeader"), "http_header");
+        assert_eq!(to_snake_case("Vec3"), "vec3");
+    }
+}
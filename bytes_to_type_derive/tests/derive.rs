@@ -0,0 +1,102 @@
+use bytes_to_type::FromBytes;
+
+#[derive(Debug, Clone, Copy, PartialEq, FromBytes)]
+#[repr(C)]
+struct TelemetryRecord {
+    id: u32,
+    flags: u16,
+    kind: u16,
+    value: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, FromBytes)]
+#[repr(C)]
+struct Pair(u16, u16);
+
+#[derive(Debug, Clone, Copy, PartialEq, FromBytes)]
+#[repr(C, align(8))]
+struct Aligned {
+    low: u32,
+    high: u32,
+}
+
+#[test]
+fn it_decodes_records_in_both_byte_orders() {
+    let bytes = [
+        1, 0, 0, 0, 2, 0, 3, 0, 0, 0, 128, 63, //
+        0, 0, 0, 4, 0, 5, 0, 6, 64, 0, 0, 0,
+    ];
+
+    assert_eq!(
+        bytes_to_telemetry_record_le(&bytes).unwrap()[0],
+        TelemetryRecord {
+            id: 1,
+            flags: 2,
+            kind: 3,
+            value: 1.0,
+        }
+    );
+    assert_eq!(
+        bytes_to_telemetry_record_be(&bytes).unwrap()[1],
+        TelemetryRecord {
+            id: 4,
+            flags: 5,
+            kind: 6,
+            value: 2.0,
+        }
+    );
+}
+
+#[test]
+fn it_decodes_tuple_structs_in_native_byte_order() {
+    let bytes = [1, 2, 3, 4];
+    let pairs = bytes_to_pair(&bytes).unwrap();
+
+    assert_eq!(
+        pairs,
+        vec![Pair(u16::from_ne_bytes([1, 2]), u16::from_ne_bytes([3, 4]))]
+    );
+}
+
+#[test]
+fn it_returns_error_if_bytes_length_is_not_a_multiple_of_record_size() {
+    let bytes = [0; 5];
+
+    assert!(bytes_to_pair(&bytes).is_err());
+}
+
+#[test]
+fn it_swaps_every_field_of_records_in_place() {
+    let mut records = [Pair(0x0102, 0x0304)];
+    bytes_to_type::swap_bytes_in_place(&mut records);
+
+    assert_eq!(records, [Pair(0x0201, 0x0403)]);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, FromBytes)]
+#[repr(C)]
+struct PacketHeader {
+    kind: u8,
+    length: bytes_to_type::U16Be,
+    checksum: bytes_to_type::U32Le,
+}
+
+#[test]
+fn it_views_records_with_fixed_byte_order_fields_in_place() {
+    let bytes = [0xFF, 7, 0, 1, 0x04, 0x03, 0x02, 0x01];
+    let headers = bytes_to_type::bytes_to_slice::<PacketHeader>(&bytes[1..]).unwrap();
+
+    assert_eq!(headers[0].length.get(), 0x0001);
+    assert_eq!(headers[0].checksum.get(), 0x0102_0304);
+    assert_eq!(bytes_to_packet_header_be(&bytes[1..]).unwrap(), headers);
+}
+
+#[test]
+fn it_derives_for_structs_with_alignment_hints() {
+    let bytes = [0, 0, 0, 1, 0, 0, 0, 2];
+
+    assert_eq!(
+        bytes_to_aligned_be(&bytes).unwrap(),
+        vec![Aligned { low: 1, high: 2 }]
+    );
+}
@@ -0,0 +1,254 @@
+use alloc::alloc::{alloc, dealloc, handle_alloc_error, Layout};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+use crate::convert::check_length;
+use crate::{FromBytes, Result};
+
+/// A vector of `T` whose storage starts at an address aligned to `ALIGN` bytes, returned by
+/// [`bytes_to_vec_aligned`].
+///
+/// The alignment lets SIMD kernels use aligned loads on the decoded elements. The vector has a
+/// fixed length and dereferences to a slice.
+pub struct AlignedVec<T, const ALIGN: usize> {
+    ptr: NonNull<T>,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+// SAFETY: `AlignedVec` owns its elements like a `Vec` does.
+unsafe impl<T: Send, const ALIGN: usize> Send for AlignedVec<T, ALIGN> {}
+
+// SAFETY: `AlignedVec` only hands out shared references to its elements through `&self`.
+unsafe impl<T: Sync, const ALIGN: usize> Sync for AlignedVec<T, ALIGN> {}
+
+impl<T: FromBytes, const ALIGN: usize> AlignedVec<T, ALIGN> {
+    /// Returns the layout of the storage of `len` elements.
+    fn layout(len: usize) -> Layout {
+        assert!(
+            ALIGN.is_power_of_two() && ALIGN >= core::mem::align_of::<T>(),
+            "Alignment {} must be a power of two of at least {}",
+            ALIGN,
+            core::mem::align_of::<T>()
+        );
+
+        Layout::from_size_align(len * core::mem::size_of::<T>(), ALIGN)
+            .expect("Aligned vector size overflows isize")
+    }
+
+    /// Allocates storage for the elements of `bytes`, whose length must be a multiple of the size
+    /// of `T`, and fills it with `fill`, which receives a pointer to the storage.
+    fn from_bytes_with(bytes: &[u8], fill: impl FnOnce(&[u8], *mut T)) -> Self {
+        let len = bytes.len() / core::mem::size_of::<T>();
+        let layout = Self::layout(len);
+
+        let ptr = if layout.size() == 0 {
+            // A dangling pointer with the requested alignment, as no storage is needed.
+            NonNull::new(core::ptr::without_provenance_mut(ALIGN)).expect("ALIGN is nonzero")
+        } else {
+            // SAFETY: the layout has a nonzero size.
+            let ptr = unsafe { alloc(layout) } as *mut T;
+            NonNull::new(ptr).unwrap_or_else(|| handle_alloc_error(layout))
+        };
+
+        fill(bytes, ptr.as_ptr());
+
+        Self {
+            ptr,
+            len,
+            marker: PhantomData,
+        }
+    }
+
+    fn decode_with(bytes: &[u8], decode: fn(&[u8]) -> T) -> Self {
+        Self::from_bytes_with(bytes, |bytes, ptr| {
+            for (index, element) in bytes.chunks_exact(core::mem::size_of::<T>()).enumerate() {
+                // SAFETY: the storage holds one element per chunk of `bytes`.
+                unsafe { ptr.add(index).write(decode(element)) };
+            }
+        })
+    }
+}
+
+impl<T, const ALIGN: usize> Deref for AlignedVec<T, ALIGN> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        // SAFETY: `ptr` points to `len` initialized elements, or is aligned and dangling if there
+        // are none.
+        unsafe { core::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T, const ALIGN: usize> DerefMut for AlignedVec<T, ALIGN> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        // SAFETY: as in `deref`, and the elements are exclusively borrowed through `self`.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) }
+    }
+}
+
+impl<T, const ALIGN: usize> Drop for AlignedVec<T, ALIGN> {
+    fn drop(&mut self) {
+        let size = self.len * core::mem::size_of::<T>();
+
+        if size != 0 {
+            // SAFETY: the storage was allocated with this layout, which was valid at the time.
+            unsafe {
+                dealloc(
+                    self.ptr.as_ptr() as *mut u8,
+                    Layout::from_size_align_unchecked(size, ALIGN),
+                )
+            };
+        }
+    }
+}
+
+impl<T: FromBytes, const ALIGN: usize> Clone for AlignedVec<T, ALIGN> {
+    fn clone(&self) -> Self {
+        Self::from_bytes_with(
+            // SAFETY: every `FromBytes` type is plain old data without padding.
+            unsafe {
+                core::slice::from_raw_parts(
+                    self.ptr.as_ptr() as *const u8,
+                    core::mem::size_of_val(&**self),
+                )
+            },
+            copy_bytes,
+        )
+    }
+}
+
+impl<T: fmt::Debug, const ALIGN: usize> fmt::Debug for AlignedVec<T, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (**self).fmt(f)
+    }
+}
+
+impl<T: PartialEq, const ALIGN: usize> PartialEq for AlignedVec<T, ALIGN> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+/// Copies `bytes` into the storage at `ptr`.
+fn copy_bytes<T>(bytes: &[u8], ptr: *mut T) {
+    // SAFETY: the storage is exactly as long as `bytes` and doesn't overlap it, and every bit
+    // pattern is a valid value of a `FromBytes` type.
+    unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), ptr as *mut u8, bytes.len()) };
+}
+
+/// Converts a byte slice into a vector of `T` in native byte order, whose storage is aligned to
+/// `ALIGN` bytes.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_vec_aligned;
+///
+/// let values = bytes_to_vec_aligned::<f32, 32>(&[0, 0, 128, 63].repeat(8)).unwrap();
+///
+/// assert_eq!(values.as_ptr() as usize % 32, 0);
+/// assert_eq!(values.len(), 8);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+///
+/// # Panics
+///
+/// Panics if `ALIGN` is not a power of two or is smaller than the alignment of `T`.
+pub fn bytes_to_vec_aligned<T: FromBytes, const ALIGN: usize>(
+    bytes: &[u8],
+) -> Result<AlignedVec<T, ALIGN>> {
+    check_length::<T>(bytes)?;
+
+    Ok(AlignedVec::from_bytes_with(bytes, copy_bytes))
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as little-endian, whose
+/// storage is aligned to `ALIGN` bytes.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+///
+/// # Panics
+///
+/// Panics if `ALIGN` is not a power of two or is smaller than the alignment of `T`.
+pub fn bytes_to_vec_aligned_le<T: FromBytes, const ALIGN: usize>(
+    bytes: &[u8],
+) -> Result<AlignedVec<T, ALIGN>> {
+    check_length::<T>(bytes)?;
+
+    Ok(AlignedVec::decode_with(bytes, T::from_le_slice))
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as big-endian, whose storage
+/// is aligned to `ALIGN` bytes.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+///
+/// # Panics
+///
+/// Panics if `ALIGN` is not a power of two or is smaller than the alignment of `T`.
+pub fn bytes_to_vec_aligned_be<T: FromBytes, const ALIGN: usize>(
+    bytes: &[u8],
+) -> Result<AlignedVec<T, ALIGN>> {
+    check_length::<T>(bytes)?;
+
+    Ok(AlignedVec::decode_with(bytes, T::from_be_slice))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_aligns_decoded_elements() {
+        let values = bytes_to_vec_aligned_be::<u16, 64>(&[0, 1, 0, 2, 0, 3]).unwrap();
+
+        assert_eq!(values.as_ptr() as usize % 64, 0);
+        assert_eq!(&values[..], &[1, 2, 3]);
+        assert_eq!(values.clone(), values);
+
+        let mut values = bytes_to_vec_aligned_le::<u16, 64>(&[1, 0]).unwrap();
+        values[0] += 1;
+        assert_eq!(&values[..], &[2]);
+    }
+
+    #[test]
+    fn it_aligns_empty_vectors() {
+        let values = bytes_to_vec_aligned::<u64, 4096>(&[]).unwrap();
+
+        assert!(values.is_empty());
+        assert_eq!(values.as_ptr() as usize % 4096, 0);
+        assert!(values.clone().is_empty());
+    }
+
+    #[test]
+    fn it_returns_error_on_length_mismatch() {
+        assert_eq!(
+            bytes_to_vec_aligned::<u32, 16>(&[0; 5]),
+            Err(Error::LengthMismatch {
+                len: 5,
+                size: 4,
+                remainder: 1
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Alignment 2 must be a power of two of at least 4")]
+    fn it_panics_on_insufficient_alignment() {
+        let _ = bytes_to_vec_aligned::<u32, 2>(&[0; 4]);
+    }
+}
@@ -0,0 +1,119 @@
+use alloc::vec::Vec;
+use core::alloc::Allocator;
+
+use crate::convert::check_length;
+use crate::{FromBytes, Result};
+
+fn decode_in<T: FromBytes, A: Allocator>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+    alloc: A,
+) -> Vec<T, A> {
+    let mut values = Vec::with_capacity_in(bytes.len() / core::mem::size_of::<T>(), alloc);
+    values.extend(bytes.chunks_exact(core::mem::size_of::<T>()).map(decode));
+
+    values
+}
+
+/// Converts a byte slice into a vector of `T` in native byte order, allocated with `alloc`,
+/// available with the `allocator_api` feature on nightly compilers.
+///
+/// This lets large decode buffers come from an arena or a pool instead of the global allocator.
+///
+/// # Example
+///
+/// ```
+/// #![feature(allocator_api)]
+///
+/// use std::alloc::System;
+///
+/// use bytes_to_type::bytes_to_vec_in;
+///
+/// let values = bytes_to_vec_in::<u16, _>(&1u16.to_ne_bytes(), System).unwrap();
+///
+/// assert_eq!(values, [1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_vec_in<T: FromBytes, A: Allocator>(bytes: &[u8], alloc: A) -> Result<Vec<T, A>> {
+    check_length::<T>(bytes)?;
+
+    if cfg!(feature = "safe-only") {
+        return Ok(decode_in(bytes, T::from_ne_slice, alloc));
+    }
+
+    let len = bytes.len() / core::mem::size_of::<T>();
+    let mut values = Vec::with_capacity_in(len, alloc);
+
+    // SAFETY: `bytes` holds exactly `len` elements, so the copy fits in the allocated capacity and
+    // initializes all of it, and every bit pattern is a valid value of a `FromBytes` type.
+    unsafe {
+        core::ptr::copy_nonoverlapping(bytes.as_ptr(), values.as_mut_ptr() as *mut u8, bytes.len());
+        values.set_len(len);
+    }
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a vector of `T` allocated with `alloc`, decoding each element as
+/// little-endian, available with the `allocator_api` feature on nightly compilers.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_vec_le_in<T: FromBytes, A: Allocator>(bytes: &[u8], alloc: A) -> Result<Vec<T, A>> {
+    check_length::<T>(bytes)?;
+
+    Ok(decode_in(bytes, T::from_le_slice, alloc))
+}
+
+/// Converts a byte slice into a vector of `T` allocated with `alloc`, decoding each element as
+/// big-endian, available with the `allocator_api` feature on nightly compilers.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_vec_be_in<T: FromBytes, A: Allocator>(bytes: &[u8], alloc: A) -> Result<Vec<T, A>> {
+    check_length::<T>(bytes)?;
+
+    Ok(decode_in(bytes, T::from_be_slice, alloc))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::alloc::Global;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_allocates_with_the_given_allocator() {
+        let bytes = [0, 1, 0, 2];
+
+        assert_eq!(
+            bytes_to_vec_be_in::<u16, _>(&bytes, Global).unwrap(),
+            [1, 2]
+        );
+        assert_eq!(
+            bytes_to_vec_le_in::<u16, _>(&bytes, &Global).unwrap(),
+            [256, 512]
+        );
+        assert_eq!(
+            bytes_to_vec_in::<u16, _>(&bytes, Global).unwrap(),
+            [u16::from_ne_bytes([0, 1]), u16::from_ne_bytes([0, 2])]
+        );
+        assert_eq!(
+            bytes_to_vec_in::<u32, _>(&bytes[1..], Global),
+            Err(Error::LengthMismatch {
+                len: 3,
+                size: 4,
+                remainder: 3
+            })
+        );
+    }
+}
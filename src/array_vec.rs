@@ -0,0 +1,174 @@
+use arrayvec::ArrayVec;
+
+use crate::convert::check_length;
+#[cfg(feature = "safe-only")]
+use crate::into::decode_elements;
+#[cfg(not(feature = "safe-only"))]
+use crate::into::write_elements;
+use crate::{Error, FromBytes, Result};
+
+fn bytes_to_arrayvec_into_with<T: FromBytes, const CAP: usize>(
+    bytes: &[u8],
+    out: &mut ArrayVec<T, CAP>,
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<usize> {
+    check_length::<T>(bytes)?;
+
+    let count = bytes.len() / core::mem::size_of::<T>();
+
+    if count > out.remaining_capacity() {
+        return Err(Error::LimitExceeded {
+            limit: out.remaining_capacity(),
+        });
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    // SAFETY: the length was checked above, and the vector has room for `count` more elements,
+    // which are all initialized before the length is updated.
+    unsafe {
+        write_elements(bytes, out.as_mut_ptr().add(out.len()), native, decode);
+        out.set_len(out.len() + count);
+    }
+
+    #[cfg(feature = "safe-only")]
+    {
+        let _ = native;
+        out.extend(decode_elements(bytes, decode));
+    }
+
+    Ok(count)
+}
+
+/// Decodes a byte slice in native byte order and appends the elements to an [`ArrayVec`],
+/// returning the number of elements appended, available with the `arrayvec` feature.
+///
+/// # Example
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use bytes_to_type::bytes_to_arrayvec_le_into;
+///
+/// let mut header = ArrayVec::<u16, 4>::new();
+///
+/// assert_eq!(bytes_to_arrayvec_le_into(&[1, 0, 2, 0], &mut header).unwrap(), 2);
+/// assert_eq!(&header[..], &[1, 2]);
+/// assert!(bytes_to_arrayvec_le_into(&[0; 6], &mut header).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// and [`Error::LimitExceeded`] if `out` doesn't have room for all elements. `out` is left
+/// unchanged in both cases.
+pub fn bytes_to_arrayvec_into<T: FromBytes, const CAP: usize>(
+    bytes: &[u8],
+    out: &mut ArrayVec<T, CAP>,
+) -> Result<usize> {
+    bytes_to_arrayvec_into_with(bytes, out, true, T::from_ne_slice)
+}
+
+/// Decodes a byte slice as little-endian and appends the elements to an [`ArrayVec`], returning
+/// the number of elements appended, available with the `arrayvec` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_arrayvec_into`].
+pub fn bytes_to_arrayvec_le_into<T: FromBytes, const CAP: usize>(
+    bytes: &[u8],
+    out: &mut ArrayVec<T, CAP>,
+) -> Result<usize> {
+    bytes_to_arrayvec_into_with(bytes, out, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Decodes a byte slice as big-endian and appends the elements to an [`ArrayVec`], returning the
+/// number of elements appended, available with the `arrayvec` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_arrayvec_into`].
+pub fn bytes_to_arrayvec_be_into<T: FromBytes, const CAP: usize>(
+    bytes: &[u8],
+    out: &mut ArrayVec<T, CAP>,
+) -> Result<usize> {
+    bytes_to_arrayvec_into_with(bytes, out, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+/// Converts a byte slice into a new [`ArrayVec`] of `T` in native byte order, available with the
+/// `arrayvec` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// and [`Error::LimitExceeded`] if it holds more than `CAP` elements.
+pub fn bytes_to_arrayvec<T: FromBytes, const CAP: usize>(bytes: &[u8]) -> Result<ArrayVec<T, CAP>> {
+    let mut values = ArrayVec::new();
+    bytes_to_arrayvec_into(bytes, &mut values)?;
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a new [`ArrayVec`] of `T`, decoding each element as little-endian,
+/// available with the `arrayvec` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_arrayvec`].
+pub fn bytes_to_arrayvec_le<T: FromBytes, const CAP: usize>(
+    bytes: &[u8],
+) -> Result<ArrayVec<T, CAP>> {
+    let mut values = ArrayVec::new();
+    bytes_to_arrayvec_le_into(bytes, &mut values)?;
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a new [`ArrayVec`] of `T`, decoding each element as big-endian,
+/// available with the `arrayvec` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_arrayvec`].
+pub fn bytes_to_arrayvec_be<T: FromBytes, const CAP: usize>(
+    bytes: &[u8],
+) -> Result<ArrayVec<T, CAP>> {
+    let mut values = ArrayVec::new();
+    bytes_to_arrayvec_be_into(bytes, &mut values)?;
+
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_appends_to_arrayvecs() {
+        let mut values = ArrayVec::<u32, 3>::new();
+        values.push(7);
+
+        assert_eq!(
+            bytes_to_arrayvec_be_into(&[0, 0, 0, 1, 0, 0, 0, 2], &mut values).unwrap(),
+            2
+        );
+        assert_eq!(&values[..], &[7, 1, 2]);
+        assert_eq!(
+            bytes_to_arrayvec_into(&[0; 4], &mut values),
+            Err(Error::LimitExceeded { limit: 0 })
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_input_exceeds_capacity() {
+        assert_eq!(
+            bytes_to_arrayvec_le::<u16, 2>(&[1, 0, 2, 0])
+                .unwrap()
+                .as_slice(),
+            &[1, 2]
+        );
+        assert_eq!(
+            bytes_to_arrayvec::<u16, 1>(&[1, 0, 2, 0]),
+            Err(Error::LimitExceeded { limit: 1 })
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use arrow_array::{ArrowPrimitiveType, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
+
+use crate::convert::{check_length, is_aligned};
+use crate::{bytes_to_vec_be, bytes_to_vec_le, FromBytes, Result};
+
+/// Reinterprets an Arrow [`Buffer`] as a [`ScalarBuffer`] of `T` in native byte order, available
+/// with the `arrow` feature.
+///
+/// If the buffer is aligned for `T`, which is always the case for buffers allocated by Arrow, no
+/// bytes are copied, so buffers backed by memory-mapped IPC files stay shared. Misaligned buffers
+/// are copied into a new aligned allocation.
+///
+/// # Example
+///
+/// ```
+/// use arrow_buffer::Buffer;
+/// use bytes_to_type::buffer_to_scalar_buffer;
+///
+/// let buffer = Buffer::from_vec(1u32.to_ne_bytes().repeat(3));
+/// let values = buffer_to_scalar_buffer::<u32>(buffer).unwrap();
+///
+/// assert_eq!(&values[..], &[1, 1, 1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of the buffer
+/// is not a multiple of the size of `T`.
+pub fn buffer_to_scalar_buffer<T: FromBytes + ArrowNativeType>(
+    buffer: Buffer,
+) -> Result<ScalarBuffer<T>> {
+    check_length::<T>(&buffer)?;
+
+    if is_aligned::<T>(&buffer) {
+        return Ok(ScalarBuffer::from(buffer));
+    }
+
+    Ok(ScalarBuffer::from(Buffer::from_slice_ref(&buffer[..])))
+}
+
+/// Converts a byte slice into a [`ScalarBuffer`] of `T` in native byte order, available with the
+/// `arrow` feature.
+///
+/// The bytes are copied straight into an aligned Arrow allocation, without an intermediate
+/// [`Vec`](alloc::vec::Vec).
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_scalar_buffer<T: FromBytes + ArrowNativeType>(
+    bytes: &[u8],
+) -> Result<ScalarBuffer<T>> {
+    check_length::<T>(bytes)?;
+
+    Ok(ScalarBuffer::from(Buffer::from_slice_ref(bytes)))
+}
+
+/// Converts a byte slice into a [`ScalarBuffer`] of `T`, decoding each element as little-endian,
+/// available with the `arrow` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_scalar_buffer_le<T: FromBytes + ArrowNativeType>(
+    bytes: &[u8],
+) -> Result<ScalarBuffer<T>> {
+    bytes_to_vec_le(bytes).map(ScalarBuffer::from)
+}
+
+/// Converts a byte slice into a [`ScalarBuffer`] of `T`, decoding each element as big-endian,
+/// available with the `arrow` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_scalar_buffer_be<T: FromBytes + ArrowNativeType>(
+    bytes: &[u8],
+) -> Result<ScalarBuffer<T>> {
+    bytes_to_vec_be(bytes).map(ScalarBuffer::from)
+}
+
+/// Converts a byte slice into a [`PrimitiveArray`] without nulls, decoding elements in native
+/// byte order, available with the `arrow` feature.
+///
+/// # Example
+///
+/// ```
+/// use arrow_array::types::Float32Type;
+/// use arrow_array::Array;
+/// use bytes_to_type::bytes_to_primitive_array;
+///
+/// let bytes = [1.5f32.to_ne_bytes(), 2.5f32.to_ne_bytes()].concat();
+/// let array = bytes_to_primitive_array::<Float32Type>(&bytes).unwrap();
+///
+/// assert_eq!(array.len(), 2);
+/// assert_eq!(array.value(1), 2.5);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of the native type of `A`.
+pub fn bytes_to_primitive_array<A>(bytes: &[u8]) -> Result<PrimitiveArray<A>>
+where
+    A: ArrowPrimitiveType,
+    A::Native: FromBytes,
+{
+    bytes_to_scalar_buffer(bytes).map(|values| PrimitiveArray::new(values, None))
+}
+
+/// Converts a byte slice into a [`PrimitiveArray`] without nulls, decoding elements as
+/// little-endian, available with the `arrow` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of the native type of `A`.
+pub fn bytes_to_primitive_array_le<A>(bytes: &[u8]) -> Result<PrimitiveArray<A>>
+where
+    A: ArrowPrimitiveType,
+    A::Native: FromBytes,
+{
+    bytes_to_scalar_buffer_le(bytes).map(|values| PrimitiveArray::new(values, None))
+}
+
+/// Converts a byte slice into a [`PrimitiveArray`] without nulls, decoding elements as
+/// big-endian, available with the `arrow` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of the native type of `A`.
+pub fn bytes_to_primitive_array_be<A>(bytes: &[u8]) -> Result<PrimitiveArray<A>>
+where
+    A: ArrowPrimitiveType,
+    A::Native: FromBytes,
+{
+    bytes_to_scalar_buffer_be(bytes).map(|values| PrimitiveArray::new(values, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::types::{Int16Type, UInt32Type};
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_shares_aligned_buffers() {
+        let buffer = Buffer::from_vec(vec![7u64, 8]);
+        let pointer = buffer.as_ptr();
+        let values = buffer_to_scalar_buffer::<u32>(buffer).unwrap();
+
+        assert_eq!(values.inner().as_ptr(), pointer);
+        assert_eq!(values.len(), 4);
+    }
+
+    #[test]
+    fn it_copies_misaligned_buffers() {
+        let buffer = Buffer::from_vec(vec![0u8; 9]).slice(1);
+        let values = buffer_to_scalar_buffer::<u64>(buffer).unwrap();
+
+        assert_eq!(&values[..], &[0]);
+        assert_eq!(
+            buffer_to_scalar_buffer::<u32>(Buffer::from_vec(vec![0u8; 6])),
+            Err(Error::LengthMismatch {
+                len: 6,
+                size: 4,
+                remainder: 2
+            })
+        );
+    }
+
+    #[test]
+    fn it_converts_bytes_to_primitive_arrays() {
+        let bytes = [0, 0, 0, 1, 0, 0, 1, 0];
+
+        let array = bytes_to_primitive_array_be::<UInt32Type>(&bytes).unwrap();
+        assert_eq!(array.values(), &[1, 256]);
+
+        let array = bytes_to_primitive_array_le::<Int16Type>(&bytes).unwrap();
+        assert_eq!(array.values(), &[0, 256, 0, 1]);
+
+        assert!(bytes_to_primitive_array::<UInt32Type>(&bytes[1..]).is_err());
+    }
+}
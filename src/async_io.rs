@@ -0,0 +1,114 @@
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, FromBytes, Result};
+
+async fn read_with<T: FromBytes, R: AsyncRead + Unpin>(
+    mut reader: R,
+    count: usize,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> io::Result<Vec<T>> {
+    let len = count
+        .checked_mul(core::mem::size_of::<T>())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Element count is too large"))?;
+    let mut bytes = vec![0; len];
+
+    reader.read_exact(&mut bytes).await?;
+
+    convert(&bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+/// Reads exactly `count` elements of `T` in native byte order from an async reader, available
+/// with the `tokio` feature.
+///
+/// # Example
+///
+/// ```
+/// # async fn example() -> std::io::Result<()> {
+/// use bytes_to_type::read_elements_be;
+///
+/// let stream: &[u8] = &[0, 1, 0, 2, 0, 3];
+/// let values = read_elements_be::<u16, _>(stream, 2).await?;
+///
+/// assert_eq!(values, vec![1, 2]);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// Returns any I/O error produced by `reader`, including an error of kind
+/// [`io::ErrorKind::UnexpectedEof`] if the reader ends before `count` elements were read.
+pub async fn read_elements<T: FromBytes, R: AsyncRead + Unpin>(
+    reader: R,
+    count: usize,
+) -> io::Result<Vec<T>> {
+    read_with(reader, count, bytes_to_vec::<T>).await
+}
+
+/// Reads exactly `count` elements of `T` from an async reader, decoding each element as
+/// little-endian, available with the `tokio` feature.
+///
+/// # Errors
+///
+/// See [`read_elements`].
+pub async fn read_elements_le<T: FromBytes, R: AsyncRead + Unpin>(
+    reader: R,
+    count: usize,
+) -> io::Result<Vec<T>> {
+    read_with(reader, count, bytes_to_vec_le::<T>).await
+}
+
+/// Reads exactly `count` elements of `T` from an async reader, decoding each element as
+/// big-endian, available with the `tokio` feature.
+///
+/// # Errors
+///
+/// See [`read_elements`].
+pub async fn read_elements_be<T: FromBytes, R: AsyncRead + Unpin>(
+    reader: R,
+    count: usize,
+) -> io::Result<Vec<T>> {
+    read_with(reader, count, bytes_to_vec_be::<T>).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use super::*;
+
+    /// Drives a future whose I/O is always ready to completion.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut context = Context::from_waker(Waker::noop());
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn it_reads_the_requested_number_of_elements() {
+        let mut stream: &[u8] = &[1, 0, 2, 0, 3, 0];
+
+        assert_eq!(
+            block_on(read_elements_le::<u16, _>(&mut stream, 2)).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(stream, &[3, 0]);
+    }
+
+    #[test]
+    fn it_returns_error_if_the_reader_ends_early() {
+        let stream: &[u8] = &[1, 0, 2];
+        let error = block_on(read_elements::<u16, _>(stream, 2)).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
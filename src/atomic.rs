@@ -0,0 +1,129 @@
+use alloc::vec::Vec;
+#[cfg(target_has_atomic = "16")]
+use core::sync::atomic::{AtomicI16, AtomicU16};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::{AtomicI32, AtomicU32};
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::{AtomicI64, AtomicU64};
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicI8, AtomicU8};
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicIsize, AtomicUsize};
+
+use crate::from_bytes::sealed;
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, FromBytes, Result};
+
+/// An atomic integer type, such as [`AtomicU32`], that can be decoded from raw bytes.
+///
+/// Atomics are not `Copy`, so they cannot implement [`FromBytes`]. Instead, they are decoded as
+/// their underlying integer type and wrapped afterwards. This trait is sealed and implemented for
+/// all atomic integer types of `core::sync::atomic` supported by the target.
+pub trait AtomicFromBytes: sealed::Sealed {
+    /// The underlying integer type.
+    #[doc(hidden)]
+    type Int: FromBytes;
+
+    /// Wraps the underlying integer.
+    #[doc(hidden)]
+    fn new(value: Self::Int) -> Self;
+}
+
+macro_rules! impl_atomic_from_bytes {
+    ($($width:literal: $($type:ty => $int:ty),*;)*) => {
+        $($(
+            #[cfg(target_has_atomic = $width)]
+            impl sealed::Sealed for $type {}
+
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicFromBytes for $type {
+                type Int = $int;
+
+                #[inline]
+                fn new(value: $int) -> Self {
+                    <$type>::new(value)
+                }
+            }
+        )*)*
+    };
+}
+
+impl_atomic_from_bytes! {
+    "8": AtomicU8 => u8, AtomicI8 => i8;
+    "16": AtomicU16 => u16, AtomicI16 => i16;
+    "32": AtomicU32 => u32, AtomicI32 => i32;
+    "64": AtomicU64 => u64, AtomicI64 => i64;
+    "ptr": AtomicUsize => usize, AtomicIsize => isize;
+}
+
+fn bytes_to_atomic_with<T: AtomicFromBytes>(
+    bytes: &[u8],
+    convert: fn(&[u8]) -> Result<Vec<T::Int>>,
+) -> Result<Vec<T>> {
+    // Atomics have the size of their integer type, so collecting reuses the allocation.
+    Ok(convert(bytes)?.into_iter().map(T::new).collect())
+}
+
+/// Converts a byte slice into a vector of an atomic integer type in native byte order.
+///
+/// This sets up shared counters or flags, such as a table of reference counts loaded from disk,
+/// which are then updated concurrently.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// use bytes_to_type::bytes_to_atomic_le;
+///
+/// let counters = bytes_to_atomic_le::<AtomicU32>(&[1, 0, 0, 0, 2, 0, 0, 0]).unwrap();
+/// counters[1].fetch_add(1, Ordering::Relaxed);
+///
+/// assert_eq!(counters[1].load(Ordering::Relaxed), 3);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_atomic<T: AtomicFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_atomic_with(bytes, bytes_to_vec::<T::Int>)
+}
+
+/// Converts a byte slice into a vector of an atomic integer type, decoding each element as
+/// little-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_atomic`].
+pub fn bytes_to_atomic_le<T: AtomicFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_atomic_with(bytes, bytes_to_vec_le::<T::Int>)
+}
+
+/// Converts a byte slice into a vector of an atomic integer type, decoding each element as
+/// big-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_atomic`].
+pub fn bytes_to_atomic_be<T: AtomicFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_atomic_with(bytes, bytes_to_vec_be::<T::Int>)
+}
+
+#[cfg(all(test, target_has_atomic = "64"))]
+mod tests {
+    use core::sync::atomic::Ordering;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_converts_bytes_to_atomics() {
+        let values = bytes_to_atomic_be::<AtomicI64>(&[255; 8]).unwrap();
+
+        assert_eq!(values[0].load(Ordering::Relaxed), -1);
+        assert_eq!(
+            bytes_to_atomic::<AtomicU16>(&[1]).unwrap_err(),
+            Error::length_mismatch(1, 2)
+        );
+    }
+}
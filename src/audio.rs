@@ -0,0 +1,206 @@
+//! Decoding of PCM audio into normalized `f32` samples.
+//!
+//! Audio APIs and DSP code usually work with `f32` samples in the range `[-1.0, 1.0]`, while files
+//! and devices exchange integer PCM data in a variety of widths. The functions of this module decode
+//! and normalize the samples in a single pass, optionally splitting interleaved channels.
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::audio::{self, SampleFormat};
+//!
+//! // Two stereo frames of 16-bit little-endian samples.
+//! let bytes = [0x00, 0x40, 0x00, 0xC0, 0xFF, 0x7F, 0x00, 0x80];
+//! let channels = audio::decode_channels(&bytes, SampleFormat::I16Le, 2).unwrap();
+//!
+//! assert_eq!(channels[0], vec![0.5, 32767.0 / 32768.0]);
+//! assert_eq!(channels[1], vec![-0.5, -1.0]);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::odd_width::{extend_i24, u24_be, u24_le};
+use crate::{Error, Result};
+
+/// The encoding of a PCM sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum SampleFormat {
+    /// Unsigned 8-bit samples in offset binary, where 128 is silence.
+    U8,
+    /// Signed little-endian 16-bit samples.
+    I16Le,
+    /// Signed big-endian 16-bit samples.
+    I16Be,
+    /// Signed little-endian 24-bit samples packed in 3 bytes.
+    I24Le,
+    /// Signed big-endian 24-bit samples packed in 3 bytes.
+    I24Be,
+    /// Signed little-endian 32-bit samples.
+    I32Le,
+    /// Signed big-endian 32-bit samples.
+    I32Be,
+    /// Little-endian `f32` samples, which are passed through unchanged.
+    F32Le,
+    /// Big-endian `f32` samples, which are passed through unchanged.
+    F32Be,
+}
+
+impl SampleFormat {
+    /// Returns the number of bytes taken by a sample.
+    pub fn sample_size(self) -> usize {
+        match self {
+            SampleFormat::U8 => 1,
+            SampleFormat::I16Le | SampleFormat::I16Be => 2,
+            SampleFormat::I24Le | SampleFormat::I24Be => 3,
+            SampleFormat::I32Le
+            | SampleFormat::I32Be
+            | SampleFormat::F32Le
+            | SampleFormat::F32Be => 4,
+        }
+    }
+
+    /// Returns the function decoding a sample of this format, given exactly its bytes.
+    fn decoder(self) -> fn(&[u8]) -> f32 {
+        match self {
+            SampleFormat::U8 => |bytes| (f32::from(bytes[0]) - 128.0) / 128.0,
+            SampleFormat::I16Le => {
+                |bytes| f32::from(i16::from_le_bytes([bytes[0], bytes[1]])) / 32768.0
+            }
+            SampleFormat::I16Be => {
+                |bytes| f32::from(i16::from_be_bytes([bytes[0], bytes[1]])) / 32768.0
+            }
+            SampleFormat::I24Le => {
+                |bytes| extend_i24(u24_le([bytes[0], bytes[1], bytes[2]])) as f32 / 8_388_608.0
+            }
+            SampleFormat::I24Be => {
+                |bytes| extend_i24(u24_be([bytes[0], bytes[1], bytes[2]])) as f32 / 8_388_608.0
+            }
+            SampleFormat::I32Le => |bytes| i32::from_le_bytes(word(bytes)) as f32 / 2_147_483_648.0,
+            SampleFormat::I32Be => |bytes| i32::from_be_bytes(word(bytes)) as f32 / 2_147_483_648.0,
+            SampleFormat::F32Le => |bytes| f32::from_le_bytes(word(bytes)),
+            SampleFormat::F32Be => |bytes| f32::from_be_bytes(word(bytes)),
+        }
+    }
+}
+
+fn word(bytes: &[u8]) -> [u8; 4] {
+    [bytes[0], bytes[1], bytes[2], bytes[3]]
+}
+
+/// Decodes PCM samples of the given format into `f32` samples normalized to `[-1.0, 1.0]`.
+///
+/// Integer samples are divided by the magnitude of their most negative value, so the most negative
+/// sample maps to exactly `-1.0`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::audio::{self, SampleFormat};
+///
+/// assert_eq!(
+///     audio::decode_samples(&[0, 128, 192], SampleFormat::U8).unwrap(),
+///     vec![-1.0, 0.0, 0.5]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the sample
+/// size.
+pub fn decode_samples(bytes: &[u8], format: SampleFormat) -> Result<Vec<f32>> {
+    let size = format.sample_size();
+
+    if !bytes.len().is_multiple_of(size) {
+        return Err(Error::length_mismatch(bytes.len(), size));
+    }
+
+    Ok(bytes.chunks_exact(size).map(format.decoder()).collect())
+}
+
+/// Decodes interleaved PCM frames of `channels` samples of the given format into one vector of
+/// normalized `f32` samples per channel.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the frame
+/// size.
+///
+/// # Panics
+///
+/// Panics if `channels` is zero.
+pub fn decode_channels(
+    bytes: &[u8],
+    format: SampleFormat,
+    channels: usize,
+) -> Result<Vec<Vec<f32>>> {
+    assert!(channels != 0, "Channel count must be nonzero");
+
+    let size = format.sample_size();
+    let frame = channels * size;
+
+    if !bytes.len().is_multiple_of(frame) {
+        return Err(Error::length_mismatch(bytes.len(), frame));
+    }
+
+    let decode = format.decoder();
+    let mut output = alloc::vec![Vec::with_capacity(bytes.len() / frame); channels];
+
+    for frame in bytes.chunks_exact(frame) {
+        for (channel, sample) in output.iter_mut().zip(frame.chunks_exact(size)) {
+            channel.push(decode(sample));
+        }
+    }
+
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_normalizes_integer_samples() {
+        assert_eq!(
+            decode_samples(&[0x80, 0x00, 0x40, 0x00], SampleFormat::I16Be).unwrap(),
+            vec![-1.0, 0.5]
+        );
+        assert_eq!(
+            decode_samples(&[0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F], SampleFormat::I24Le).unwrap(),
+            vec![-1.0, 8_388_607.0 / 8_388_608.0]
+        );
+        assert_eq!(
+            decode_samples(&[0, 0, 0, 0x80], SampleFormat::I32Le).unwrap(),
+            vec![-1.0]
+        );
+        assert_eq!(
+            decode_samples(&0.25f32.to_be_bytes(), SampleFormat::F32Be).unwrap(),
+            vec![0.25]
+        );
+    }
+
+    #[test]
+    fn it_deinterleaves_channels() {
+        let bytes = [128, 0, 255, 128, 64, 0];
+        let channels = decode_channels(&bytes, SampleFormat::U8, 3).unwrap();
+
+        assert_eq!(
+            channels,
+            vec![vec![0.0, 0.0], vec![-1.0, -0.5], vec![127.0 / 128.0, -1.0]]
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_incomplete_frame() {
+        assert_eq!(
+            decode_channels(&[0; 10], SampleFormat::I16Le, 2),
+            Err(Error::LengthMismatch {
+                len: 10,
+                size: 4,
+                remainder: 2
+            })
+        );
+    }
+}
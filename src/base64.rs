@@ -0,0 +1,110 @@
+use alloc::vec::Vec;
+
+use base64::{DecodeError, Engine};
+
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, Error, FromBytes, Result};
+
+fn base64_to_vec_with<T>(
+    s: &str,
+    engine: &impl Engine,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    let bytes = engine.decode(s).map_err(|error| {
+        let offset = match error {
+            DecodeError::InvalidByte(offset, _) | DecodeError::InvalidLastSymbol(offset, _) => {
+                offset
+            }
+            DecodeError::InvalidLength(_) | DecodeError::InvalidPadding => s.len(),
+        };
+
+        Error::InvalidBase64 { offset }
+    })?;
+
+    convert(&bytes)
+}
+
+/// Decodes a base64 string with the given engine into a vector of `T` in native byte order.
+///
+/// The engine selects the alphabet and padding rules, such as
+/// [`STANDARD`](base64::engine::general_purpose::STANDARD) or
+/// [`URL_SAFE_NO_PAD`](base64::engine::general_purpose::URL_SAFE_NO_PAD). This function is
+/// available with the `base64` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidBase64`] if `s` is not valid base64 for `engine`, or
+/// [`Error::LengthMismatch`] if the number of decoded bytes is not a multiple of the size of `T`.
+pub fn base64_to_vec<T: FromBytes>(s: &str, engine: &impl Engine) -> Result<Vec<T>> {
+    base64_to_vec_with(s, engine, bytes_to_vec::<T>)
+}
+
+/// Decodes a base64 string with the given engine into a vector of `T`, decoding each element as
+/// little-endian.
+///
+/// This is how embedding vectors and other packed float arrays are usually carried in JSON
+/// payloads.
+///
+/// # Example
+///
+/// ```
+/// use base64::engine::general_purpose::STANDARD;
+/// use bytes_to_type::base64_to_vec_le;
+///
+/// let embedding = base64_to_vec_le::<f32>("AACAPwAAAEA=", &STANDARD).unwrap();
+///
+/// assert_eq!(embedding, vec![1.0, 2.0]);
+/// ```
+///
+/// # Errors
+///
+/// See [`base64_to_vec`].
+pub fn base64_to_vec_le<T: FromBytes>(s: &str, engine: &impl Engine) -> Result<Vec<T>> {
+    base64_to_vec_with(s, engine, bytes_to_vec_le::<T>)
+}
+
+/// Decodes a base64 string with the given engine into a vector of `T`, decoding each element as
+/// big-endian.
+///
+/// # Errors
+///
+/// See [`base64_to_vec`].
+pub fn base64_to_vec_be<T: FromBytes>(s: &str, engine: &impl Engine) -> Result<Vec<T>> {
+    base64_to_vec_with(s, engine, bytes_to_vec_be::<T>)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_base64_into_elements() {
+        assert_eq!(
+            base64_to_vec_be::<u16>("AAH__w", &URL_SAFE_NO_PAD).unwrap(),
+            vec![1, 0xFFFF]
+        );
+        assert_eq!(
+            base64_to_vec::<u8>("", &STANDARD).unwrap(),
+            Vec::<u8>::new()
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_invalid_base64() {
+        assert_eq!(
+            base64_to_vec_le::<u8>("AA*A", &STANDARD),
+            Err(Error::InvalidBase64 { offset: 2 })
+        );
+        assert_eq!(
+            base64_to_vec_le::<u8>("AAA", &STANDARD),
+            Err(Error::InvalidBase64 { offset: 3 })
+        );
+        assert_eq!(
+            base64_to_vec_le::<u32>("AAAA", &STANDARD),
+            Err(Error::length_mismatch(3, 4))
+        );
+    }
+}
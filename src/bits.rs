@@ -0,0 +1,143 @@
+//! Decoding of data packed at the bit level.
+//!
+//! Packed sensor and image formats store values in fields that don't align with byte boundaries,
+//! such as 12-bit samples packed back to back. The functions of this module read such bit streams
+//! in either [`BitOrder`].
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::bits::{self, BitOrder};
+//!
+//! // Two 12-bit samples, 0xABC and 0x123, packed into 3 bytes.
+//! let bytes = [0xAB, 0xC1, 0x23];
+//!
+//! assert_eq!(bits::unpack_u16(&bytes, 12, BitOrder::MsbFirst), vec![0xABC, 0x123]);
+//! ```
+
+use alloc::vec::Vec;
+
+/// The order in which the bits of each byte are read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// The most significant bit of each byte comes first, and fields are read most significant
+    /// bit first, as in most network protocols and image formats.
+    MsbFirst,
+    /// The least significant bit of each byte comes first, and fields are read least significant
+    /// bit first, as in DEFLATE streams.
+    LsbFirst,
+}
+
+/// Expands every byte of `bytes` into 8 `bool`s, one per bit, in the given order.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bits::{self, BitOrder};
+///
+/// assert_eq!(
+///     bits::bytes_to_bools(&[0b1000_0001], BitOrder::MsbFirst),
+///     vec![true, false, false, false, false, false, false, true]
+/// );
+/// ```
+pub fn bytes_to_bools(bytes: &[u8], order: BitOrder) -> Vec<bool> {
+    bytes
+        .iter()
+        .flat_map(|&byte| {
+            (0..8).map(move |index| match order {
+                BitOrder::MsbFirst => byte & (0x80 >> index) != 0,
+                BitOrder::LsbFirst => byte & (1 << index) != 0,
+            })
+        })
+        .collect()
+}
+
+/// Extracts consecutive `width`-bit fields from `bytes` into a vector of `u16`.
+///
+/// Bits left over at the end of the input that are not enough for a whole field are ignored, as
+/// they are usually padding up to the next byte boundary.
+///
+/// # Panics
+///
+/// Panics if `width` is 0 or greater than 16.
+pub fn unpack_u16(bytes: &[u8], width: u32, order: BitOrder) -> Vec<u16> {
+    assert!(
+        (1..=16).contains(&width),
+        "Bit width must be between 1 and 16, got {}",
+        width
+    );
+
+    let mask = (1u32 << width) - 1;
+    let mut fields = Vec::with_capacity(bytes.len() * 8 / width as usize);
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+
+    for &byte in bytes {
+        match order {
+            BitOrder::MsbFirst => buffer = (buffer << 8) | u32::from(byte),
+            BitOrder::LsbFirst => buffer |= u32::from(byte) << buffered,
+        }
+        buffered += 8;
+
+        while buffered >= width {
+            buffered -= width;
+
+            match order {
+                BitOrder::MsbFirst => {
+                    fields.push(((buffer >> buffered) & mask) as u16);
+                    buffer &= (1 << buffered) - 1;
+                }
+                BitOrder::LsbFirst => {
+                    fields.push((buffer & mask) as u16);
+                    buffer >>= width;
+                }
+            }
+        }
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_expands_bytes_into_bits() {
+        assert_eq!(
+            bytes_to_bools(&[0b0000_0110], BitOrder::LsbFirst),
+            vec![false, true, true, false, false, false, false, false]
+        );
+        assert_eq!(bytes_to_bools(&[0xFF, 0], BitOrder::MsbFirst).len(), 16);
+    }
+
+    #[test]
+    fn it_unpacks_bit_fields_in_both_orders() {
+        let bytes = [0xAB, 0xC1, 0x23];
+
+        assert_eq!(
+            unpack_u16(&bytes, 12, BitOrder::MsbFirst),
+            vec![0xABC, 0x123]
+        );
+        assert_eq!(
+            unpack_u16(&bytes, 12, BitOrder::LsbFirst),
+            vec![0x1AB, 0x23C]
+        );
+        assert_eq!(
+            unpack_u16(&[0b1110_0100], 3, BitOrder::MsbFirst),
+            vec![0b111, 0b001]
+        );
+        assert_eq!(
+            unpack_u16(&[0x34, 0x12], 16, BitOrder::LsbFirst),
+            vec![0x1234]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Bit width must be between 1 and 16")]
+    fn it_panics_on_invalid_bit_width() {
+        unpack_u16(&[0], 17, BitOrder::MsbFirst);
+    }
+}
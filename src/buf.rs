@@ -0,0 +1,118 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use bytes::Buf;
+
+use crate::{Error, FromBytes, Result};
+
+fn buf_to_vec_with<T: FromBytes, B: Buf>(mut buf: B, decode: fn(&[u8]) -> T) -> Result<Vec<T>> {
+    let size = core::mem::size_of::<T>();
+
+    if !buf.remaining().is_multiple_of(size) {
+        return Err(Error::length_mismatch(buf.remaining(), size));
+    }
+
+    let mut values = Vec::with_capacity(buf.remaining() / size);
+    let mut scratch = vec![0; size];
+
+    while buf.has_remaining() {
+        let chunk = buf.chunk();
+
+        if chunk.len() >= size {
+            let whole = chunk.len() - chunk.len() % size;
+
+            values.extend(chunk[..whole].chunks_exact(size).map(decode));
+            buf.advance(whole);
+        } else {
+            // The element straddles a chunk boundary, so stitch it together from several chunks.
+            buf.copy_to_slice(&mut scratch);
+            values.push(decode(&scratch));
+        }
+    }
+
+    Ok(values)
+}
+
+/// Converts the contents of a [`bytes::Buf`] into a vector of `T` in native byte order, available
+/// with the `bytes` feature.
+///
+/// The buffer may be made of several discontiguous chunks, such as a chain of [`bytes::Bytes`].
+/// Elements straddling a chunk boundary are stitched together before being decoded.
+///
+/// # Example
+///
+/// ```
+/// use bytes::Buf;
+/// use bytes_to_type::buf_to_vec_be;
+///
+/// let buf = (&[0, 1, 0][..]).chain(&[2][..]);
+///
+/// assert_eq!(buf_to_vec_be::<u16>(buf).unwrap(), vec![1, 2]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the remaining length of `buf` is not a multiple of the
+/// size of `T`.
+pub fn buf_to_vec<T: FromBytes>(buf: impl Buf) -> Result<Vec<T>> {
+    buf_to_vec_with(buf, T::from_ne_slice)
+}
+
+/// Converts the contents of a [`bytes::Buf`] into a vector of `T`, decoding each element as
+/// little-endian, available with the `bytes` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the remaining length of `buf` is not a multiple of the
+/// size of `T`.
+pub fn buf_to_vec_le<T: FromBytes>(buf: impl Buf) -> Result<Vec<T>> {
+    buf_to_vec_with(buf, T::from_le_slice)
+}
+
+/// Converts the contents of a [`bytes::Buf`] into a vector of `T`, decoding each element as
+/// big-endian, available with the `bytes` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the remaining length of `buf` is not a multiple of the
+/// size of `T`.
+pub fn buf_to_vec_be<T: FromBytes>(buf: impl Buf) -> Result<Vec<T>> {
+    buf_to_vec_with(buf, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_stitches_elements_across_chunks() {
+        let buf = (&[1, 0, 0][..])
+            .chain(&[0][..])
+            .chain(&[2, 0][..])
+            .chain(&[0, 0, 3, 0, 0, 0][..]);
+
+        assert_eq!(buf_to_vec_le::<u32>(buf).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn it_matches_contiguous_conversion() {
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+        let buf = (&bytes[..3]).chain(&bytes[3..]);
+
+        assert_eq!(
+            buf_to_vec::<u16>(buf).unwrap(),
+            crate::bytes_to_vec::<u16>(&bytes).unwrap()
+        );
+        assert_eq!(
+            buf_to_vec_be::<u16>(&bytes[..]).unwrap(),
+            crate::bytes_to_vec_be::<u16>(&bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_type_size() {
+        let buf = (&[1, 2][..]).chain(&[3][..]);
+
+        assert_eq!(buf_to_vec::<u16>(buf), Err(Error::length_mismatch(3, 2)));
+    }
+}
@@ -0,0 +1,112 @@
+use alloc::vec::Vec;
+
+use num_traits::{NumCast, ToPrimitive};
+
+use crate::convert::check_length;
+use crate::{FromBytes, ValidationError};
+
+fn bytes_to_vec_cast_with<T: FromBytes + ToPrimitive, U: NumCast>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<U>, ValidationError<T>> {
+    check_length::<T>(bytes)?;
+
+    bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        .enumerate()
+        .map(|(index, chunk)| {
+            let value = decode(chunk);
+
+            U::from(value).ok_or(ValidationError::Rejected { index, value })
+        })
+        .collect()
+}
+
+/// Converts a byte slice of `T` values in native byte order into a vector of `U`, casting each
+/// element with [`NumCast`], available with the `num-traits` feature.
+///
+/// Decoding and promoting in one pass avoids an intermediate `Vec<T>`, as when decoding integer
+/// samples straight into `f64` for signal processing. `T` can be any numeric type implementing
+/// [`ToPrimitive`], so functions generic over `T: num_traits::PrimInt + FromBytes` or
+/// `T: num_traits::Float + FromBytes` can call this directly.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_le_cast, FromBytes, ValidationError};
+/// use num_traits::PrimInt;
+///
+/// fn normalize<T: PrimInt + FromBytes>(bytes: &[u8]) -> Option<Vec<f64>> {
+///     let max = T::max_value().to_f64()?;
+///     let values = bytes_to_vec_le_cast::<T, f64>(bytes).ok()?;
+///
+///     Some(values.into_iter().map(|value| value / max).collect())
+/// }
+///
+/// assert_eq!(normalize::<u16>(&[0, 0, 255, 255]), Some(vec![0.0, 1.0]));
+/// assert_eq!(
+///     bytes_to_vec_le_cast::<i16, u8>(&[7, 0, 255, 255]),
+///     Err(ValidationError::Rejected { index: 1, value: -1 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::Rejected`] with the index and value of the first element that
+/// cannot be represented as a `U`, such as a negative value cast to an unsigned type or a `NaN`
+/// cast to an integer, or [`ValidationError::Decode`] wrapping
+/// [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not a
+/// multiple of the size of `T`.
+pub fn bytes_to_vec_cast<T: FromBytes + ToPrimitive, U: NumCast>(
+    bytes: &[u8],
+) -> Result<Vec<U>, ValidationError<T>> {
+    bytes_to_vec_cast_with(bytes, T::from_ne_slice)
+}
+
+/// Converts a byte slice of little-endian `T` values into a vector of `U`, casting each element
+/// with [`NumCast`], available with the `num-traits` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_cast`].
+pub fn bytes_to_vec_le_cast<T: FromBytes + ToPrimitive, U: NumCast>(
+    bytes: &[u8],
+) -> Result<Vec<U>, ValidationError<T>> {
+    bytes_to_vec_cast_with(bytes, T::from_le_slice)
+}
+
+/// Converts a byte slice of big-endian `T` values into a vector of `U`, casting each element with
+/// [`NumCast`], available with the `num-traits` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_cast`].
+pub fn bytes_to_vec_be_cast<T: FromBytes + ToPrimitive, U: NumCast>(
+    bytes: &[u8],
+) -> Result<Vec<U>, ValidationError<T>> {
+    bytes_to_vec_cast_with(bytes, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_casts_elements_while_decoding() {
+        assert_eq!(
+            bytes_to_vec_be_cast::<u16, f64>(&[0, 1, 1, 0]).unwrap(),
+            vec![1.0, 256.0]
+        );
+        assert!(matches!(
+            bytes_to_vec_cast::<f32, i32>(&f32::NAN.to_ne_bytes()),
+            Err(ValidationError::Rejected { index: 0, value }) if value.is_nan()
+        ));
+        assert_eq!(
+            bytes_to_vec_cast::<u32, u8>(&[1, 2, 3]),
+            Err(ValidationError::Decode(Error::length_mismatch(3, 4)))
+        );
+    }
+}
@@ -0,0 +1,520 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::{Enumerate, FusedIterator};
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use core::slice::ChunksExact;
+
+use crate::convert::check_length;
+use crate::from_bytes::sealed;
+use crate::{BigEndian, ByteOrder, Error, FromBytes, LittleEndian, NativeEndian, Result};
+
+/// Converts a byte slice into a vector of `bool`, accepting only the bytes `0` and `1`.
+///
+/// Reinterpreting any other byte as a `bool` is undefined behavior, which is why `bool` does not
+/// implement [`FromBytes`](crate::FromBytes). Use [`bytes_to_bool_nonzero`] to treat every nonzero
+/// byte as `true` instead.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_bool, Error};
+///
+/// assert_eq!(bytes_to_bool(&[0, 1, 1]).unwrap(), vec![false, true, true]);
+/// assert_eq!(
+///     bytes_to_bool(&[0, 2, 1, 255]),
+///     Err(Error::InvalidBool { offsets: vec![1, 3] })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidBool`] listing the offsets of all bytes other than `0` and `1`.
+pub fn bytes_to_bool(bytes: &[u8]) -> Result<Vec<bool>> {
+    let invalid = bytes.iter().filter(|&&byte| byte > 1).count();
+
+    if invalid > 0 {
+        let mut offsets = Vec::with_capacity(invalid);
+        offsets.extend(
+            bytes
+                .iter()
+                .enumerate()
+                .filter(|(_, &byte)| byte > 1)
+                .map(|(offset, _)| offset),
+        );
+
+        return Err(Error::InvalidBool { offsets });
+    }
+
+    Ok(bytes.iter().map(|&byte| byte == 1).collect())
+}
+
+/// Converts a byte slice into a vector of `bool`, treating every nonzero byte as `true`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_bool_nonzero;
+///
+/// assert_eq!(bytes_to_bool_nonzero(&[0, 1, 255]), vec![false, true, true]);
+/// ```
+pub fn bytes_to_bool_nonzero(bytes: &[u8]) -> Vec<bool> {
+    bytes.iter().map(|&byte| byte != 0).collect()
+}
+
+fn bytes_to_char_with(bytes: &[u8], decode: fn(&[u8]) -> u32) -> Result<Vec<char>> {
+    check_length::<u32>(bytes)?;
+
+    bytes
+        .chunks_exact(4)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let value = decode(chunk);
+
+            char::from_u32(value).ok_or(Error::InvalidChar {
+                offset: index * 4,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// Converts a byte slice into a vector of `char`, decoding each element as a 4-byte code point in
+/// native byte order.
+///
+/// Every value is checked with [`char::from_u32`], since casting a surrogate or a value above
+/// `char::MAX` to `char` is undefined behavior.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_char_be, Error};
+///
+/// assert_eq!(bytes_to_char_be(&[0, 0, 0, 0x52, 0, 1, 0xF9, 0x80]).unwrap(), vec!['R', '🦀']);
+/// assert_eq!(
+///     bytes_to_char_be(&[0, 0, 0xD8, 0]),
+///     Err(Error::InvalidChar { offset: 0, value: 0xD800 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of 4, or
+/// [`Error::InvalidChar`] for the first value that is not a Unicode scalar value.
+pub fn bytes_to_char(bytes: &[u8]) -> Result<Vec<char>> {
+    bytes_to_char_with(bytes, u32::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of `char`, decoding each element as a little-endian 4-byte
+/// code point.
+///
+/// # Errors
+///
+/// See [`bytes_to_char`].
+pub fn bytes_to_char_le(bytes: &[u8]) -> Result<Vec<char>> {
+    bytes_to_char_with(bytes, u32::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of `char`, decoding each element as a big-endian 4-byte
+/// code point.
+///
+/// # Errors
+///
+/// See [`bytes_to_char`].
+pub fn bytes_to_char_be(bytes: &[u8]) -> Result<Vec<char>> {
+    bytes_to_char_with(bytes, u32::from_be_slice)
+}
+
+/// A nonzero integer type, such as [`NonZeroU32`], that can be decoded from raw bytes.
+///
+/// Zero is not a valid value of these types, so they cannot implement [`FromBytes`]. Instead, they
+/// are decoded as their underlying integer type, and zeros are rejected. This trait is sealed and
+/// implemented for all nonzero integer types of `core::num`.
+pub trait NonZeroFromBytes: Copy + sealed::Sealed {
+    /// The underlying integer type.
+    #[doc(hidden)]
+    type Int: FromBytes;
+
+    /// Converts the underlying integer, returning `None` if it is zero.
+    #[doc(hidden)]
+    fn new(value: Self::Int) -> Option<Self>;
+}
+
+macro_rules! impl_nonzero_from_bytes {
+    ($($type:ty => $int:ty),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            impl NonZeroFromBytes for $type {
+                type Int = $int;
+
+                #[inline]
+                fn new(value: $int) -> Option<Self> {
+                    <$type>::new(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero_from_bytes!(
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize
+);
+
+fn bytes_to_nonzero_with<T: NonZeroFromBytes>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T::Int,
+) -> Result<Vec<T>> {
+    check_length::<T::Int>(bytes)?;
+
+    bytes
+        .chunks_exact(core::mem::size_of::<T::Int>())
+        .enumerate()
+        .map(|(index, chunk)| T::new(decode(chunk)).ok_or(Error::ZeroValue { index }))
+        .collect()
+}
+
+/// Converts a byte slice into a vector of a nonzero integer type in native byte order, rejecting
+/// zeros.
+///
+/// This suits ID tables and similar data where zero is a reserved sentinel.
+///
+/// # Example
+///
+/// ```
+/// use std::num::NonZeroU16;
+///
+/// use bytes_to_type::{bytes_to_nonzero_le, Error};
+///
+/// assert_eq!(
+///     bytes_to_nonzero_le::<NonZeroU16>(&[1, 0, 0, 1]).unwrap(),
+///     vec![NonZeroU16::new(1).unwrap(), NonZeroU16::new(256).unwrap()]
+/// );
+/// assert_eq!(
+///     bytes_to_nonzero_le::<NonZeroU16>(&[1, 0, 0, 0]),
+///     Err(Error::ZeroValue { index: 1 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// or [`Error::ZeroValue`] with the index of the first zero element.
+pub fn bytes_to_nonzero<T: NonZeroFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_nonzero_with(bytes, T::Int::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of a nonzero integer type, decoding each element as
+/// little-endian and rejecting zeros.
+///
+/// # Errors
+///
+/// See [`bytes_to_nonzero`].
+pub fn bytes_to_nonzero_le<T: NonZeroFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_nonzero_with(bytes, T::Int::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of a nonzero integer type, decoding each element as
+/// big-endian and rejecting zeros.
+///
+/// # Errors
+///
+/// See [`bytes_to_nonzero`].
+pub fn bytes_to_nonzero_be<T: NonZeroFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_nonzero_with(bytes, T::Int::from_be_slice)
+}
+
+/// A lazy iterator validating elements of `T` decoded from a byte slice, yielding a result for
+/// each element.
+///
+/// Unlike the functions collecting a vector, which fail on the first invalid element, this lets a
+/// consumer skip or log bad records and carry on with the rest of the buffer. Each error is the
+/// one the collecting function would return for that element, with its offset or index.
+///
+/// This struct is created by [`iter_bool`], [`iter_char`], [`iter_nonzero`],
+/// [`iter_enum`](crate::iter_enum) and their `_le`/`_be` variants.
+#[derive(Debug, Clone)]
+pub struct CheckedElements<'a, T> {
+    chunks: Enumerate<ChunksExact<'a, u8>>,
+    decode: fn(usize, &[u8]) -> Result<T>,
+}
+
+impl<'a, T> CheckedElements<'a, T> {
+    pub(crate) fn new(bytes: &'a [u8], size: usize, decode: fn(usize, &[u8]) -> Result<T>) -> Self {
+        Self {
+            chunks: bytes.chunks_exact(size).enumerate(),
+            decode,
+        }
+    }
+}
+
+impl<T> Iterator for CheckedElements<'_, T> {
+    type Item = Result<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T>> {
+        let (index, chunk) = self.chunks.next()?;
+        Some((self.decode)(index, chunk))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Result<T>> {
+        let (index, chunk) = self.chunks.nth(n)?;
+        Some((self.decode)(index, chunk))
+    }
+}
+
+impl<T> DoubleEndedIterator for CheckedElements<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Result<T>> {
+        let (index, chunk) = self.chunks.next_back()?;
+        Some((self.decode)(index, chunk))
+    }
+}
+
+impl<T> ExactSizeIterator for CheckedElements<'_, T> {}
+
+impl<T> FusedIterator for CheckedElements<'_, T> {}
+
+fn check_bool(index: usize, chunk: &[u8]) -> Result<bool> {
+    match chunk[0] {
+        0 => Ok(false),
+        1 => Ok(true),
+        _ => Err(Error::InvalidBool {
+            offsets: vec![index],
+        }),
+    }
+}
+
+fn check_char<O: ByteOrder>(index: usize, chunk: &[u8]) -> Result<char> {
+    let value = O::decode::<u32>(chunk);
+
+    char::from_u32(value).ok_or(Error::InvalidChar {
+        offset: index * 4,
+        value,
+    })
+}
+
+fn check_nonzero<T: NonZeroFromBytes, O: ByteOrder>(index: usize, chunk: &[u8]) -> Result<T> {
+    T::new(O::decode(chunk)).ok_or(Error::ZeroValue { index })
+}
+
+/// Returns an iterator decoding `bool` values from `bytes`, yielding [`Error::InvalidBool`] with
+/// the offset of each byte other than `0` and `1`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{iter_bool, Error};
+///
+/// let flags: Vec<_> = iter_bool(&[1, 7, 0]).collect();
+///
+/// assert_eq!(
+///     flags,
+///     [Ok(true), Err(Error::InvalidBool { offsets: vec![1] }), Ok(false)]
+/// );
+/// ```
+pub fn iter_bool(bytes: &[u8]) -> CheckedElements<'_, bool> {
+    CheckedElements::new(bytes, 1, check_bool)
+}
+
+/// Returns an iterator decoding `char` values from `bytes` as 4-byte code points in native byte
+/// order, yielding [`Error::InvalidChar`] for each value that is not a Unicode scalar value.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::iter_char_be;
+///
+/// let text: String = iter_char_be(&[0, 0, 0, 0x4F, 0, 0, 0xD8, 0, 0, 0, 0, 0x4B])
+///     .unwrap()
+///     .filter_map(Result::ok)
+///     .collect();
+///
+/// assert_eq!(text, "OK");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of 4.
+pub fn iter_char(bytes: &[u8]) -> Result<CheckedElements<'_, char>> {
+    check_length::<u32>(bytes)?;
+    Ok(CheckedElements::new(bytes, 4, check_char::<NativeEndian>))
+}
+
+/// Returns an iterator decoding `char` values from `bytes` as little-endian 4-byte code points.
+///
+/// # Errors
+///
+/// See [`iter_char`].
+pub fn iter_char_le(bytes: &[u8]) -> Result<CheckedElements<'_, char>> {
+    check_length::<u32>(bytes)?;
+    Ok(CheckedElements::new(bytes, 4, check_char::<LittleEndian>))
+}
+
+/// Returns an iterator decoding `char` values from `bytes` as big-endian 4-byte code points.
+///
+/// # Errors
+///
+/// See [`iter_char`].
+pub fn iter_char_be(bytes: &[u8]) -> Result<CheckedElements<'_, char>> {
+    check_length::<u32>(bytes)?;
+    Ok(CheckedElements::new(bytes, 4, check_char::<BigEndian>))
+}
+
+/// Returns an iterator decoding a nonzero integer type from `bytes` in native byte order,
+/// yielding [`Error::ZeroValue`] with the index of each zero element.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn iter_nonzero<T: NonZeroFromBytes>(bytes: &[u8]) -> Result<CheckedElements<'_, T>> {
+    check_length::<T::Int>(bytes)?;
+    Ok(CheckedElements::new(
+        bytes,
+        core::mem::size_of::<T::Int>(),
+        check_nonzero::<T, NativeEndian>,
+    ))
+}
+
+/// Returns an iterator decoding a nonzero integer type from `bytes` as little-endian.
+///
+/// # Errors
+///
+/// See [`iter_nonzero`].
+pub fn iter_nonzero_le<T: NonZeroFromBytes>(bytes: &[u8]) -> Result<CheckedElements<'_, T>> {
+    check_length::<T::Int>(bytes)?;
+    Ok(CheckedElements::new(
+        bytes,
+        core::mem::size_of::<T::Int>(),
+        check_nonzero::<T, LittleEndian>,
+    ))
+}
+
+/// Returns an iterator decoding a nonzero integer type from `bytes` as big-endian.
+///
+/// # Errors
+///
+/// See [`iter_nonzero`].
+pub fn iter_nonzero_be<T: NonZeroFromBytes>(bytes: &[u8]) -> Result<CheckedElements<'_, T>> {
+    check_length::<T::Int>(bytes)?;
+    Ok(CheckedElements::new(
+        bytes,
+        core::mem::size_of::<T::Int>(),
+        check_nonzero::<T, BigEndian>,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_converts_bytes_to_bool() {
+        assert_eq!(bytes_to_bool(&[1, 0]).unwrap(), vec![true, false]);
+        assert_eq!(bytes_to_bool(&[]).unwrap(), Vec::<bool>::new());
+        assert_eq!(bytes_to_bool_nonzero(&[2, 0]), vec![true, false]);
+    }
+
+    #[test]
+    fn it_returns_error_listing_invalid_bool_offsets() {
+        assert_eq!(
+            bytes_to_bool(&[2, 0, 1, 3]),
+            Err(Error::InvalidBool {
+                offsets: vec![0, 3]
+            })
+        );
+    }
+
+    #[test]
+    fn it_converts_bytes_to_char() {
+        assert_eq!(bytes_to_char_le(&[0x41, 0, 0, 0]).unwrap(), vec!['A']);
+        assert_eq!(
+            bytes_to_char(&0x10FFFFu32.to_ne_bytes()).unwrap(),
+            vec![char::MAX]
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_invalid_code_points() {
+        let bytes = [0x41, 0, 0, 0, 0, 0, 0x11, 0];
+
+        assert_eq!(
+            bytes_to_char_le(&bytes),
+            Err(Error::InvalidChar {
+                offset: 4,
+                value: 0x110000
+            })
+        );
+        assert_eq!(
+            bytes_to_char_be(&bytes[..3]),
+            Err(Error::length_mismatch(3, 4))
+        );
+    }
+
+    #[test]
+    fn it_converts_bytes_to_nonzero_integers() {
+        assert_eq!(
+            bytes_to_nonzero_be::<NonZeroI32>(&[255, 255, 255, 255]).unwrap(),
+            vec![NonZeroI32::new(-1).unwrap()]
+        );
+        assert_eq!(
+            bytes_to_nonzero::<NonZeroU8>(&[3]).unwrap(),
+            vec![NonZeroU8::new(3).unwrap()]
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_zero_elements() {
+        assert_eq!(
+            bytes_to_nonzero_le::<NonZeroU32>(&[1, 0, 0, 0, 0, 0, 0, 0]),
+            Err(Error::ZeroValue { index: 1 })
+        );
+        assert_eq!(
+            bytes_to_nonzero::<NonZeroU64>(&[1]),
+            Err(Error::length_mismatch(1, 8))
+        );
+    }
+
+    #[test]
+    fn it_yields_an_error_per_invalid_element() {
+        let bytes = [1, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0];
+        let mut elements = iter_nonzero_le::<NonZeroU32>(&bytes).unwrap();
+
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements.next_back(), Some(Ok(NonZeroU32::new(3).unwrap())));
+        assert_eq!(elements.next(), Some(Ok(NonZeroU32::new(1).unwrap())));
+        assert_eq!(elements.next(), Some(Err(Error::ZeroValue { index: 1 })));
+        assert_eq!(elements.next(), None);
+
+        assert_eq!(
+            iter_char(&0xD800u32.to_ne_bytes()).unwrap().next(),
+            Some(Err(Error::InvalidChar {
+                offset: 0,
+                value: 0xD800
+            }))
+        );
+        assert_eq!(
+            iter_nonzero_be::<NonZeroU16>(&[1]).unwrap_err(),
+            Error::length_mismatch(1, 2)
+        );
+    }
+}
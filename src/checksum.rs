@@ -0,0 +1,314 @@
+use alloc::vec::Vec;
+
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, Endianness, Error, FromBytes, Result};
+
+/// Builds the lookup table of a reflected CRC with the given reversed polynomial.
+const fn reflected_table(polynomial: u32) -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ polynomial
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+/// Builds the lookup table of a non-reflected 16-bit CRC with the given polynomial.
+const fn crc16_table(polynomial: u16) -> [u16; 256] {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = (index as u16) << 8;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ polynomial
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+static CRC32_TABLE: [u32; 256] = reflected_table(0xEDB8_8320);
+static CRC16_MODBUS_TABLE: [u32; 256] = reflected_table(0xA001);
+static CRC16_CCITT_TABLE: [u16; 256] = crc16_table(0x1021);
+
+fn reflected_crc(bytes: &[u8], table: &[u32; 256], init: u32) -> u32 {
+    bytes.iter().fold(init, |crc, &byte| {
+        table[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MODULUS: u32 = 65521;
+    // The largest number of bytes that can be summed before the sums may overflow.
+    const BLOCK: usize = 5552;
+
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for block in bytes.chunks(BLOCK) {
+        for &byte in block {
+            a += u32::from(byte);
+            b += a;
+        }
+
+        a %= MODULUS;
+        b %= MODULUS;
+    }
+
+    (b << 16) | a
+}
+
+/// A checksum algorithm protecting a block of bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Checksum {
+    /// CRC-32 as used by zlib, PNG and Ethernet, with the polynomial `0x04C11DB7`.
+    Crc32,
+    /// CRC-16/CCITT-FALSE, with the polynomial `0x1021` and an initial value of `0xFFFF`, as used
+    /// by many sensor and radio protocols.
+    Crc16Ccitt,
+    /// CRC-16/MODBUS, with the reflected polynomial `0x8005` and an initial value of `0xFFFF`.
+    Crc16Modbus,
+    /// Adler-32, as used by zlib streams.
+    Adler32,
+}
+
+impl Checksum {
+    /// Computes the checksum of `bytes`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes_to_type::Checksum;
+    ///
+    /// assert_eq!(Checksum::Crc32.compute(b"123456789"), 0xCBF4_3926);
+    /// assert_eq!(Checksum::Crc16Ccitt.compute(b"123456789"), 0x29B1);
+    /// ```
+    pub fn compute(self, bytes: &[u8]) -> u32 {
+        match self {
+            Checksum::Crc32 => !reflected_crc(bytes, &CRC32_TABLE, !0),
+            Checksum::Crc16Ccitt => u32::from(bytes.iter().fold(0xFFFF, |crc: u16, &byte| {
+                (crc << 8) ^ CRC16_CCITT_TABLE[usize::from((crc >> 8) as u8 ^ byte)]
+            })),
+            Checksum::Crc16Modbus => reflected_crc(bytes, &CRC16_MODBUS_TABLE, 0xFFFF),
+            Checksum::Adler32 => adler32(bytes),
+        }
+    }
+
+    /// Returns the size of the checksum when stored alongside the data, in bytes.
+    pub const fn size(self) -> usize {
+        match self {
+            Checksum::Crc32 | Checksum::Adler32 => 4,
+            Checksum::Crc16Ccitt | Checksum::Crc16Modbus => 2,
+        }
+    }
+}
+
+/// Verifies that the checksum of `bytes` is `expected`.
+///
+/// # Errors
+///
+/// Returns [`Error::ChecksumMismatch`] if the checksum differs from `expected`.
+pub fn verify_checksum(bytes: &[u8], checksum: Checksum, expected: u32) -> Result<()> {
+    let actual = checksum.compute(bytes);
+
+    if actual != expected {
+        return Err(Error::ChecksumMismatch { expected, actual });
+    }
+
+    Ok(())
+}
+
+/// Verifies the checksum stored in the last [`Checksum::size`] bytes of `bytes` in the given byte
+/// order, and returns the bytes before it.
+///
+/// This is the layout of most firmware images and sensor frames, which end with the checksum of
+/// everything before it.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_le, strip_checksum, Checksum, Endianness};
+///
+/// let frame = [1, 0, 2, 0, 0x00, 0xB8];
+/// let payload = strip_checksum(&frame, Checksum::Crc16Modbus, Endianness::Little).unwrap();
+///
+/// assert_eq!(bytes_to_vec_le::<u16>(payload).unwrap(), vec![1, 2]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is shorter than the checksum, or
+/// [`Error::ChecksumMismatch`] if the stored checksum is wrong.
+pub fn strip_checksum(bytes: &[u8], checksum: Checksum, endianness: Endianness) -> Result<&[u8]> {
+    let size = checksum.size();
+    let payload_len = bytes.len().checked_sub(size).ok_or(Error::Truncated {
+        len: bytes.len(),
+        expected: size,
+    })?;
+    let (payload, stored) = bytes.split_at(payload_len);
+
+    let expected = match (size, endianness) {
+        (2, Endianness::Little) => u32::from(u16::from_le_slice(stored)),
+        (2, Endianness::Big) => u32::from(u16::from_be_slice(stored)),
+        (_, Endianness::Little) => u32::from_le_slice(stored),
+        (_, Endianness::Big) => u32::from_be_slice(stored),
+    };
+    verify_checksum(payload, checksum, expected)?;
+
+    Ok(payload)
+}
+
+fn bytes_to_vec_verified_with<T>(
+    bytes: &[u8],
+    checksum: Checksum,
+    expected: u32,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    verify_checksum(bytes, checksum, expected)?;
+
+    convert(bytes)
+}
+
+/// Verifies that the checksum of `bytes` is `expected`, and then converts them into a vector of
+/// `T` in native byte order.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_verified_be, Checksum, Error};
+///
+/// let bytes = [0, 1, 0, 2];
+///
+/// assert_eq!(
+///     bytes_to_vec_verified_be::<u16>(&bytes, Checksum::Adler32, 0x0009_0004).unwrap(),
+///     vec![1, 2]
+/// );
+/// assert!(matches!(
+///     bytes_to_vec_verified_be::<u16>(&bytes, Checksum::Adler32, 0),
+///     Err(Error::ChecksumMismatch { .. })
+/// ));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::ChecksumMismatch`] if the checksum differs from `expected`, or
+/// [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_vec_verified<T: FromBytes>(
+    bytes: &[u8],
+    checksum: Checksum,
+    expected: u32,
+) -> Result<Vec<T>> {
+    bytes_to_vec_verified_with(bytes, checksum, expected, bytes_to_vec::<T>)
+}
+
+/// Verifies that the checksum of `bytes` is `expected`, and then converts them into a vector of
+/// `T`, decoding each element as little-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_verified`].
+pub fn bytes_to_vec_verified_le<T: FromBytes>(
+    bytes: &[u8],
+    checksum: Checksum,
+    expected: u32,
+) -> Result<Vec<T>> {
+    bytes_to_vec_verified_with(bytes, checksum, expected, bytes_to_vec_le::<T>)
+}
+
+/// Verifies that the checksum of `bytes` is `expected`, and then converts them into a vector of
+/// `T`, decoding each element as big-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_verified`].
+pub fn bytes_to_vec_verified_be<T: FromBytes>(
+    bytes: &[u8],
+    checksum: Checksum,
+    expected: u32,
+) -> Result<Vec<T>> {
+    bytes_to_vec_verified_with(bytes, checksum, expected, bytes_to_vec_be::<T>)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_computes_standard_check_values() {
+        let bytes = b"123456789";
+
+        assert_eq!(Checksum::Crc32.compute(bytes), 0xCBF4_3926);
+        assert_eq!(Checksum::Crc16Ccitt.compute(bytes), 0x29B1);
+        assert_eq!(Checksum::Crc16Modbus.compute(bytes), 0x4B37);
+        assert_eq!(Checksum::Adler32.compute(bytes), 0x091E_01DE);
+        assert_eq!(Checksum::Adler32.compute(&[0xFF; 100_000]), 0x149A_302C);
+        assert_eq!(Checksum::Crc32.compute(&[]), 0);
+    }
+
+    #[test]
+    fn it_strips_trailing_checksums() {
+        let mut frame = vec![1, 2, 3];
+        frame.extend_from_slice(&Checksum::Crc32.compute(&frame).to_be_bytes());
+
+        assert_eq!(
+            strip_checksum(&frame, Checksum::Crc32, Endianness::Big).unwrap(),
+            &[1, 2, 3]
+        );
+        assert!(matches!(
+            strip_checksum(&frame, Checksum::Crc32, Endianness::Little),
+            Err(Error::ChecksumMismatch { .. })
+        ));
+        assert_eq!(
+            strip_checksum(&[1], Checksum::Crc16Ccitt, Endianness::Big),
+            Err(Error::Truncated {
+                len: 1,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn it_verifies_checksum_before_decoding() {
+        let bytes = [1, 0, 0, 0];
+        let crc = Checksum::Crc32.compute(&bytes);
+
+        assert_eq!(
+            bytes_to_vec_verified_le::<u32>(&bytes, Checksum::Crc32, crc).unwrap(),
+            vec![1]
+        );
+        assert_eq!(
+            bytes_to_vec_verified::<u8>(&bytes, Checksum::Crc32, crc ^ 1),
+            Err(Error::ChecksumMismatch {
+                expected: crc ^ 1,
+                actual: crc
+            })
+        );
+    }
+}
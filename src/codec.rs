@@ -0,0 +1,144 @@
+use std::io;
+
+use tokio_util::bytes::{Buf, BytesMut};
+use tokio_util::codec;
+
+use crate::{Error, FromBytes};
+
+/// A [`tokio_util::codec::Decoder`] emitting frames of decoded elements from a byte stream,
+/// available with the `tokio` feature.
+///
+/// Each frame contains all complete elements that were buffered when the frame was decoded.
+/// Bytes of an incomplete trailing element stay in the buffer until the rest of the element
+/// arrives, so the decoder can be wired to [`FramedRead`](tokio_util::codec::FramedRead)
+/// directly.
+///
+/// If the stream ends in the middle of an element, [`decode_eof`](codec::Decoder::decode_eof)
+/// returns an error wrapping [`Error::LengthMismatch`], whose length counts every byte of the
+/// stream, so its [`offset`](Error::offset) is the position of the incomplete element.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::ElementDecoder;
+/// use tokio_util::bytes::BytesMut;
+/// use tokio_util::codec::Decoder;
+///
+/// let mut decoder = ElementDecoder::<u16>::new_be();
+/// let mut buffer = BytesMut::from(&[0, 1, 0, 2, 0][..]);
+///
+/// assert_eq!(decoder.decode(&mut buffer).unwrap(), Some(vec![1, 2]));
+/// assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ElementDecoder<T> {
+    decode: fn(&[u8]) -> T,
+    consumed: usize,
+}
+
+impl<T: FromBytes> ElementDecoder<T> {
+    /// Creates a codec that decodes elements in native byte order.
+    pub fn new() -> Self {
+        Self {
+            decode: T::from_ne_slice,
+            consumed: 0,
+        }
+    }
+
+    /// Creates a codec that decodes elements as little-endian.
+    pub fn new_le() -> Self {
+        Self {
+            decode: T::from_le_slice,
+            consumed: 0,
+        }
+    }
+
+    /// Creates a codec that decodes elements as big-endian.
+    pub fn new_be() -> Self {
+        Self {
+            decode: T::from_be_slice,
+            consumed: 0,
+        }
+    }
+}
+
+impl<T: FromBytes> Default for ElementDecoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: FromBytes> codec::Decoder for ElementDecoder<T> {
+    type Item = Vec<T>;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Vec<T>>> {
+        let size = core::mem::size_of::<T>();
+        let complete = src.len() - src.len() % size;
+
+        if complete == 0 {
+            src.reserve(size);
+            return Ok(None);
+        }
+
+        let values = src[..complete]
+            .chunks_exact(size)
+            .map(self.decode)
+            .collect();
+        src.advance(complete);
+        self.consumed += complete;
+
+        Ok(Some(values))
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> io::Result<Option<Vec<T>>> {
+        match self.decode(src)? {
+            Some(values) => Ok(Some(values)),
+            None if src.is_empty() => Ok(None),
+            None => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                Error::length_mismatch(self.consumed + src.len(), core::mem::size_of::<T>()),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio_util::codec::Decoder;
+
+    use super::*;
+
+    #[test]
+    fn it_emits_frames_of_complete_elements() {
+        let mut decoder = ElementDecoder::<u32>::new_le();
+        let mut buffer = BytesMut::from(&[1, 0, 0][..]);
+
+        assert_eq!(decoder.decode(&mut buffer).unwrap(), None);
+
+        buffer.extend_from_slice(&[0, 2, 0, 0, 0, 3]);
+        assert_eq!(decoder.decode(&mut buffer).unwrap(), Some(vec![1, 2]));
+        assert_eq!(&buffer[..], &[3]);
+    }
+
+    #[test]
+    fn it_returns_error_on_incomplete_element_at_eof() {
+        let mut decoder = ElementDecoder::<u32>::default();
+        let mut buffer = BytesMut::from(&[1, 2, 3, 4, 5, 6][..]);
+
+        assert_eq!(decoder.decode(&mut buffer).unwrap().unwrap().len(), 1);
+
+        let error = decoder.decode_eof(&mut buffer).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.get_ref().unwrap().downcast_ref::<Error>(),
+            Some(&Error::LengthMismatch {
+                len: 6,
+                size: 4,
+                remainder: 2
+            })
+        );
+
+        assert_eq!(decoder.decode_eof(&mut BytesMut::new()).unwrap(), None);
+    }
+}
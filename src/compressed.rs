@@ -0,0 +1,208 @@
+use std::io::{self, Read};
+
+use crate::io::invalid_data;
+use crate::{bytes_to_vec_be_into, bytes_to_vec_into, bytes_to_vec_le_into, Error, FromBytes};
+
+/// The number of decompressed bytes decoded at a time.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// A compression format accepted by [`decode_compressed`].
+///
+/// Each variant is available with the feature of the crate implementing it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum Compression {
+    /// A raw DEFLATE stream, without a header. Available with the `flate2` feature.
+    #[cfg(feature = "flate2")]
+    Deflate,
+    /// A zlib stream. Available with the `flate2` feature.
+    #[cfg(feature = "flate2")]
+    Zlib,
+    /// A gzip file, possibly made of several members. Available with the `flate2` feature.
+    #[cfg(feature = "flate2")]
+    Gzip,
+    /// A Zstandard stream. Available with the `zstd` feature.
+    #[cfg(feature = "zstd")]
+    Zstd,
+    /// An LZ4 frame. Available with the `lz4` feature.
+    #[cfg(feature = "lz4")]
+    Lz4,
+}
+
+fn decompressor(bytes: &[u8], compression: Compression) -> io::Result<Box<dyn Read + '_>> {
+    Ok(match compression {
+        #[cfg(feature = "flate2")]
+        Compression::Deflate => Box::new(flate2::read::DeflateDecoder::new(bytes)),
+        #[cfg(feature = "flate2")]
+        Compression::Zlib => Box::new(flate2::read::ZlibDecoder::new(bytes)),
+        #[cfg(feature = "flate2")]
+        Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(bytes)),
+        #[cfg(feature = "zstd")]
+        Compression::Zstd => Box::new(zstd::stream::read::Decoder::new(bytes)?),
+        #[cfg(feature = "lz4")]
+        Compression::Lz4 => Box::new(lz4_flex::frame::FrameDecoder::new(bytes)),
+    })
+}
+
+/// Decodes the decompressed bytes of `reader` one chunk at a time, carrying an incomplete
+/// trailing element over to the next chunk.
+fn decode_stream<T: FromBytes>(
+    mut reader: impl Read,
+    append: fn(&[u8], &mut Vec<T>) -> crate::Result<usize>,
+) -> io::Result<Vec<T>> {
+    let size = core::mem::size_of::<T>();
+    let mut buffer = vec![0; CHUNK_SIZE.max(size)];
+    let mut values = Vec::new();
+    let mut filled = 0;
+    let mut total = 0;
+
+    loop {
+        let read = match reader.read(&mut buffer[filled..]) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        filled += read;
+        total += read;
+
+        let complete = filled - filled % size;
+        append(&buffer[..complete], &mut values).map_err(invalid_data)?;
+        buffer.copy_within(complete..filled, 0);
+        filled -= complete;
+    }
+
+    if filled != 0 {
+        return Err(invalid_data(Error::length_mismatch(total, size)));
+    }
+
+    Ok(values)
+}
+
+/// Decompresses `bytes` and converts the decompressed bytes into a vector of `T` in native byte
+/// order.
+///
+/// The decompressed bytes are decoded as they are produced, a chunk at a time, so only the
+/// resulting vector is held in memory rather than both it and the decompressed bytes. This function
+/// is available with any of the `flate2`, `zstd` and `lz4` features.
+///
+/// # Errors
+///
+/// Returns any I/O error produced by the decompressor, usually of kind
+/// [`io::ErrorKind::InvalidData`] for corrupt input. If the number of decompressed bytes is not a
+/// multiple of the size of `T`, returns an error of kind [`io::ErrorKind::InvalidData`] wrapping
+/// [`Error::LengthMismatch`].
+pub fn decode_compressed<T: FromBytes>(
+    bytes: &[u8],
+    compression: Compression,
+) -> io::Result<Vec<T>> {
+    decode_stream(decompressor(bytes, compression)?, bytes_to_vec_into::<T>)
+}
+
+/// Decompresses `bytes` and converts the decompressed bytes into a vector of `T`, decoding each
+/// element as little-endian.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "zstd")]
+/// # {
+/// use bytes_to_type::{decode_compressed_le, Compression};
+///
+/// let column: Vec<u8> = [1.0f64, 2.0, 3.0].iter().flat_map(|value| value.to_le_bytes()).collect();
+/// let compressed = zstd::encode_all(&column[..], 0).unwrap();
+///
+/// assert_eq!(
+///     decode_compressed_le::<f64>(&compressed, Compression::Zstd).unwrap(),
+///     vec![1.0, 2.0, 3.0]
+/// );
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// See [`decode_compressed`].
+pub fn decode_compressed_le<T: FromBytes>(
+    bytes: &[u8],
+    compression: Compression,
+) -> io::Result<Vec<T>> {
+    decode_stream(decompressor(bytes, compression)?, bytes_to_vec_le_into::<T>)
+}
+
+/// Decompresses `bytes` and converts the decompressed bytes into a vector of `T`, decoding each
+/// element as big-endian.
+///
+/// # Errors
+///
+/// See [`decode_compressed`].
+pub fn decode_compressed_be<T: FromBytes>(
+    bytes: &[u8],
+    compression: Compression,
+) -> io::Result<Vec<T>> {
+    decode_stream(decompressor(bytes, compression)?, bytes_to_vec_be_into::<T>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decodes_elements_split_across_chunks() {
+        let expected: Vec<u32> = (0..CHUNK_SIZE as u32).collect();
+        let bytes: Vec<u8> = expected
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect();
+        // Splitting the reads within an element carries it over to the next read.
+        let reader = bytes[..5].chain(&bytes[5..]);
+
+        assert_eq!(
+            decode_stream::<u32>(reader, bytes_to_vec_be_into).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_trailing_bytes() {
+        let error = decode_stream::<u32>(&[0; 6][..], bytes_to_vec_into).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.into_inner().unwrap().downcast_ref::<Error>(),
+            Some(&Error::length_mismatch(6, 4))
+        );
+    }
+
+    #[cfg(feature = "flate2")]
+    #[test]
+    fn it_decodes_flate2_formats() {
+        use std::io::Write;
+
+        let bytes: Vec<u8> = (0..1000u16).flat_map(u16::to_le_bytes).collect();
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::fast());
+        encoder.write_all(&bytes).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(
+            decode_compressed_le::<u16>(&compressed, Compression::Gzip).unwrap(),
+            (0..1000).collect::<Vec<_>>()
+        );
+        assert!(decode_compressed::<u16>(&compressed, Compression::Zlib).is_err());
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn it_decodes_lz4_frames() {
+        use std::io::Write;
+
+        let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+        encoder.write_all(&[0, 0, 0, 1, 0, 0, 0, 2]).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        assert_eq!(
+            decode_compressed_be::<i32>(&compressed, Compression::Lz4).unwrap(),
+            vec![1, 2]
+        );
+    }
+}
@@ -0,0 +1,117 @@
+//! Element kinds accepted by the [`bytes_to_const!`](crate::bytes_to_const) macro.
+//!
+//! Like the field kinds of [`byte_record!`](crate::byte_record), every kind is both a type alias
+//! naming the element type and a `const fn` decoding an array of that type, so the macro maps a
+//! kind such as `u32_be` to both with a single path.
+//!
+//! The module also holds the support items of the
+//! [`include_typed_bytes!`](crate::include_typed_bytes) macro.
+
+#![allow(non_camel_case_types)]
+
+use crate::FromBytes;
+
+/// Bytes stored with the alignment of `T`, as included by
+/// [`include_typed_bytes!`](crate::include_typed_bytes).
+#[repr(C)]
+pub struct Aligned<T, B: ?Sized> {
+    pub align: [T; 0],
+    pub bytes: B,
+}
+
+/// Reinterprets aligned bytes as a slice of `T`, failing to compile if their length is not a
+/// multiple of the size of `T`.
+pub const fn aligned_slice<T: FromBytes>(aligned: &'static Aligned<T, [u8]>) -> &'static [T] {
+    let len = aligned.bytes.len();
+
+    assert!(
+        len.is_multiple_of(core::mem::size_of::<T>()),
+        "the length of the file is not a multiple of the size of the element type"
+    );
+
+    // SAFETY: the bytes are aligned for `T` and their length is a multiple of its size, and every
+    // bit pattern is a valid value of a `FromBytes` type.
+    unsafe {
+        core::slice::from_raw_parts(
+            aligned.bytes.as_ptr() as *const T,
+            len / core::mem::size_of::<T>(),
+        )
+    }
+}
+
+macro_rules! const_kinds {
+    ($($type:ident),*) => {
+        $crate::paste! {
+            $(
+                const_kinds!(@kind $type, [<$type _ne>], from_ne_bytes);
+                const_kinds!(@kind $type, [<$type _le>], from_le_bytes);
+                const_kinds!(@kind $type, [<$type _be>], from_be_bytes);
+            )*
+        }
+    };
+    (@kind $type:ident, $kind:ident, $from_bytes:ident) => {
+        pub type $kind = $type;
+
+        pub const fn $kind<const N: usize>(bytes: &[u8]) -> [$type; N] {
+            const SIZE: usize = core::mem::size_of::<$type>();
+
+            assert!(
+                bytes.len() == N * SIZE,
+                "the length of the bytes is not the size of the array"
+            );
+
+            let mut values = [0 as $type; N];
+            let mut index = 0;
+
+            while index < N {
+                let mut element = [0; SIZE];
+                let mut byte = 0;
+
+                while byte < SIZE {
+                    element[byte] = bytes[index * SIZE + byte];
+                    byte += 1;
+                }
+
+                values[index] = $type::$from_bytes(element);
+                index += 1;
+            }
+
+            values
+        }
+    };
+}
+
+const_kinds!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+
+pub type u8 = core::primitive::u8;
+pub type i8 = core::primitive::i8;
+
+pub const fn u8<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    assert!(
+        bytes.len() == N,
+        "the length of the bytes is not the size of the array"
+    );
+
+    let mut values = [0; N];
+    let mut index = 0;
+
+    while index < N {
+        values[index] = bytes[index];
+        index += 1;
+    }
+
+    values
+}
+
+pub const fn i8<const N: usize>(bytes: &[u8]) -> [i8; N] {
+    let bytes = u8::<N>(bytes);
+    let mut values = [0; N];
+    let mut index = 0;
+
+    while index < N {
+        values[index] = bytes[index] as i8;
+        index += 1;
+    }
+
+    values
+}
@@ -0,0 +1,1172 @@
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::ops::RangeInclusive;
+
+use crate::trace::{self, ConversionPath};
+use crate::{Endianness, Error, FromBytes, Result, ValidationError};
+
+/// Checks that `bytes` can be split into a whole number of elements of type `T`.
+pub(crate) fn check_length<T>(bytes: &[u8]) -> Result<()> {
+    if !bytes.len().is_multiple_of(core::mem::size_of::<T>()) {
+        return Err(Error::length_mismatch(
+            bytes.len(),
+            core::mem::size_of::<T>(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Splits `bytes` into the longest prefix holding whole elements of `T` and the remaining tail.
+pub(crate) fn split_whole<T>(bytes: &[u8]) -> (&[u8], &[u8]) {
+    bytes.split_at(bytes.len() - bytes.len() % core::mem::size_of::<T>())
+}
+
+/// Returns whether `bytes` starts at an address suitably aligned for `T`.
+pub(crate) fn is_aligned<T>(bytes: &[u8]) -> bool {
+    (bytes.as_ptr() as usize).is_multiple_of(core::mem::align_of::<T>())
+}
+
+/// Views `values` as their bytes in native byte order, used by
+/// [`type_to_bytes!`](crate::type_to_bytes).
+#[doc(hidden)]
+pub fn as_bytes<T: FromBytes>(values: &[T]) -> &[u8] {
+    // SAFETY: implementing `FromBytes` requires the absence of padding, so all bytes of `values`
+    // are initialized.
+    unsafe {
+        core::slice::from_raw_parts(values.as_ptr() as *const u8, core::mem::size_of_val(values))
+    }
+}
+
+/// Returns the path taken by the copying conversions of `bytes` to elements of `T` in native
+/// byte order.
+fn native_path<T>(bytes: &[u8]) -> ConversionPath {
+    if cfg!(feature = "safe-only") {
+        ConversionPath::Elementwise
+    } else if is_aligned::<T>(bytes) {
+        ConversionPath::Copied
+    } else {
+        ConversionPath::Realigned
+    }
+}
+
+/// Returns the path taken by the copying conversions to elements of `T` in the byte order
+/// opposite to the native one.
+#[cfg_attr(
+    not(all(feature = "simd", not(feature = "safe-only"))),
+    allow(clippy::extra_unused_type_parameters)
+)]
+fn swapped_path<T: FromBytes>() -> ConversionPath {
+    #[cfg(all(feature = "simd", not(feature = "safe-only")))]
+    if T::IS_PRIMITIVE && matches!(core::mem::size_of::<T>(), 2 | 4 | 8 | 16) {
+        return ConversionPath::Swapped;
+    }
+
+    ConversionPath::Elementwise
+}
+
+/// Returns the path that converting `bytes` into a vector of `T` in the byte order `endianness`
+/// takes, such as with [`bytes_to_vec_endian`](crate::bytes_to_vec_endian).
+///
+/// Input in native byte order is copied as a whole whatever its alignment: aligned input through
+/// a slice cast, and unaligned input with a single byte copy into a freshly allocated vector, which
+/// is aligned for `T`. Input in the opposite byte order is decoded element by element, or copied
+/// and then byte-swapped with the `simd` feature. This lets tests and benchmarks check that a hot
+/// conversion takes the expected path.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(not(feature = "safe-only"))]
+/// # {
+/// use bytes_to_type::{conversion_path, ConversionPath, Endianness};
+///
+/// let values = [1u32, 2, 3];
+/// let bytes: Vec<u8> = values.iter().flat_map(|value| value.to_ne_bytes()).collect();
+///
+/// assert!(matches!(
+///     conversion_path::<u32>(&bytes[1..9], Endianness::NATIVE),
+///     ConversionPath::Copied | ConversionPath::Realigned
+/// ));
+/// assert_eq!(conversion_path::<u8>(&bytes, Endianness::Big), ConversionPath::Copied);
+/// # }
+/// ```
+pub fn conversion_path<T: FromBytes>(bytes: &[u8], endianness: Endianness) -> ConversionPath {
+    if endianness == Endianness::NATIVE || is_byte_sized::<T>() {
+        native_path::<T>(bytes)
+    } else {
+        swapped_path::<T>()
+    }
+}
+
+/// Copies `bytes`, whose length must be a multiple of the size of `T`, into a vector of `T` in
+/// native byte order.
+fn decode_ne<T: FromBytes>(bytes: &[u8]) -> Vec<T> {
+    trace::conversion::<T>(bytes.len(), native_path::<T>(bytes));
+
+    copy_ne(bytes)
+}
+
+/// Copies `bytes` into a vector of `T` in native byte order, like [`decode_ne`] but without
+/// recording the conversion.
+///
+/// Aligned input is copied through a slice cast, while unaligned input is copied byte for byte
+/// into a freshly allocated vector, whose buffer is aligned for `T`. Both are a single `memcpy`,
+/// which for large inputs is much faster than decoding the elements one by one.
+#[cfg(not(feature = "safe-only"))]
+fn copy_ne<T: FromBytes>(bytes: &[u8]) -> Vec<T> {
+    let count = bytes.len() / core::mem::size_of::<T>();
+
+    if !is_aligned::<T>(bytes) {
+        let mut values = Vec::<T>::with_capacity(count);
+
+        // SAFETY: the vector has room for `count` elements, which the bytes fill entirely since
+        // the length is a multiple of the size of `T`, and every bit pattern is a valid value of
+        // a `FromBytes` type.
+        unsafe {
+            core::ptr::copy_nonoverlapping(
+                bytes.as_ptr(),
+                values.as_mut_ptr() as *mut u8,
+                bytes.len(),
+            );
+            values.set_len(count);
+        }
+
+        return values;
+    }
+
+    // SAFETY: the pointer is aligned for `T`, the length is a multiple of the size of `T`, and
+    // every bit pattern is a valid value of a `FromBytes` type.
+    unsafe { core::slice::from_raw_parts(bytes.as_ptr() as *const T, count) }.to_vec()
+}
+
+/// Decodes `bytes` into a vector of `T` in native byte order one element at a time, so that no
+/// `unsafe` code is involved.
+#[cfg(feature = "safe-only")]
+fn copy_ne<T: FromBytes>(bytes: &[u8]) -> Vec<T> {
+    decode_with(bytes, T::from_ne_slice)
+}
+
+/// Returns whether elements of `T` have no byte order to swap, like `u8` and `i8`, in which case
+/// every conversion can take the native copying path.
+pub(crate) const fn is_byte_sized<T>() -> bool {
+    core::mem::size_of::<T>() == 1
+}
+
+/// Decodes `bytes`, whose length must be a multiple of the size of `T`, as little-endian.
+fn decode_le<T: FromBytes>(bytes: &[u8]) -> Vec<T> {
+    if cfg!(target_endian = "little") || is_byte_sized::<T>() {
+        decode_ne(bytes)
+    } else {
+        decode_swapped(bytes, T::from_le_slice)
+    }
+}
+
+/// Decodes `bytes`, whose length must be a multiple of the size of `T`, as big-endian.
+fn decode_be<T: FromBytes>(bytes: &[u8]) -> Vec<T> {
+    if cfg!(target_endian = "big") || is_byte_sized::<T>() {
+        decode_ne(bytes)
+    } else {
+        decode_swapped(bytes, T::from_be_slice)
+    }
+}
+
+/// Decodes `bytes` in the byte order opposite to the native one, using `decode` for each element.
+///
+/// With the `simd` feature, primitive elements are instead copied in native byte order and then
+/// swapped in place with vector instructions, unless the `safe-only` feature is enabled.
+fn decode_swapped<T: FromBytes>(bytes: &[u8], decode: fn(&[u8]) -> T) -> Vec<T> {
+    let path = swapped_path::<T>();
+    trace::conversion::<T>(bytes.len(), path);
+
+    #[cfg(all(feature = "simd", not(feature = "safe-only")))]
+    if path == ConversionPath::Swapped {
+        let mut values = copy_ne::<T>(bytes);
+
+        // SAFETY: the vector holds `values.len()` initialized primitives, and any bytes written
+        // to them form valid values.
+        let swapped = unsafe {
+            core::slice::from_raw_parts_mut(
+                values.as_mut_ptr() as *mut u8,
+                core::mem::size_of_val(values.as_slice()),
+            )
+        };
+        crate::simd::swap_bytes(swapped, core::mem::size_of::<T>());
+
+        return values;
+    }
+
+    decode_with(bytes, decode)
+}
+
+/// Decodes `bytes`, whose length must be a multiple of the size of `T`, element by element.
+fn decode_with<T: FromBytes>(bytes: &[u8], decode: fn(&[u8]) -> T) -> Vec<T> {
+    bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        .map(decode)
+        .collect()
+}
+
+/// Decodes `bytes` as consecutive records of `N` bytes with `decode`, for types whose wire layout
+/// differs from their memory layout.
+pub(crate) fn decode_records<T, const N: usize>(
+    bytes: &[u8],
+    decode: fn([u8; N]) -> T,
+) -> Result<Vec<T>> {
+    if !bytes.len().is_multiple_of(N) {
+        return Err(Error::length_mismatch(bytes.len(), N));
+    }
+
+    Ok(bytes
+        .chunks_exact(N)
+        .map(|chunk| decode(chunk.try_into().unwrap()))
+        .collect())
+}
+
+/// Converts a byte slice into a vector of `T`, reinterpreting the bytes in native byte order.
+///
+/// This is the generic counterpart of the functions generated by [`bytes_to_type!`](crate::bytes_to_type),
+/// and works for every type implementing [`FromBytes`] without a macro invocation. The input does
+/// not need to be aligned for `T`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_vec;
+///
+/// let bytes = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// let result = bytes_to_vec::<u32>(&bytes).expect("Failed to convert bytes to u32");
+///
+/// assert_eq!(result, vec![67305985, 134678021]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_vec<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    check_length::<T>(bytes)?;
+
+    Ok(decode_ne(bytes))
+}
+
+/// Converts a byte slice into a vector of `T` in native byte order, without checking its length.
+///
+/// This is the unchecked counterpart of [`bytes_to_vec`] for hot loops where the length of the
+/// input is already validated upstream. The bytes are copied into the new vector in one go, so the
+/// input does not need to be aligned for `T`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_vec_unchecked;
+///
+/// let bytes = vec![1, 0, 0, 0, 2, 0, 0, 0];
+///
+/// // SAFETY: the length of `bytes` is a multiple of the size of `u32`.
+/// let result = unsafe { bytes_to_vec_unchecked::<u32>(&bytes) };
+///
+/// assert_eq!(result, [1, 2].map(u32::from_le));
+/// ```
+///
+/// # Safety
+///
+/// The length of `bytes` must be a multiple of the size of `T`. Calling this function with any
+/// other input is undefined behavior.
+pub unsafe fn bytes_to_vec_unchecked<T: FromBytes>(bytes: &[u8]) -> Vec<T> {
+    debug_assert!(check_length::<T>(bytes).is_ok());
+
+    let len = bytes.len() / core::mem::size_of::<T>();
+    let mut values = Vec::<T>::with_capacity(len);
+
+    // SAFETY: the caller guarantees that `bytes` holds exactly `len` elements, so the copy fits in
+    // the allocated capacity and initializes all of it, and every bit pattern is a valid value of
+    // a `FromBytes` type.
+    unsafe {
+        core::ptr::copy_nonoverlapping(bytes.as_ptr(), values.as_mut_ptr() as *mut u8, bytes.len());
+        values.set_len(len);
+    }
+
+    values
+}
+
+/// Checks that `bytes` can be viewed as a slice of `T` in place.
+fn check_view<T>(bytes: &[u8]) -> Result<()> {
+    check_length::<T>(bytes)?;
+
+    if !is_aligned::<T>(bytes) {
+        trace::conversion::<T>(bytes.len(), ConversionPath::Misaligned);
+        return Err(Error::Misaligned {
+            align: core::mem::align_of::<T>(),
+        });
+    }
+
+    trace::conversion::<T>(bytes.len(), ConversionPath::Borrowed);
+    Ok(())
+}
+
+/// Reinterprets a byte slice as a slice of `T` in native byte order, without copying.
+///
+/// This is useful for large buffers, such as memory-mapped files, where copying the data into a
+/// vector is too expensive.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_slice, type_to_bytes};
+///
+/// type_to_bytes!(u32);
+///
+/// let values: Vec<u32> = vec![1, 2, 3];
+/// let bytes = u32_as_bytes(&values);
+/// let result = bytes_to_slice::<u32>(bytes).expect("Failed to view bytes as u32");
+///
+/// assert_eq!(result, &[1, 2, 3]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// or [`Error::Misaligned`] if `bytes` is not properly aligned for `T`.
+pub fn bytes_to_slice<T: FromBytes>(bytes: &[u8]) -> Result<&[T]> {
+    check_view::<T>(bytes)?;
+
+    // SAFETY: the pointer is aligned for `T`, the length is a multiple of the size of `T`, and
+    // every bit pattern is a valid value of a `FromBytes` type.
+    Ok(unsafe {
+        core::slice::from_raw_parts(
+            bytes.as_ptr() as *const T,
+            bytes.len() / core::mem::size_of::<T>(),
+        )
+    })
+}
+
+/// Reinterprets a mutable byte slice as a mutable slice of `T` in native byte order, without
+/// copying.
+///
+/// Values written through the returned slice modify the underlying bytes in place, which avoids a
+/// decode, modify and re-encode cycle for data living inside a shared buffer.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_slice_mut, type_to_bytes};
+///
+/// let mut values: Vec<u16> = vec![1, 2];
+/// let bytes = unsafe {
+///     std::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, 4)
+/// };
+///
+/// bytes_to_slice_mut::<u16>(bytes).unwrap()[1] = 0x0102;
+///
+/// assert_eq!(values, vec![1, 0x0102]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// or [`Error::Misaligned`] if `bytes` is not properly aligned for `T`.
+pub fn bytes_to_slice_mut<T: FromBytes>(bytes: &mut [u8]) -> Result<&mut [T]> {
+    check_view::<T>(bytes)?;
+
+    // SAFETY: the pointer is aligned for `T`, the length is a multiple of the size of `T`, and
+    // every bit pattern is a valid value of a `FromBytes` type. `FromBytes` types have no
+    // padding, so writing a `T` leaves every byte of the buffer initialized.
+    Ok(unsafe {
+        core::slice::from_raw_parts_mut(
+            bytes.as_mut_ptr() as *mut T,
+            bytes.len() / core::mem::size_of::<T>(),
+        )
+    })
+}
+
+/// Converts a byte slice into a [`Cow`] of `T` in native byte order, borrowing when possible.
+///
+/// If `bytes` happens to be aligned for `T`, the result borrows the input like
+/// [`bytes_to_slice`]. Otherwise, the bytes are copied into an owned vector like
+/// [`bytes_to_vec`]. This gives zero-copy conversions most of the time, without a separate code
+/// path for unaligned input.
+///
+/// # Example
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use bytes_to_type::{bytes_to_cow, type_to_bytes};
+///
+/// type_to_bytes!(u32);
+///
+/// let values: Vec<u32> = vec![1, 2, 3];
+/// let result = bytes_to_cow::<u32>(u32_as_bytes(&values)).unwrap();
+///
+/// assert!(matches!(result, Cow::Borrowed(_)));
+/// assert_eq!(result, &[1, 2, 3][..]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_cow<T: FromBytes>(bytes: &[u8]) -> Result<Cow<'_, [T]>> {
+    match bytes_to_slice(bytes) {
+        Ok(slice) => Ok(Cow::Borrowed(slice)),
+        Err(Error::Misaligned { .. }) => Ok(Cow::Owned(decode_ne(bytes))),
+        Err(error) => Err(error),
+    }
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as little-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_vec_le<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    check_length::<T>(bytes)?;
+
+    Ok(decode_le(bytes))
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as big-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_vec_be<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    check_length::<T>(bytes)?;
+
+    Ok(decode_be(bytes))
+}
+
+pub(crate) fn decode_validated<T: FromBytes, F: FnMut(&T) -> bool>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+    mut predicate: F,
+) -> Result<Vec<T>, ValidationError<T>> {
+    check_length::<T>(bytes)?;
+
+    let mut values = Vec::with_capacity(bytes.len() / core::mem::size_of::<T>());
+
+    for (index, chunk) in bytes.chunks_exact(core::mem::size_of::<T>()).enumerate() {
+        let value = decode(chunk);
+
+        if !predicate(&value) {
+            return Err(ValidationError::Rejected { index, value });
+        }
+
+        values.push(value);
+    }
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a vector of `T` in native byte order, checking every element with
+/// `predicate` as it is decoded.
+///
+/// This validates the input in the same pass as the conversion, instead of decoding everything
+/// and scanning the result again. Decoding stops at the first element rejected by `predicate`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_validated, ValidationError};
+///
+/// let ids = 7u32.to_ne_bytes().into_iter().chain(900u32.to_ne_bytes()).collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     bytes_to_vec_validated::<u32, _>(&ids, |&id| id <= 100),
+///     Err(ValidationError::Rejected { index: 1, value: 900 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::Rejected`] with the index and value of the first element for which
+/// `predicate` returns `false`, or [`ValidationError::Decode`] wrapping [`Error::LengthMismatch`]
+/// if the length of `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_vec_validated<T: FromBytes, F: FnMut(&T) -> bool>(
+    bytes: &[u8],
+    predicate: F,
+) -> Result<Vec<T>, ValidationError<T>> {
+    decode_validated(bytes, T::from_ne_slice, predicate)
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as little-endian and checking
+/// it with `predicate`.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_validated`].
+pub fn bytes_to_vec_le_validated<T: FromBytes, F: FnMut(&T) -> bool>(
+    bytes: &[u8],
+    predicate: F,
+) -> Result<Vec<T>, ValidationError<T>> {
+    decode_validated(bytes, T::from_le_slice, predicate)
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as big-endian and checking it
+/// with `predicate`.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_validated`].
+pub fn bytes_to_vec_be_validated<T: FromBytes, F: FnMut(&T) -> bool>(
+    bytes: &[u8],
+    predicate: F,
+) -> Result<Vec<T>, ValidationError<T>> {
+    decode_validated(bytes, T::from_be_slice, predicate)
+}
+
+/// Converts a byte slice into a vector of `T` in native byte order, checking that every element
+/// lies within `range`.
+///
+/// This catches values that cannot occur in a well-formed input, such as the counts of a 12-bit
+/// ADC stored in `u16` going past `4095` after a bit shift or corruption, as soon as they are
+/// decoded. Decoding stops at the first element outside of `range`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_le_in_range, ValidationError};
+///
+/// let counts = [0xFF, 0x0F, 0x00, 0x10];
+///
+/// assert_eq!(
+///     bytes_to_vec_le_in_range::<u16>(&counts[..2], 0..=4095).unwrap(),
+///     vec![4095]
+/// );
+/// assert_eq!(
+///     bytes_to_vec_le_in_range::<u16>(&counts, 0..=4095),
+///     Err(ValidationError::Rejected { index: 1, value: 4096 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ValidationError::Rejected`] with the index and value of the first element outside of
+/// `range`, or [`ValidationError::Decode`] wrapping [`Error::LengthMismatch`] if the length of
+/// `bytes` is not a multiple of the size of `T`.
+pub fn bytes_to_vec_in_range<T: FromBytes + PartialOrd>(
+    bytes: &[u8],
+    range: RangeInclusive<T>,
+) -> Result<Vec<T>, ValidationError<T>> {
+    decode_validated(bytes, T::from_ne_slice, |value| range.contains(value))
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as little-endian and checking
+/// that it lies within `range`.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_in_range`].
+pub fn bytes_to_vec_le_in_range<T: FromBytes + PartialOrd>(
+    bytes: &[u8],
+    range: RangeInclusive<T>,
+) -> Result<Vec<T>, ValidationError<T>> {
+    decode_validated(bytes, T::from_le_slice, |value| range.contains(value))
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as big-endian and checking
+/// that it lies within `range`.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_in_range`].
+pub fn bytes_to_vec_be_in_range<T: FromBytes + PartialOrd>(
+    bytes: &[u8],
+    range: RangeInclusive<T>,
+) -> Result<Vec<T>, ValidationError<T>> {
+    decode_validated(bytes, T::from_be_slice, |value| range.contains(value))
+}
+
+fn bytes_to_mapped_with<T: FromBytes, U>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+    f: impl FnMut(T) -> U,
+) -> Result<Vec<U>> {
+    check_length::<T>(bytes)?;
+
+    Ok(bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        .map(decode)
+        .map(f)
+        .collect())
+}
+
+/// Converts a byte slice into a vector of `U` by decoding elements of `T` in native byte order and
+/// applying `f` to each of them.
+///
+/// The transform runs in the same pass as the conversion, filling a single allocation, instead of
+/// decoding into a `Vec<T>` and mapping it into a second vector.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_mapped_le;
+///
+/// // 12-bit ADC counts with a reference voltage of 3.3 V, scaled to millivolts.
+/// let millivolts = bytes_to_mapped_le::<u16, u32>(&[0, 0, 255, 15], |count| {
+///     u32::from(count) * 3300 / 4095
+/// })
+/// .unwrap();
+///
+/// assert_eq!(millivolts, vec![0, 3300]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// without calling `f`.
+pub fn bytes_to_mapped<T: FromBytes, U>(bytes: &[u8], f: impl FnMut(T) -> U) -> Result<Vec<U>> {
+    bytes_to_mapped_with(bytes, T::from_ne_slice, f)
+}
+
+/// Converts a byte slice into a vector of `U` by decoding elements of `T` as little-endian and
+/// applying `f` to each of them.
+///
+/// # Errors
+///
+/// See [`bytes_to_mapped`].
+pub fn bytes_to_mapped_le<T: FromBytes, U>(bytes: &[u8], f: impl FnMut(T) -> U) -> Result<Vec<U>> {
+    bytes_to_mapped_with(bytes, T::from_le_slice, f)
+}
+
+/// Converts a byte slice into a vector of `U` by decoding elements of `T` as big-endian and
+/// applying `f` to each of them.
+///
+/// # Errors
+///
+/// See [`bytes_to_mapped`].
+pub fn bytes_to_mapped_be<T: FromBytes, U>(bytes: &[u8], f: impl FnMut(T) -> U) -> Result<Vec<U>> {
+    bytes_to_mapped_with(bytes, T::from_be_slice, f)
+}
+
+/// Converts an owned byte vector into a vector of `T` in native byte order, reusing the
+/// allocation when possible.
+///
+/// The allocation is reused when `T` has an alignment of 1 and the capacity of `bytes` is a
+/// multiple of the size of `T`, since a `Vec<T>` must be deallocated with the same layout its
+/// buffer was allocated with. In every other case, this falls back to copying the bytes just like
+/// [`bytes_to_vec`].
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::vec_bytes_to_vec;
+///
+/// let bytes = vec![1, 255];
+/// let values = vec_bytes_to_vec::<i8>(bytes).expect("Failed to convert bytes to i8");
+///
+/// assert_eq!(values, vec![1, -1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn vec_bytes_to_vec<T: FromBytes>(bytes: Vec<u8>) -> Result<Vec<T>> {
+    check_length::<T>(&bytes)?;
+
+    let size = core::mem::size_of::<T>();
+
+    if core::mem::align_of::<T>() != 1 || !bytes.capacity().is_multiple_of(size) {
+        return Ok(decode_ne(&bytes));
+    }
+
+    let mut bytes = core::mem::ManuallyDrop::new(bytes);
+
+    // SAFETY: `T` has the same alignment as `u8`, so the allocation has the layout a `Vec<T>`
+    // with this capacity expects. The length and capacity are multiples of the size of `T`, and
+    // every bit pattern is a valid value of a `FromBytes` type.
+    Ok(unsafe {
+        Vec::from_raw_parts(
+            bytes.as_mut_ptr() as *mut T,
+            bytes.len() / size,
+            bytes.capacity() / size,
+        )
+    })
+}
+
+/// Converts as many whole elements of `T` as possible in native byte order, returning them along
+/// with the remaining bytes.
+///
+/// This is useful for formats whose payload isn't element-aligned: instead of failing, the
+/// trailing bytes that don't form a complete element are handed back to the caller.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_vec_le_partial;
+///
+/// let bytes = vec![1, 0, 2, 0, 3];
+/// let (values, rest) = bytes_to_vec_le_partial::<u16>(&bytes);
+///
+/// assert_eq!(values, vec![1, 2]);
+/// assert_eq!(rest, &[3]);
+/// ```
+pub fn bytes_to_vec_partial<T: FromBytes>(bytes: &[u8]) -> (Vec<T>, &[u8]) {
+    let (whole, rest) = split_whole::<T>(bytes);
+
+    (decode_ne(whole), rest)
+}
+
+/// Converts as many whole elements of `T` as possible, decoding each element as little-endian,
+/// and returns them along with the remaining bytes.
+pub fn bytes_to_vec_le_partial<T: FromBytes>(bytes: &[u8]) -> (Vec<T>, &[u8]) {
+    let (whole, rest) = split_whole::<T>(bytes);
+
+    (decode_le(whole), rest)
+}
+
+/// Converts as many whole elements of `T` as possible, decoding each element as big-endian, and
+/// returns them along with the remaining bytes.
+pub fn bytes_to_vec_be_partial<T: FromBytes>(bytes: &[u8]) -> (Vec<T>, &[u8]) {
+    let (whole, rest) = split_whole::<T>(bytes);
+
+    (decode_be(whole), rest)
+}
+
+fn bytes_to_array_with<T: FromBytes, const N: usize>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+) -> Result<[T; N]> {
+    let size = core::mem::size_of::<T>();
+    let expected = N * size;
+
+    if bytes.len() != expected {
+        return Err(Error::UnexpectedLength {
+            len: bytes.len(),
+            expected,
+        });
+    }
+
+    Ok(core::array::from_fn(|index| {
+        decode(&bytes[index * size..(index + 1) * size])
+    }))
+}
+
+/// Converts a byte slice into an array of exactly `N` elements of `T` in native byte order.
+///
+/// This avoids both the allocation of [`bytes_to_vec`] and a manual length check when the number
+/// of elements is known in advance.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_array_le;
+///
+/// let bytes = vec![0, 0, 128, 63, 0, 0, 0, 64, 0, 0, 64, 64];
+/// let vertex: [f32; 3] = bytes_to_array_le(&bytes).expect("Failed to decode vertex");
+///
+/// assert_eq!(vertex, [1.0, 2.0, 3.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` is not exactly
+/// `N * size_of::<T>()`.
+pub fn bytes_to_array<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<[T; N]> {
+    bytes_to_array_with(bytes, T::from_ne_slice)
+}
+
+/// Converts a byte slice into an array of exactly `N` elements of `T`, decoding each element as
+/// little-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` is not exactly
+/// `N * size_of::<T>()`.
+pub fn bytes_to_array_le<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<[T; N]> {
+    bytes_to_array_with(bytes, T::from_le_slice)
+}
+
+/// Converts a byte slice into an array of exactly `N` elements of `T`, decoding each element as
+/// big-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` is not exactly
+/// `N * size_of::<T>()`.
+pub fn bytes_to_array_be<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<[T; N]> {
+    bytes_to_array_with(bytes, T::from_be_slice)
+}
+
+fn bytes_to_chunks_with<T: FromBytes, const N: usize>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<[T; N]>> {
+    const { assert!(N != 0, "Chunk length must be nonzero") };
+
+    let size = core::mem::size_of::<T>();
+    let chunk = N.checked_mul(size).ok_or(Error::LimitExceeded {
+        limit: usize::MAX / size,
+    })?;
+
+    if !bytes.len().is_multiple_of(chunk) {
+        return Err(Error::length_mismatch(bytes.len(), chunk));
+    }
+
+    Ok(bytes
+        .chunks_exact(chunk)
+        .map(|chunk| core::array::from_fn(|index| decode(&chunk[index * size..(index + 1) * size])))
+        .collect())
+}
+
+/// Converts a byte slice into a vector of arrays of `N` elements of `T` in native byte order.
+///
+/// This suits data made of fixed-size groups, such as vertices, RGB pixels or quaternions, and
+/// decodes the groups directly instead of re-chunking the result of [`bytes_to_vec`].
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_chunks;
+///
+/// let pixels: Vec<[u8; 3]> = bytes_to_chunks(&[255, 0, 0, 0, 255, 0]).unwrap();
+///
+/// assert_eq!(pixels, vec![[255, 0, 0], [0, 255, 0]]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of
+/// `N * size_of::<T>()`, or [`Error::LimitExceeded`] with the largest supported `N` if
+/// `N * size_of::<T>()` overflows `usize`.
+///
+/// # Compile-Time Checks
+///
+/// A chunk length of zero is rejected when the call is compiled:
+///
+/// ```compile_fail
+/// let _ = bytes_to_type::bytes_to_chunks::<u8, 0>(&[]);
+/// ```
+pub fn bytes_to_chunks<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<Vec<[T; N]>> {
+    bytes_to_chunks_with(bytes, T::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of arrays of `N` elements of `T`, decoding each element as
+/// little-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_chunks`].
+pub fn bytes_to_chunks_le<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<Vec<[T; N]>> {
+    bytes_to_chunks_with(bytes, T::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of arrays of `N` elements of `T`, decoding each element as
+/// big-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_chunks`].
+pub fn bytes_to_chunks_be<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<Vec<[T; N]>> {
+    bytes_to_chunks_with(bytes, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_converts_bytes_to_any_primitive() {
+        let bytes = vec![0, 0, 128, 63];
+
+        assert_eq!(bytes_to_vec_le::<f32>(&bytes).unwrap(), vec![1.0]);
+        assert_eq!(bytes_to_vec_le::<i16>(&bytes).unwrap(), vec![0, 16256]);
+        assert_eq!(bytes_to_vec_be::<u16>(&bytes).unwrap(), vec![0, 32831]);
+        assert_eq!(bytes_to_vec::<u8>(&bytes).unwrap(), bytes);
+    }
+
+    #[test]
+    fn it_converts_unaligned_bytes() {
+        let bytes = vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+
+        // At most one of these offsets is aligned for `u32`.
+        for offset in 0..4 {
+            let window = &bytes[offset..offset + 8];
+            let expected = vec![
+                u32::from_ne_bytes(window[..4].try_into().unwrap()),
+                u32::from_ne_bytes(window[4..].try_into().unwrap()),
+            ];
+
+            assert_eq!(bytes_to_vec::<u32>(window).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn it_checks_elements_against_a_range() {
+        assert_eq!(
+            bytes_to_vec_be_in_range::<i16>(&[0xFF, 0xFF, 0, 5], -1..=5).unwrap(),
+            vec![-1, 5]
+        );
+        assert!(matches!(
+            bytes_to_vec_in_range::<f32>(&f32::NAN.to_ne_bytes(), f32::MIN..=f32::MAX),
+            Err(ValidationError::Rejected { index: 0, value }) if value.is_nan()
+        ));
+        assert_eq!(
+            bytes_to_vec_le_in_range::<u16>(&[1], 0..=1),
+            Err(ValidationError::Decode(Error::length_mismatch(1, 2)))
+        );
+    }
+
+    #[test]
+    fn it_maps_elements_while_decoding() {
+        let mut calls = 0;
+        let doubled = bytes_to_mapped_be::<u16, u32>(&[0, 1, 1, 0], |value| {
+            calls += 1;
+            u32::from(value) * 2
+        });
+
+        assert_eq!(doubled.unwrap(), vec![2, 512]);
+        assert_eq!(calls, 2);
+        assert_eq!(
+            bytes_to_mapped::<u32, u32>(&[1, 2, 3], |_| unreachable!()),
+            Err(Error::length_mismatch(3, 4))
+        );
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    #[test]
+    fn it_reports_the_conversion_path() {
+        let values = [1u64, 2, 3];
+        let bytes = as_bytes(&values);
+        let opposite = match Endianness::NATIVE {
+            Endianness::Little => Endianness::Big,
+            Endianness::Big => Endianness::Little,
+        };
+
+        assert_eq!(
+            conversion_path::<u64>(bytes, Endianness::NATIVE),
+            ConversionPath::Copied
+        );
+        assert_eq!(
+            conversion_path::<u64>(&bytes[1..17], Endianness::NATIVE),
+            ConversionPath::Realigned
+        );
+        assert_eq!(
+            conversion_path::<crate::U16Le>(bytes, opposite),
+            ConversionPath::Elementwise
+        );
+    }
+
+    #[test]
+    fn it_converts_long_inputs_in_both_byte_orders() {
+        let bytes: Vec<u8> = (0..=255).cycle().take(1000).collect();
+
+        for offset in 0..8 {
+            let window = &bytes[offset..offset + 960];
+            let chunks = window.chunks_exact(8);
+
+            assert_eq!(
+                bytes_to_vec_le::<u64>(window).unwrap(),
+                chunks
+                    .clone()
+                    .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()))
+                    .collect::<Vec<_>>()
+            );
+            assert_eq!(
+                bytes_to_vec_be::<u64>(window).unwrap(),
+                chunks
+                    .map(|chunk| u64::from_be_bytes(chunk.try_into().unwrap()))
+                    .collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn it_converts_bytes_without_checking_the_length() {
+        let bytes = vec![0, 1, 2, 3, 4, 5, 6, 7, 8];
+        let window = &bytes[1..];
+
+        assert_eq!(
+            unsafe { bytes_to_vec_unchecked::<u32>(window) },
+            bytes_to_vec::<u32>(window).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_validates_elements_while_decoding() {
+        let bytes = vec![0, 1, 0, 2, 1, 0];
+        let mut seen = 0;
+
+        assert_eq!(
+            bytes_to_vec_be_validated::<u16, _>(&bytes[..4], |&value| value < 10),
+            Ok(vec![1, 2])
+        );
+        assert_eq!(
+            bytes_to_vec_be_validated::<u16, _>(&bytes, |&value| {
+                seen += 1;
+                value < 10
+            }),
+            Err(ValidationError::Rejected {
+                index: 2,
+                value: 256
+            })
+        );
+        assert_eq!(seen, 3);
+        assert_eq!(
+            bytes_to_vec_le_validated::<u16, _>(&bytes[..3], |_| true),
+            Err(ValidationError::Decode(Error::length_mismatch(3, 2)))
+        );
+    }
+
+    #[test]
+    fn it_borrows_aligned_bytes_as_slice() {
+        let values: Vec<u32> = vec![1, 2, 3];
+        let bytes = unsafe { core::slice::from_raw_parts(values.as_ptr() as *const u8, 12) };
+
+        assert_eq!(bytes_to_slice::<u32>(bytes).unwrap(), &values[..]);
+        assert!(core::ptr::eq(
+            bytes_to_slice::<u32>(bytes).unwrap().as_ptr(),
+            values.as_ptr()
+        ));
+    }
+
+    #[test]
+    fn it_borrows_or_copies_bytes_depending_on_alignment() {
+        let values: Vec<u32> = vec![1, 2, 3, 4];
+        let bytes = unsafe { core::slice::from_raw_parts(values.as_ptr() as *const u8, 16) };
+
+        let aligned = bytes_to_cow::<u32>(&bytes[..12]).unwrap();
+        assert!(matches!(aligned, Cow::Borrowed(_)));
+        assert_eq!(aligned, &values[..3]);
+
+        let unaligned = bytes_to_cow::<u32>(&bytes[1..13]).unwrap();
+        assert!(matches!(unaligned, Cow::Owned(_)));
+        assert_eq!(unaligned, bytes_to_vec::<u32>(&bytes[1..13]).unwrap());
+
+        assert!(bytes_to_cow::<u32>(&bytes[..3]).is_err());
+    }
+
+    #[test]
+    fn it_modifies_values_through_mutable_slice() {
+        let mut values: Vec<u32> = vec![1, 2, 3];
+        let bytes = unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut u8, 12) };
+
+        for value in bytes_to_slice_mut::<u32>(bytes).unwrap() {
+            *value *= 10;
+        }
+        assert!(bytes_to_slice_mut::<u32>(&mut bytes[1..5]).is_err());
+        assert!(bytes_to_slice_mut::<u32>(&mut bytes[..6]).is_err());
+
+        assert_eq!(values, vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_are_not_aligned() {
+        let values: Vec<u32> = vec![1, 2, 3];
+        let bytes = unsafe { core::slice::from_raw_parts(values.as_ptr() as *const u8, 12) };
+
+        assert_eq!(
+            bytes_to_slice::<u32>(&bytes[1..9]),
+            Err(Error::Misaligned { align: 4 })
+        );
+    }
+
+    #[test]
+    fn it_reuses_the_allocation_of_owned_bytes() {
+        let bytes = vec![1, 2, 255];
+        let ptr = bytes.as_ptr();
+        let values = vec_bytes_to_vec::<i8>(bytes).unwrap();
+
+        assert_eq!(values, vec![1, 2, -1]);
+        assert!(core::ptr::eq(values.as_ptr() as *const u8, ptr));
+    }
+
+    #[test]
+    fn it_copies_owned_bytes_if_the_allocation_cannot_be_reused() {
+        let bytes = vec![1, 0, 2, 0];
+
+        assert_eq!(
+            vec_bytes_to_vec::<u16>(bytes.clone()).unwrap(),
+            bytes_to_vec::<u16>(&bytes).unwrap()
+        );
+        assert_eq!(
+            vec_bytes_to_vec::<u16>(vec![1, 2, 3]),
+            Err(Error::length_mismatch(3, 2))
+        );
+    }
+
+    #[test]
+    fn it_returns_remaining_bytes_from_partial_conversions() {
+        let bytes = [0, 1, 0, 2, 9];
+
+        assert_eq!(
+            bytes_to_vec_be_partial::<u16>(&bytes),
+            (vec![1, 2], &[9][..])
+        );
+        assert_eq!(
+            bytes_to_vec_le_partial::<u16>(&bytes),
+            (vec![256, 512], &[9][..])
+        );
+        assert_eq!(
+            bytes_to_vec_partial::<u8>(&bytes),
+            (bytes.to_vec(), &[][..])
+        );
+        assert_eq!(bytes_to_vec_le_partial::<u64>(&bytes), (vec![], &bytes[..]));
+    }
+
+    #[test]
+    fn it_converts_bytes_to_arrays() {
+        let bytes = [0, 1, 0, 2];
+
+        assert_eq!(bytes_to_array_be::<u16, 2>(&bytes), Ok([1, 2]));
+        assert_eq!(bytes_to_array_le::<u16, 2>(&bytes), Ok([256, 512]));
+        assert_eq!(
+            bytes_to_array::<u16, 2>(&bytes),
+            Ok([u16::from_ne_bytes([0, 1]), u16::from_ne_bytes([0, 2])])
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_does_not_match_array_size() {
+        let bytes = [0, 1, 0, 2];
+
+        assert_eq!(
+            bytes_to_array::<u16, 3>(&bytes),
+            Err(Error::UnexpectedLength {
+                len: 4,
+                expected: 6
+            })
+        );
+        assert!(bytes_to_array::<u16, 1>(&bytes).is_err());
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_type_size() {
+        let bytes = vec![1, 2, 3];
+
+        assert_eq!(
+            bytes_to_vec::<u16>(&bytes),
+            Err(Error::LengthMismatch {
+                len: 3,
+                size: 2,
+                remainder: 1
+            })
+        );
+        assert!(bytes_to_vec_le::<u16>(&bytes).is_err());
+        assert!(bytes_to_vec_be::<u16>(&bytes).is_err());
+        assert!(bytes_to_slice::<u16>(&bytes).is_err());
+    }
+
+    #[test]
+    fn it_converts_bytes_to_chunks() {
+        let bytes = [0, 1, 0, 2, 0, 3, 0, 4];
+
+        assert_eq!(
+            bytes_to_chunks_be::<u16, 2>(&bytes).unwrap(),
+            vec![[1, 2], [3, 4]]
+        );
+        assert_eq!(
+            bytes_to_chunks_le::<u16, 4>(&bytes).unwrap(),
+            vec![[256, 512, 768, 1024]]
+        );
+        assert_eq!(
+            bytes_to_chunks::<u16, 3>(&bytes),
+            Err(Error::LengthMismatch {
+                len: 8,
+                size: 6,
+                remainder: 2
+            })
+        );
+    }
+}
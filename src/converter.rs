@@ -0,0 +1,202 @@
+use alloc::vec::Vec;
+
+use crate::convert::{check_length, decode_validated, split_whole};
+use crate::{bytes_to_vec_endian, Endianness, Error, FromBytes, Result, ValidationError};
+
+/// A reusable conversion configuration, set up once and applied to many inputs.
+///
+/// Formats whose byte order or strictness is only known after reading a header would otherwise
+/// have to pick among the `_le`, `_be`, `_partial` and `_validated` function variants at every
+/// call site. A `Converter` captures these choices instead, and is cheap to copy around.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{Converter, Endianness};
+///
+/// let converter = Converter::new()
+///     .endianness(Endianness::Big)
+///     .allow_trailing(true);
+///
+/// assert_eq!(converter.convert::<u16>(&[0, 1, 0, 2, 9]).unwrap(), vec![1, 2]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub struct Converter {
+    endianness: Endianness,
+    allow_trailing: bool,
+    limit: Option<usize>,
+}
+
+impl Converter {
+    /// Creates a converter decoding elements in native byte order, rejecting trailing bytes and
+    /// accepting any number of elements.
+    pub const fn new() -> Self {
+        Converter {
+            endianness: Endianness::NATIVE,
+            allow_trailing: false,
+            limit: None,
+        }
+    }
+
+    /// Sets the byte order in which elements are decoded.
+    pub const fn endianness(mut self, endianness: Endianness) -> Self {
+        self.endianness = endianness;
+        self
+    }
+
+    /// Sets whether trailing bytes that don't form a complete element are ignored instead of
+    /// rejected with [`Error::LengthMismatch`].
+    pub const fn allow_trailing(mut self, allow_trailing: bool) -> Self {
+        self.allow_trailing = allow_trailing;
+        self
+    }
+
+    /// Sets the maximum number of elements accepted, beyond which conversions fail with
+    /// [`Error::LimitExceeded`] before decoding anything.
+    pub const fn limit(mut self, max_elements: usize) -> Self {
+        self.limit = Some(max_elements);
+        self
+    }
+
+    /// Checks every decoded element with `predicate`, rejecting the input at the first element
+    /// for which it returns `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes_to_type::{Converter, ValidationError};
+    ///
+    /// let converter = Converter::new().validate(|&value: &i8| value >= 0);
+    ///
+    /// assert_eq!(converter.convert::<i8>(&[1, 2]).unwrap(), vec![1, 2]);
+    /// assert_eq!(
+    ///     converter.convert::<i8>(&[1, 255]),
+    ///     Err(ValidationError::Rejected { index: 1, value: -1 })
+    /// );
+    /// ```
+    pub const fn validate<F>(self, predicate: F) -> ValidatingConverter<F> {
+        ValidatingConverter {
+            converter: self,
+            predicate,
+        }
+    }
+
+    /// Returns the bytes holding the elements to decode, after checking the length of `bytes`.
+    fn elements<'a, T>(&self, bytes: &'a [u8]) -> Result<&'a [u8]> {
+        let bytes = if self.allow_trailing {
+            split_whole::<T>(bytes).0
+        } else {
+            check_length::<T>(bytes)?;
+            bytes
+        };
+
+        match self.limit {
+            Some(limit) if bytes.len() / core::mem::size_of::<T>() > limit => {
+                Err(Error::LimitExceeded { limit })
+            }
+            _ => Ok(bytes),
+        }
+    }
+
+    /// Converts a byte slice into a vector of `T` with this configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of
+    /// `T` and trailing bytes are not allowed, or [`Error::LimitExceeded`] if `bytes` holds more
+    /// elements than the limit.
+    pub fn convert<T: FromBytes>(&self, bytes: &[u8]) -> Result<Vec<T>> {
+        bytes_to_vec_endian(self.elements::<T>(bytes)?, self.endianness)
+    }
+}
+
+impl Default for Converter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`Converter`] which also checks every decoded element with a predicate, returned by
+/// [`Converter::validate`].
+#[derive(Debug, Clone, Copy)]
+pub struct ValidatingConverter<F> {
+    converter: Converter,
+    predicate: F,
+}
+
+impl<F> ValidatingConverter<F> {
+    /// Converts a byte slice into a vector of `T` with this configuration, checking every element
+    /// with the predicate as it is decoded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ValidationError::Rejected`] with the index and value of the first element
+    /// rejected by the predicate, or [`ValidationError::Decode`] wrapping any error returned by
+    /// [`Converter::convert`].
+    pub fn convert<T: FromBytes>(&self, bytes: &[u8]) -> Result<Vec<T>, ValidationError<T>>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let decode = match self.converter.endianness {
+            Endianness::Little => T::from_le_slice,
+            Endianness::Big => T::from_be_slice,
+        };
+
+        decode_validated(
+            self.converter.elements::<T>(bytes)?,
+            decode,
+            &self.predicate,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_applies_the_configuration_to_every_conversion() {
+        let converter = Converter::default().endianness(Endianness::Little);
+
+        assert_eq!(converter.convert::<u16>(&[1, 0]).unwrap(), vec![1]);
+        assert_eq!(
+            converter.convert::<u16>(&[1, 0, 2]),
+            Err(Error::length_mismatch(3, 2))
+        );
+
+        let converter = converter.allow_trailing(true).limit(2);
+
+        assert_eq!(converter.convert::<u16>(&[1, 0, 2]).unwrap(), vec![1]);
+        assert_eq!(
+            converter.convert::<u8>(&[1, 2, 3]),
+            Err(Error::LimitExceeded { limit: 2 })
+        );
+    }
+
+    #[test]
+    fn it_validates_elements_with_the_configuration() {
+        let converter = Converter::new()
+            .endianness(Endianness::Big)
+            .allow_trailing(true)
+            .validate(|&value: &u16| value < 10);
+
+        assert_eq!(converter.convert::<u16>(&[0, 9, 0]).unwrap(), vec![9]);
+        assert_eq!(
+            converter.convert::<u16>(&[0, 1, 1, 0]),
+            Err(ValidationError::Rejected {
+                index: 1,
+                value: 256
+            })
+        );
+        assert_eq!(
+            Converter::new()
+                .limit(0)
+                .validate(|_: &u8| true)
+                .convert::<u8>(&[1]),
+            Err(ValidationError::Decode(Error::LimitExceeded { limit: 0 }))
+        );
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{bytes_to_array, bytes_to_array_be, bytes_to_array_le, Error, FromBytes, Result};
+
+/// A sequential reader decoding values of mixed types from a byte slice.
+///
+/// `ByteCursor` keeps track of its position in the input, so heterogeneous binary headers can be
+/// walked field by field without slicing the input by hand. A read that runs past the end of the
+/// input returns [`Error::UnexpectedEnd`] with the offset of the failing read, and leaves the
+/// cursor where it was.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::ByteCursor;
+///
+/// let bytes = [0xCA, 0xFE, 2, 0, 0, 0, 0, 0, 128, 63, 0, 0, 0, 64];
+/// let mut cursor = ByteCursor::new(&bytes);
+///
+/// assert_eq!(cursor.read_be::<u16>().unwrap(), 0xCAFE);
+/// assert_eq!(cursor.read_le::<u16>().unwrap(), 2);
+/// cursor.skip(2).unwrap();
+/// assert_eq!(cursor.read_array_le::<f32, 2>().unwrap(), [1.0, 2.0]);
+/// assert!(cursor.remaining().is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ByteCursor<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    /// Creates a cursor positioned at the start of `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Creates a cursor positioned at `position` in `bytes`, as if that many bytes were consumed.
+    pub(crate) fn at(bytes: &'a [u8], position: usize) -> Self {
+        Self { bytes, position }
+    }
+
+    /// Returns the number of bytes consumed so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the bytes that have not been consumed yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        &self.bytes[self.position..]
+    }
+
+    /// Returns whether all of the input has been consumed.
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    /// Consumes the next `len` bytes and returns them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `len` bytes remain.
+    pub fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let remaining = self.remaining();
+
+        if remaining.len() < len {
+            return Err(Error::UnexpectedEnd {
+                offset: self.position,
+                needed: len,
+                remaining: remaining.len(),
+            });
+        }
+
+        self.position += len;
+
+        Ok(&remaining[..len])
+    }
+
+    /// Skips the next `len` bytes, such as padding or reserved fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `len` bytes remain.
+    pub fn skip(&mut self, len: usize) -> Result<()> {
+        self.read_bytes(len).map(|_| ())
+    }
+
+    /// Reads a value of `T` in native byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `size_of::<T>()` bytes remain.
+    pub fn read<T: FromBytes>(&mut self) -> Result<T> {
+        self.read_bytes(core::mem::size_of::<T>())
+            .map(T::from_ne_slice)
+    }
+
+    /// Reads a little-endian value of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `size_of::<T>()` bytes remain.
+    pub fn read_le<T: FromBytes>(&mut self) -> Result<T> {
+        self.read_bytes(core::mem::size_of::<T>())
+            .map(T::from_le_slice)
+    }
+
+    /// Reads a big-endian value of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `size_of::<T>()` bytes remain.
+    pub fn read_be<T: FromBytes>(&mut self) -> Result<T> {
+        self.read_bytes(core::mem::size_of::<T>())
+            .map(T::from_be_slice)
+    }
+
+    /// Reads an array of `N` values of `T` in native byte order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `size_of::<[T; N]>()` bytes remain.
+    pub fn read_array<T: FromBytes, const N: usize>(&mut self) -> Result<[T; N]> {
+        bytes_to_array(self.read_bytes(core::mem::size_of::<[T; N]>())?)
+    }
+
+    /// Reads an array of `N` little-endian values of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `size_of::<[T; N]>()` bytes remain.
+    pub fn read_array_le<T: FromBytes, const N: usize>(&mut self) -> Result<[T; N]> {
+        bytes_to_array_le(self.read_bytes(core::mem::size_of::<[T; N]>())?)
+    }
+
+    /// Reads an array of `N` big-endian values of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnexpectedEnd`] if fewer than `size_of::<[T; N]>()` bytes remain.
+    pub fn read_array_be<T: FromBytes, const N: usize>(&mut self) -> Result<[T; N]> {
+        bytes_to_array_be(self.read_bytes(core::mem::size_of::<[T; N]>())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_reads_values_sequentially() {
+        let bytes = [1, 0, 2, 3, 0, 0, 0, 0, 4, 0, 5, 0];
+        let mut cursor = ByteCursor::new(&bytes);
+
+        assert_eq!(cursor.read::<u8>().unwrap(), 1);
+        assert_eq!(cursor.read_be::<u16>().unwrap(), 2);
+        assert_eq!(cursor.read_le::<u8>().unwrap(), 3);
+        cursor.skip(3).unwrap();
+        assert_eq!(cursor.position(), 7);
+        assert_eq!(cursor.read_array_be::<i16, 2>().unwrap(), [4, 5]);
+        assert_eq!(cursor.remaining(), &[0]);
+        assert!(!cursor.is_empty());
+    }
+
+    #[test]
+    fn it_returns_error_with_offset_and_does_not_advance_past_the_end() {
+        let bytes = [1, 2, 3];
+        let mut cursor = ByteCursor::new(&bytes);
+
+        cursor.skip(1).unwrap();
+
+        assert_eq!(
+            cursor.read_le::<u32>(),
+            Err(Error::UnexpectedEnd {
+                offset: 1,
+                needed: 4,
+                remaining: 2
+            })
+        );
+        assert_eq!(cursor.position(), 1);
+        assert_eq!(cursor.read_le::<u16>().unwrap(), 0x0302);
+        assert!(cursor.is_empty());
+    }
+}
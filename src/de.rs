@@ -0,0 +1,223 @@
+use alloc::string::{String, ToString};
+use core::fmt;
+
+use serde::de::value::SeqDeserializer;
+use serde::de::{self, Deserialize, Deserializer, IntoDeserializer, Visitor};
+
+use crate::convert::check_length;
+use crate::{Error, FromBytes};
+
+/// A [`serde::Deserializer`] presenting a byte buffer as a sequence of elements of `T`, available
+/// with the `serde` feature.
+///
+/// Any type deserializable from a sequence of `T` can be produced, such as `Vec<T>`, arrays, tuples
+/// and newtype wrappers around them, so code generic over [`Deserialize`] can consume raw typed
+/// buffers.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::ElementDeserializer;
+/// use serde::Deserialize;
+///
+/// let bytes = [0, 1, 0, 2, 0, 3];
+///
+/// let values = Vec::<u16>::deserialize(ElementDeserializer::<u16>::new_be(&bytes)).unwrap();
+/// assert_eq!(values, vec![1, 2, 3]);
+///
+/// let values = <[u16; 3]>::deserialize(ElementDeserializer::<u16>::new_le(&bytes)).unwrap();
+/// assert_eq!(values, [256, 512, 768]);
+/// ```
+///
+/// # Errors
+///
+/// Deserialization fails with [`DeserializeError::Decode`] if the length of the buffer is not a
+/// multiple of the size of `T`, and with [`DeserializeError::Custom`] if the target type rejects
+/// the sequence, for example because an array is longer or shorter than the buffer.
+#[derive(Debug, Clone)]
+pub struct ElementDeserializer<'de, T> {
+    bytes: &'de [u8],
+    decode: fn(&[u8]) -> T,
+}
+
+impl<'de, T: FromBytes> ElementDeserializer<'de, T> {
+    /// Creates a deserializer that decodes elements in native byte order.
+    pub fn new(bytes: &'de [u8]) -> Self {
+        Self {
+            bytes,
+            decode: T::from_ne_slice,
+        }
+    }
+
+    /// Creates a deserializer that decodes elements as little-endian.
+    pub fn new_le(bytes: &'de [u8]) -> Self {
+        Self {
+            bytes,
+            decode: T::from_le_slice,
+        }
+    }
+
+    /// Creates a deserializer that decodes elements as big-endian.
+    pub fn new_be(bytes: &'de [u8]) -> Self {
+        Self {
+            bytes,
+            decode: T::from_be_slice,
+        }
+    }
+}
+
+impl<'de, T> Deserializer<'de> for ElementDeserializer<'de, T>
+where
+    T: FromBytes + IntoDeserializer<'de, DeserializeError>,
+{
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeserializeError> {
+        check_length::<T>(self.bytes)?;
+
+        let elements = self
+            .bytes
+            .chunks_exact(core::mem::size_of::<T>())
+            .map(self.decode);
+        let mut sequence = SeqDeserializer::new(elements);
+        let value = visitor.visit_seq(&mut sequence)?;
+        sequence.end()?;
+
+        Ok(value)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeserializeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf option
+        unit unit_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Deserializes a value of type `D` from a byte buffer holding elements of `T` in native byte
+/// order, available with the `serde` feature.
+///
+/// See [`ElementDeserializer`] for details.
+///
+/// # Errors
+///
+/// Returns a [`DeserializeError`] if the buffer cannot be decoded or `D` rejects its elements.
+pub fn deserialize_elements<'de, T, D>(bytes: &'de [u8]) -> Result<D, DeserializeError>
+where
+    T: FromBytes + IntoDeserializer<'de, DeserializeError>,
+    D: Deserialize<'de>,
+{
+    D::deserialize(ElementDeserializer::<T>::new(bytes))
+}
+
+/// Deserializes a value of type `D` from a byte buffer holding little-endian elements of `T`,
+/// available with the `serde` feature.
+///
+/// # Errors
+///
+/// Returns a [`DeserializeError`] if the buffer cannot be decoded or `D` rejects its elements.
+pub fn deserialize_elements_le<'de, T, D>(bytes: &'de [u8]) -> Result<D, DeserializeError>
+where
+    T: FromBytes + IntoDeserializer<'de, DeserializeError>,
+    D: Deserialize<'de>,
+{
+    D::deserialize(ElementDeserializer::<T>::new_le(bytes))
+}
+
+/// Deserializes a value of type `D` from a byte buffer holding big-endian elements of `T`,
+/// available with the `serde` feature.
+///
+/// # Errors
+///
+/// Returns a [`DeserializeError`] if the buffer cannot be decoded or `D` rejects its elements.
+pub fn deserialize_elements_be<'de, T, D>(bytes: &'de [u8]) -> Result<D, DeserializeError>
+where
+    T: FromBytes + IntoDeserializer<'de, DeserializeError>,
+    D: Deserialize<'de>,
+{
+    D::deserialize(ElementDeserializer::<T>::new_be(bytes))
+}
+
+/// The error type returned by [`ElementDeserializer`], available with the `serde` feature.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeserializeError {
+    /// The buffer could not be decoded.
+    Decode(Error),
+    /// The target type rejected the decoded elements.
+    Custom(String),
+}
+
+impl From<Error> for DeserializeError {
+    fn from(error: Error) -> Self {
+        DeserializeError::Decode(error)
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeserializeError::Decode(error) => error.fmt(f),
+            DeserializeError::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl core::error::Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            DeserializeError::Decode(error) => Some(error),
+            DeserializeError::Custom(_) => None,
+        }
+    }
+}
+
+impl de::Error for DeserializeError {
+    fn custom<M: fmt::Display>(message: M) -> Self {
+        DeserializeError::Custom(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Samples(Vec<f64>);
+
+    #[test]
+    fn it_deserializes_newtype_wrappers() {
+        let bytes = [1.5f64.to_le_bytes(), (-2.0f64).to_le_bytes()].concat();
+
+        assert_eq!(
+            deserialize_elements_le::<f64, Samples>(&bytes),
+            Ok(Samples(vec![1.5, -2.0]))
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_length_mismatch() {
+        assert_eq!(
+            deserialize_elements::<u32, Vec<u32>>(&[0; 6]),
+            Err(DeserializeError::Decode(Error::LengthMismatch {
+                len: 6,
+                size: 4,
+                remainder: 2
+            }))
+        );
+        assert!(matches!(
+            deserialize_elements_be::<u8, [u8; 2]>(&[1, 2, 3]),
+            Err(DeserializeError::Custom(_))
+        ));
+    }
+}
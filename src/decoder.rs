@@ -0,0 +1,117 @@
+use alloc::vec::Vec;
+
+use crate::FromBytes;
+
+/// A streaming decoder that converts bytes arriving in arbitrary-sized chunks into values of `T`.
+///
+/// Unlike the slice-based conversion functions, the decoder never fails on lengths that aren't a
+/// multiple of the size of `T`. Trailing bytes that don't form a complete element are kept in an
+/// internal buffer until the rest of the element is fed.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::Decoder;
+///
+/// let mut decoder = Decoder::<u16>::new_be();
+///
+/// decoder.feed(&[0, 1, 0]);
+/// assert_eq!(decoder.drain(), vec![1]);
+///
+/// decoder.feed(&[2]);
+/// assert_eq!(decoder.drain(), vec![2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder<T> {
+    buffer: Vec<u8>,
+    decode: fn(&[u8]) -> T,
+}
+
+impl<T: FromBytes> Decoder<T> {
+    /// Creates a decoder that decodes elements in native byte order.
+    pub fn new() -> Self {
+        Self::with_decode(T::from_ne_slice)
+    }
+
+    /// Creates a decoder that decodes elements as little-endian.
+    pub fn new_le() -> Self {
+        Self::with_decode(T::from_le_slice)
+    }
+
+    /// Creates a decoder that decodes elements as big-endian.
+    pub fn new_be() -> Self {
+        Self::with_decode(T::from_be_slice)
+    }
+
+    fn with_decode(decode: fn(&[u8]) -> T) -> Self {
+        Self {
+            buffer: Vec::new(),
+            decode,
+        }
+    }
+
+    /// Appends bytes to the internal buffer.
+    pub fn feed(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    /// Decodes and returns all complete elements fed so far.
+    ///
+    /// Bytes of an incomplete trailing element stay buffered for the next call.
+    pub fn drain(&mut self) -> Vec<T> {
+        let size = core::mem::size_of::<T>();
+        let complete = self.buffer.len() - self.buffer.len() % size;
+
+        let values = self.buffer[..complete]
+            .chunks_exact(size)
+            .map(self.decode)
+            .collect();
+        self.buffer.drain(..complete);
+
+        values
+    }
+
+    /// Returns the number of buffered bytes that have not been drained yet.
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+impl<T: FromBytes> Default for Decoder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_buffers_partial_elements_between_chunks() {
+        let mut decoder = Decoder::<u32>::new_le();
+
+        decoder.feed(&[1, 0]);
+        assert_eq!(decoder.drain(), Vec::<u32>::new());
+        assert_eq!(decoder.pending(), 2);
+
+        decoder.feed(&[0, 0, 2, 0, 0, 0, 3]);
+        assert_eq!(decoder.drain(), vec![1, 2]);
+        assert_eq!(decoder.pending(), 1);
+
+        decoder.feed(&[0, 0, 0]);
+        assert_eq!(decoder.drain(), vec![3]);
+        assert_eq!(decoder.pending(), 0);
+    }
+
+    #[test]
+    fn it_decodes_in_native_byte_order_by_default() {
+        let mut decoder = Decoder::<u16>::default();
+
+        decoder.feed(&[1, 2]);
+
+        assert_eq!(decoder.drain(), vec![u16::from_ne_bytes([1, 2])]);
+    }
+}
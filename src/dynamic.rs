@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+
+use crate::{bytes_to_vec_endian, Endianness, Result};
+
+macro_rules! scalar_types {
+    ($($(#[$attr:meta])* $variant:ident($type:ty),)*) => {
+        /// The type of the elements of a buffer, selected at runtime.
+        ///
+        /// This is used with [`decode_dynamic`] by tools that learn the element type from the data
+        /// itself, such as from the header of a NumPy or HDF5 file.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+        #[non_exhaustive]
+        pub enum ScalarType {
+            $(
+                $(#[$attr])*
+                #[doc = concat!("`", stringify!($type), "`.")]
+                $variant,
+            )*
+        }
+
+        impl ScalarType {
+            /// Returns the size of an element of this type, in bytes.
+            pub const fn size(self) -> usize {
+                match self {
+                    $($(#[$attr])* ScalarType::$variant => core::mem::size_of::<$type>(),)*
+                }
+            }
+        }
+
+        /// A vector of elements whose type is selected at runtime, returned by [`decode_dynamic`].
+        #[derive(Debug, Clone, PartialEq)]
+        #[non_exhaustive]
+        pub enum TypedVec {
+            $(
+                $(#[$attr])*
+                #[doc = concat!("A vector of `", stringify!($type), "`.")]
+                $variant(Vec<$type>),
+            )*
+        }
+
+        impl TypedVec {
+            /// Returns the type of the elements.
+            pub fn scalar_type(&self) -> ScalarType {
+                match self {
+                    $($(#[$attr])* TypedVec::$variant(_) => ScalarType::$variant,)*
+                }
+            }
+
+            /// Returns the number of elements.
+            pub fn len(&self) -> usize {
+                match self {
+                    $($(#[$attr])* TypedVec::$variant(values) => values.len(),)*
+                }
+            }
+
+            /// Returns `true` if there are no elements.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+        }
+
+        $(
+            $(#[$attr])*
+            impl From<Vec<$type>> for TypedVec {
+                fn from(values: Vec<$type>) -> Self {
+                    TypedVec::$variant(values)
+                }
+            }
+        )*
+
+        /// Converts a byte slice into a vector of elements of a type selected at runtime, decoding
+        /// each element in the given byte order.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use bytes_to_type::{decode_dynamic, Endianness, ScalarType, TypedVec};
+        ///
+        /// let values = decode_dynamic(&[0, 0, 128, 63], ScalarType::F32, Endianness::Little);
+        ///
+        /// assert_eq!(values.unwrap(), TypedVec::F32(vec![1.0]));
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of
+        /// `bytes` is not a multiple of the size of the element type.
+        pub fn decode_dynamic(
+            bytes: &[u8],
+            scalar_type: ScalarType,
+            endianness: Endianness,
+        ) -> Result<TypedVec> {
+            Ok(match scalar_type {
+                $(
+                    $(#[$attr])*
+                    ScalarType::$variant => {
+                        TypedVec::$variant(bytes_to_vec_endian(bytes, endianness)?)
+                    }
+                )*
+            })
+        }
+    };
+}
+
+scalar_types! {
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    U128(u128),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    I128(i128),
+    #[cfg(feature = "half")]
+    F16(half::f16),
+    #[cfg(feature = "half")]
+    BF16(half::bf16),
+    F32(f32),
+    F64(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_decodes_the_selected_type() {
+        let bytes = [1, 0, 0, 2];
+
+        let values = decode_dynamic(&bytes, ScalarType::U16, Endianness::Big).unwrap();
+        assert_eq!(values, TypedVec::U16(vec![0x0100, 0x0002]));
+        assert_eq!(values.scalar_type(), ScalarType::U16);
+        assert_eq!(values.len(), 2);
+
+        assert_eq!(
+            decode_dynamic(&bytes, ScalarType::I8, Endianness::Little).unwrap(),
+            TypedVec::from(vec![1i8, 0, 0, 2])
+        );
+        assert_eq!(
+            decode_dynamic(&bytes, ScalarType::F64, Endianness::Little),
+            Err(Error::length_mismatch(4, 8))
+        );
+        assert_eq!(ScalarType::I128.size(), 16);
+    }
+}
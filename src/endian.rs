@@ -0,0 +1,292 @@
+use alloc::vec::Vec;
+
+use crate::from_bytes::sealed;
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, FromBytes, Result};
+
+/// A byte order, selected at compile time with one of the [`LittleEndian`], [`BigEndian`] and
+/// [`NativeEndian`] marker types.
+///
+/// Code generic over `ByteOrder` is written once and instantiated for each protocol, instead of
+/// dispatching between the `_le` and `_be` functions by name. This trait is sealed and cannot be
+/// implemented outside of this crate.
+pub trait ByteOrder: sealed::Sealed {
+    /// Decodes a value from exactly `size_of::<T>()` bytes in this byte order.
+    #[doc(hidden)]
+    fn decode<T: FromBytes>(bytes: &[u8]) -> T;
+
+    /// Converts a byte slice into a vector of `T` in this byte order.
+    #[doc(hidden)]
+    fn bytes_to_vec<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>>;
+}
+
+/// Little-endian byte order, the least significant byte first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LittleEndian {}
+
+/// Big-endian byte order, the most significant byte first, as used by network protocols.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BigEndian {}
+
+/// The native byte order of the target platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NativeEndian {}
+
+impl sealed::Sealed for LittleEndian {}
+impl sealed::Sealed for BigEndian {}
+impl sealed::Sealed for NativeEndian {}
+
+impl ByteOrder for LittleEndian {
+    #[inline]
+    fn decode<T: FromBytes>(bytes: &[u8]) -> T {
+        T::from_le_slice(bytes)
+    }
+
+    fn bytes_to_vec<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+        bytes_to_vec_le(bytes)
+    }
+}
+
+impl ByteOrder for BigEndian {
+    #[inline]
+    fn decode<T: FromBytes>(bytes: &[u8]) -> T {
+        T::from_be_slice(bytes)
+    }
+
+    fn bytes_to_vec<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+        bytes_to_vec_be(bytes)
+    }
+}
+
+impl ByteOrder for NativeEndian {
+    #[inline]
+    fn decode<T: FromBytes>(bytes: &[u8]) -> T {
+        T::from_ne_slice(bytes)
+    }
+
+    fn bytes_to_vec<T: FromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+        bytes_to_vec(bytes)
+    }
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element in the byte order `O`.
+///
+/// This is equivalent to [`bytes_to_vec_le`], [`bytes_to_vec_be`] or [`bytes_to_vec`] for
+/// [`LittleEndian`], [`BigEndian`] and [`NativeEndian`] respectively, and takes the same fast
+/// paths.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_ordered, BigEndian, ByteOrder, LittleEndian};
+///
+/// fn lengths<O: ByteOrder>(bytes: &[u8]) -> Vec<u16> {
+///     bytes_to_vec_ordered::<u16, O>(bytes).unwrap()
+/// }
+///
+/// assert_eq!(lengths::<BigEndian>(&[0, 1]), vec![1]);
+/// assert_eq!(lengths::<LittleEndian>(&[0, 1]), vec![256]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_vec_ordered<T: FromBytes, O: ByteOrder>(bytes: &[u8]) -> Result<Vec<T>> {
+    O::bytes_to_vec(bytes)
+}
+
+/// A byte order selected at runtime, such as one read from the header of a file.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_endian, Endianness};
+///
+/// // TIFF files start with "II" for little-endian data and "MM" for big-endian data.
+/// let file = b"MM\x00\x2A";
+/// let order = if file.starts_with(b"II") { Endianness::Little } else { Endianness::Big };
+///
+/// assert_eq!(bytes_to_vec_endian::<u16>(&file[2..], order).unwrap(), vec![42]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum Endianness {
+    /// Little-endian byte order, the least significant byte first.
+    Little,
+    /// Big-endian byte order, the most significant byte first.
+    Big,
+}
+
+impl Endianness {
+    /// The native byte order of the target platform.
+    pub const NATIVE: Endianness = if cfg!(target_endian = "little") {
+        Endianness::Little
+    } else {
+        Endianness::Big
+    };
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element in a byte order chosen at
+/// runtime.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_vec_endian<T: FromBytes>(bytes: &[u8], endianness: Endianness) -> Result<Vec<T>> {
+    match endianness {
+        Endianness::Little => bytes_to_vec_le(bytes),
+        Endianness::Big => bytes_to_vec_be(bytes),
+    }
+}
+
+/// Detects the byte order of UTF-16 text or of another format from its leading byte order mark,
+/// returning the byte order along with the bytes after the mark.
+///
+/// The mark is the character U+FEFF, stored as `[0xFF, 0xFE]` in little-endian data and as
+/// `[0xFE, 0xFF]` in big-endian data. `None` is returned if `bytes` doesn't start with either.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_endian, detect_bom, Endianness};
+///
+/// let (order, text) = detect_bom(&[0xFF, 0xFE, b'h', 0, b'i', 0]).unwrap();
+///
+/// assert_eq!(order, Endianness::Little);
+/// assert_eq!(bytes_to_vec_endian::<u16>(text, order).unwrap(), vec![0x68, 0x69]);
+/// ```
+pub fn detect_bom(bytes: &[u8]) -> Option<(Endianness, &[u8])> {
+    detect_marker(bytes, &[0xFF, 0xFE], &[0xFE, 0xFF])
+}
+
+/// Detects the byte order of `bytes` from a leading marker, returning the byte order along with
+/// the bytes after the marker.
+///
+/// `little` and `big` are the markers that start little-endian and big-endian data, such as `II`
+/// and `MM` for TIFF files. `None` is returned if `bytes` doesn't start with either.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{detect_marker, Endianness};
+///
+/// let (order, rest) = detect_marker(b"MM\x00\x2A", b"II", b"MM").unwrap();
+///
+/// assert_eq!(order, Endianness::Big);
+/// assert_eq!(rest, b"\x00\x2A");
+/// ```
+pub fn detect_marker<'a>(
+    bytes: &'a [u8],
+    little: &[u8],
+    big: &[u8],
+) -> Option<(Endianness, &'a [u8])> {
+    if let Some(rest) = bytes.strip_prefix(little) {
+        Some((Endianness::Little, rest))
+    } else {
+        bytes.strip_prefix(big).map(|rest| (Endianness::Big, rest))
+    }
+}
+
+/// Detects the byte order of `bytes` from a leading magic number, returning the byte order along
+/// with the bytes after the magic number.
+///
+/// The first `size_of::<T>()` bytes are decoded in both byte orders and compared to `magic`, as
+/// done for pcap captures and several seismic formats. `None` is returned if neither matches. For a
+/// magic number which reads the same in both byte orders, little-endian is reported.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{detect_magic, Endianness};
+///
+/// let capture = [0xD4, 0xC3, 0xB2, 0xA1, 2, 0];
+/// let (order, rest) = detect_magic(&capture, 0xA1B2C3D4u32).unwrap();
+///
+/// assert_eq!(order, Endianness::Little);
+/// assert_eq!(rest, &[2, 0]);
+/// ```
+pub fn detect_magic<T: FromBytes + PartialEq>(
+    bytes: &[u8],
+    magic: T,
+) -> Option<(Endianness, &[u8])> {
+    let (head, rest) = bytes.split_at_checked(core::mem::size_of::<T>())?;
+
+    if T::from_le_slice(head) == magic {
+        Some((Endianness::Little, rest))
+    } else if T::from_be_slice(head) == magic {
+        Some((Endianness::Big, rest))
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_in_the_selected_byte_order() {
+        let bytes = [0, 0, 0, 1];
+
+        assert_eq!(
+            bytes_to_vec_ordered::<u32, BigEndian>(&bytes).unwrap(),
+            vec![1]
+        );
+        assert_eq!(
+            bytes_to_vec_ordered::<u32, LittleEndian>(&bytes).unwrap(),
+            vec![1 << 24]
+        );
+        assert_eq!(
+            bytes_to_vec_ordered::<u32, NativeEndian>(&bytes).unwrap(),
+            vec![u32::from_ne_bytes(bytes)]
+        );
+        assert_eq!(BigEndian::decode::<i16>(&[0xFF, 0xFE]), -2);
+        assert!(bytes_to_vec_ordered::<u32, NativeEndian>(&bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn it_decodes_in_a_runtime_byte_order() {
+        let bytes = [1, 0];
+
+        assert_eq!(
+            bytes_to_vec_endian::<u16>(&bytes, Endianness::Little).unwrap(),
+            vec![1]
+        );
+        assert_eq!(
+            bytes_to_vec_endian::<u16>(&bytes, Endianness::Big).unwrap(),
+            vec![256]
+        );
+        assert_eq!(
+            bytes_to_vec_endian::<u16>(&bytes, Endianness::NATIVE).unwrap(),
+            vec![u16::from_ne_bytes(bytes)]
+        );
+    }
+
+    #[test]
+    fn it_detects_byte_order_marks() {
+        assert_eq!(
+            detect_bom(&[0xFE, 0xFF, 0, 1]),
+            Some((Endianness::Big, &[0, 1][..]))
+        );
+        assert_eq!(detect_bom(&[0xFE]), None);
+        assert_eq!(detect_bom(&[0, 1]), None);
+    }
+
+    #[test]
+    fn it_detects_magic_numbers() {
+        assert_eq!(
+            detect_magic(&[0xA1, 0xB2, 0xC3, 0xD4], 0xA1B2C3D4u32),
+            Some((Endianness::Big, &[][..]))
+        );
+        assert_eq!(detect_magic(&[0xA1, 0xB2, 0xC3], 0xA1B2C3D4u32), None);
+        assert_eq!(detect_magic(&[1, 2, 3, 4], 0xA1B2C3D4u32), None);
+        assert_eq!(
+            detect_marker(b"II*\0", b"II", b"MM"),
+            Some((Endianness::Little, &b"*\0"[..]))
+        );
+        assert_eq!(detect_marker(b"XX", b"II", b"MM"), None);
+    }
+}
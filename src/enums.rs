@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use crate::convert::check_length;
+use crate::{
+    BigEndian, ByteOrder, CheckedElements, Error, FromBytes, LittleEndian, NativeEndian, Result,
+};
+
+/// A fieldless enum that can be decoded from its underlying integers, checking each against the
+/// known discriminants.
+///
+/// Reinterpreting an integer that is not a discriminant as an enum is undefined behavior, so enums
+/// cannot implement [`FromBytes`]. This trait is implemented by the
+/// [`bytes_to_enum!`](macro@crate::bytes_to_enum) macro, which generates the enum along with it.
+pub trait EnumFromBytes: Sized {
+    /// The name of the enum, reported by [`Error::InvalidDiscriminant`].
+    const NAME: &'static str;
+
+    /// The integer type given in the `#[repr]` of the enum.
+    type Repr: FromBytes + Into<i128>;
+
+    /// Returns the variant with the discriminant `value`, or `None` if there is none.
+    fn from_repr(value: Self::Repr) -> Option<Self>;
+}
+
+fn check_enum<T: EnumFromBytes, O: ByteOrder>(index: usize, chunk: &[u8]) -> Result<T> {
+    let value = O::decode::<T::Repr>(chunk);
+
+    T::from_repr(value).ok_or_else(|| Error::InvalidDiscriminant {
+        name: T::NAME,
+        offset: index * core::mem::size_of::<T::Repr>(),
+        value: value.into(),
+    })
+}
+
+fn bytes_to_enum_with<T: EnumFromBytes>(
+    bytes: &[u8],
+    check: fn(usize, &[u8]) -> Result<T>,
+) -> Result<Vec<T>> {
+    iter_enum_with(bytes, check)?.collect()
+}
+
+fn iter_enum_with<T: EnumFromBytes>(
+    bytes: &[u8],
+    check: fn(usize, &[u8]) -> Result<T>,
+) -> Result<CheckedElements<'_, T>> {
+    check_length::<T::Repr>(bytes)?;
+
+    Ok(CheckedElements::new(
+        bytes,
+        core::mem::size_of::<T::Repr>(),
+        check,
+    ))
+}
+
+/// Converts a byte slice into a vector of an enum generated by
+/// [`bytes_to_enum!`](macro@crate::bytes_to_enum), decoding each discriminant in native byte
+/// order.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_enum, bytes_to_enum_be, Error};
+///
+/// bytes_to_enum! {
+///     #[derive(Debug, PartialEq)]
+///     MessageType: u16 {
+///         Request = 1,
+///         Response = 2,
+///     }
+/// }
+///
+/// assert_eq!(
+///     bytes_to_enum_be::<MessageType>(&[0, 2, 0, 1]).unwrap(),
+///     vec![MessageType::Response, MessageType::Request]
+/// );
+/// assert_eq!(
+///     bytes_to_enum_be::<MessageType>(&[0, 1, 0, 9]),
+///     Err(Error::InvalidDiscriminant { name: "MessageType", offset: 2, value: 9 })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of the
+/// underlying integer type, or [`Error::InvalidDiscriminant`] for the first value that is not a
+/// discriminant of `T`.
+pub fn bytes_to_enum<T: EnumFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_enum_with(bytes, check_enum::<T, NativeEndian>)
+}
+
+/// Converts a byte slice into a vector of an enum, decoding each discriminant as little-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_enum`].
+pub fn bytes_to_enum_le<T: EnumFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_enum_with(bytes, check_enum::<T, LittleEndian>)
+}
+
+/// Converts a byte slice into a vector of an enum, decoding each discriminant as big-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_enum`].
+pub fn bytes_to_enum_be<T: EnumFromBytes>(bytes: &[u8]) -> Result<Vec<T>> {
+    bytes_to_enum_with(bytes, check_enum::<T, BigEndian>)
+}
+
+/// Returns an iterator decoding an enum from `bytes` in native byte order, yielding
+/// [`Error::InvalidDiscriminant`] for each value that is not a discriminant of `T`.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of the
+/// underlying integer type.
+pub fn iter_enum<T: EnumFromBytes>(bytes: &[u8]) -> Result<CheckedElements<'_, T>> {
+    iter_enum_with(bytes, check_enum::<T, NativeEndian>)
+}
+
+/// Returns an iterator decoding an enum from `bytes` as little-endian.
+///
+/// # Errors
+///
+/// See [`iter_enum`].
+pub fn iter_enum_le<T: EnumFromBytes>(bytes: &[u8]) -> Result<CheckedElements<'_, T>> {
+    iter_enum_with(bytes, check_enum::<T, LittleEndian>)
+}
+
+/// Returns an iterator decoding an enum from `bytes` as big-endian.
+///
+/// # Errors
+///
+/// See [`iter_enum`].
+pub fn iter_enum_be<T: EnumFromBytes>(bytes: &[u8]) -> Result<CheckedElements<'_, T>> {
+    iter_enum_with(bytes, check_enum::<T, BigEndian>)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    crate::bytes_to_enum! {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub(crate) Level: i8 {
+            Low = -1,
+            Medium,
+            /// Variant attributes are forwarded.
+            #[allow(dead_code)]
+            High = 5,
+        }
+    }
+
+    #[test]
+    fn it_decodes_discriminants() {
+        assert_eq!(
+            bytes_to_enum::<Level>(&[255, 0]).unwrap(),
+            vec![Level::Low, Level::Medium]
+        );
+        assert_eq!(Level::from_repr(5), Some(Level::High));
+        assert_eq!(Level::from_repr(1), None);
+    }
+
+    #[test]
+    fn it_reports_each_invalid_discriminant() {
+        let mut levels = iter_enum_le::<Level>(&[5, 3, 0]).unwrap();
+
+        assert_eq!(levels.next(), Some(Ok(Level::High)));
+        assert_eq!(
+            levels.next(),
+            Some(Err(Error::InvalidDiscriminant {
+                name: "Level",
+                offset: 1,
+                value: 3
+            }))
+        );
+        assert_eq!(levels.next(), Some(Ok(Level::Medium)));
+    }
+}
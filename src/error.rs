@@ -0,0 +1,424 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The error type returned by the conversion functions of this crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The length of the input is not a multiple of the size of the target type.
+    LengthMismatch {
+        /// The length of the input, in bytes.
+        len: usize,
+        /// The size of the target type, in bytes.
+        size: usize,
+        /// The number of bytes left over after the last whole element.
+        remainder: usize,
+    },
+    /// The length of the input differs from the exact length required.
+    UnexpectedLength {
+        /// The length of the input, in bytes.
+        len: usize,
+        /// The required length, in bytes.
+        expected: usize,
+    },
+    /// The input is shorter than the minimum length required.
+    Truncated {
+        /// The length of the input, in bytes.
+        len: usize,
+        /// The minimum required length, in bytes.
+        expected: usize,
+    },
+    /// A sequential read ran past the end of the input.
+    UnexpectedEnd {
+        /// The offset of the failing read from the start of the input, in bytes.
+        offset: usize,
+        /// The number of bytes the read needed.
+        needed: usize,
+        /// The number of bytes left in the input.
+        remaining: usize,
+    },
+    /// The input contains bytes that are neither `0` nor `1` where a `bool` was expected.
+    InvalidBool {
+        /// The offsets of the invalid bytes.
+        offsets: Vec<usize>,
+    },
+    /// The input contains floats that are `NaN` or infinite where finite values were expected.
+    NonFinite {
+        /// The offsets of the non-finite floats, in bytes.
+        offsets: Vec<usize>,
+    },
+    /// The input contains a value that is not a Unicode scalar value where a `char` was expected.
+    InvalidChar {
+        /// The offset of the invalid value, in bytes.
+        offset: usize,
+        /// The invalid value.
+        value: u32,
+    },
+    /// The input contains an unpaired surrogate where UTF-16 text was expected.
+    InvalidUtf16 {
+        /// The offset of the unpaired surrogate, in bytes.
+        offset: usize,
+        /// The unpaired surrogate.
+        unit: u16,
+    },
+    /// The input contains a zero where a nonzero integer was expected.
+    ZeroValue {
+        /// The index of the zero element.
+        index: usize,
+    },
+    /// The input contains a timestamp that cannot be represented.
+    TimeOverflow {
+        /// The index of the timestamp.
+        index: usize,
+    },
+    /// The input contains a variable-length integer which does not fit in 64 bits.
+    VarintOverflow {
+        /// The offset of the first byte of the integer.
+        offset: usize,
+    },
+    /// The input is not aligned for the target type.
+    Misaligned {
+        /// The required alignment of the target type, in bytes.
+        align: usize,
+    },
+    /// The input contains more elements than the caller allowed.
+    LimitExceeded {
+        /// The maximum number of elements allowed.
+        limit: usize,
+    },
+    /// The input contains a character that is not a hex digit, or a hex digit without a pair.
+    InvalidHex {
+        /// The offset of the invalid character in the string, in bytes.
+        offset: usize,
+    },
+    /// The checksum of the input differs from the expected one.
+    ChecksumMismatch {
+        /// The expected checksum.
+        expected: u32,
+        /// The checksum of the input.
+        actual: u32,
+    },
+    /// The input is not valid base64.
+    InvalidBase64 {
+        /// The offset of the invalid character in the string, or its length if the string ends
+        /// with incomplete or invalid padding, in bytes.
+        offset: usize,
+    },
+    /// The input contains a value that is not a discriminant of the enum it was decoded as.
+    InvalidDiscriminant {
+        /// The name of the enum.
+        name: &'static str,
+        /// The offset of the invalid value, in bytes.
+        offset: usize,
+        /// The invalid value.
+        value: i128,
+    },
+    /// The input contains an entry of an offset table that is smaller than the previous one or
+    /// past the end of the data it indexes.
+    InvalidOffset {
+        /// The offset of the invalid entry in the table, in bytes.
+        offset: usize,
+        /// The invalid entry.
+        value: u64,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::LengthMismatch {
+                len,
+                size,
+                remainder,
+            } => write!(
+                f,
+                "Bytes length {} is not a multiple of {}, leaving {} trailing bytes at offset {}",
+                len,
+                size,
+                remainder,
+                len.saturating_sub(*remainder)
+            ),
+            Error::UnexpectedLength { len, expected } => {
+                write!(f, "Bytes length is {}, expected {}", len, expected)
+            }
+            Error::Truncated { len, expected } => {
+                write!(f, "Bytes length is {}, expected at least {}", len, expected)
+            }
+            Error::UnexpectedEnd {
+                offset,
+                needed,
+                remaining,
+            } => write!(
+                f,
+                "Expected {} bytes at offset {}, but only {} remain",
+                needed, offset, remaining
+            ),
+            Error::InvalidBool { offsets } => {
+                write!(f, "Invalid bool bytes at offsets {:?}", offsets)
+            }
+            Error::NonFinite { offsets } => {
+                write!(f, "Non-finite floats at offsets {:?}", offsets)
+            }
+            Error::InvalidChar { offset, value } => {
+                write!(f, "Invalid char {:#x} at offset {}", value, offset)
+            }
+            Error::InvalidUtf16 { offset, unit } => {
+                write!(f, "Unpaired surrogate {:#x} at offset {}", unit, offset)
+            }
+            Error::ZeroValue { index } => write!(f, "Element {} is zero", index),
+            Error::TimeOverflow { index } => write!(f, "Timestamp {} is out of range", index),
+            Error::VarintOverflow { offset } => {
+                write!(f, "Varint at offset {} overflows 64 bits", offset)
+            }
+            Error::Misaligned { align } => write!(f, "Bytes are not aligned to {}", align),
+            Error::LimitExceeded { limit } => {
+                write!(f, "Input exceeds the limit of {} elements", limit)
+            }
+            Error::InvalidHex { offset } => write!(f, "Invalid hex digit at offset {}", offset),
+            Error::InvalidBase64 { offset } => write!(f, "Invalid base64 at offset {}", offset),
+            Error::ChecksumMismatch { expected, actual } => {
+                write!(f, "Checksum is {:#x}, expected {:#x}", actual, expected)
+            }
+            Error::InvalidDiscriminant {
+                name,
+                offset,
+                value,
+            } => write!(
+                f,
+                "Invalid discriminant {} for {} at offset {}",
+                value, name, offset
+            ),
+            Error::InvalidOffset { offset, value } => write!(
+                f,
+                "Table entry {} at offset {} is out of order or out of bounds",
+                value, offset
+            ),
+        }
+    }
+}
+
+impl Error {
+    /// Creates an [`Error::LengthMismatch`] for an input of `len` bytes and elements of `size`
+    /// bytes.
+    pub(crate) fn length_mismatch(len: usize, size: usize) -> Self {
+        Error::LengthMismatch {
+            len,
+            size,
+            remainder: len % size,
+        }
+    }
+
+    /// Returns the byte offset in the input at which decoding failed, if the error is tied to one.
+    ///
+    /// For [`Error::LengthMismatch`], this is the offset of the incomplete trailing element. For
+    /// [`Error::Truncated`], it is the end of the input, for [`Error::UnexpectedEnd`], the offset
+    /// of the failing read, for [`Error::InvalidBool`] and [`Error::NonFinite`], the offset of the
+    /// first invalid element, and for [`Error::InvalidChar`], [`Error::InvalidUtf16`],
+    /// [`Error::VarintOverflow`], [`Error::InvalidHex`], [`Error::InvalidBase64`],
+    /// [`Error::InvalidDiscriminant`] and [`Error::InvalidOffset`], the offset of the invalid
+    /// value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bytes_to_type::bytes_to_vec;
+    ///
+    /// let error = bytes_to_vec::<u32>(&[0; 10]).unwrap_err();
+    ///
+    /// assert_eq!(error.offset(), Some(8));
+    /// ```
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Error::LengthMismatch { len, remainder, .. } => Some(len.saturating_sub(*remainder)),
+            Error::Truncated { len, .. } => Some(*len),
+            Error::UnexpectedEnd { offset, .. } => Some(*offset),
+            Error::InvalidBool { offsets } | Error::NonFinite { offsets } => {
+                offsets.first().copied()
+            }
+            Error::InvalidChar { offset, .. }
+            | Error::InvalidUtf16 { offset, .. }
+            | Error::VarintOverflow { offset }
+            | Error::InvalidHex { offset }
+            | Error::InvalidBase64 { offset }
+            | Error::InvalidDiscriminant { offset, .. }
+            | Error::InvalidOffset { offset, .. } => Some(*offset),
+            Error::ZeroValue { .. }
+            | Error::TimeOverflow { .. }
+            | Error::UnexpectedLength { .. }
+            | Error::Misaligned { .. }
+            | Error::LimitExceeded { .. }
+            | Error::ChecksumMismatch { .. } => None,
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// The error type returned by the validating conversion functions, such as
+/// [`bytes_to_vec_validated`](crate::bytes_to_vec_validated).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError<T> {
+    /// The input could not be decoded.
+    Decode(Error),
+    /// An element was rejected by the predicate.
+    Rejected {
+        /// The index of the rejected element.
+        index: usize,
+        /// The rejected element.
+        value: T,
+    },
+}
+
+impl<T> From<Error> for ValidationError<T> {
+    fn from(error: Error) -> Self {
+        ValidationError::Decode(error)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for ValidationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationError::Decode(error) => error.fmt(f),
+            ValidationError::Rejected { index, value } => {
+                write!(f, "Element {} was rejected: {:?}", index, value)
+            }
+        }
+    }
+}
+
+impl<T: fmt::Debug> core::error::Error for ValidationError<T> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            ValidationError::Decode(error) => Some(error),
+            ValidationError::Rejected { .. } => None,
+        }
+    }
+}
+
+/// A specialized [`Result`](core::result::Result) type for conversions, using [`Error`] as the
+/// default error type.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::*;
+
+    #[test]
+    fn it_formats_errors() {
+        let error = Error::length_mismatch(11, 4);
+        assert_eq!(
+            error.to_string(),
+            "Bytes length 11 is not a multiple of 4, leaving 3 trailing bytes at offset 8"
+        );
+
+        let error = Error::LengthMismatch {
+            len: 0,
+            size: 4,
+            remainder: 1,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Bytes length 0 is not a multiple of 4, leaving 1 trailing bytes at offset 0"
+        );
+        assert_eq!(error.offset(), Some(0));
+
+        let error = Error::UnexpectedLength {
+            len: 8,
+            expected: 12,
+        };
+        assert_eq!(error.to_string(), "Bytes length is 8, expected 12");
+
+        let error = Error::Truncated {
+            len: 2,
+            expected: 15,
+        };
+        assert_eq!(error.to_string(), "Bytes length is 2, expected at least 15");
+
+        let error = Error::UnexpectedEnd {
+            offset: 6,
+            needed: 4,
+            remaining: 1,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Expected 4 bytes at offset 6, but only 1 remain"
+        );
+
+        let error = Error::InvalidBool {
+            offsets: alloc::vec![1, 5],
+        };
+        assert_eq!(error.to_string(), "Invalid bool bytes at offsets [1, 5]");
+
+        let error = Error::NonFinite {
+            offsets: alloc::vec![4],
+        };
+        assert_eq!(error.to_string(), "Non-finite floats at offsets [4]");
+        assert_eq!(error.offset(), Some(4));
+
+        let error = Error::InvalidChar {
+            offset: 8,
+            value: 0xD800,
+        };
+        assert_eq!(error.to_string(), "Invalid char 0xd800 at offset 8");
+
+        let error = Error::InvalidUtf16 {
+            offset: 2,
+            unit: 0xDC00,
+        };
+        assert_eq!(error.to_string(), "Unpaired surrogate 0xdc00 at offset 2");
+
+        let error = Error::ZeroValue { index: 3 };
+        assert_eq!(error.to_string(), "Element 3 is zero");
+
+        let error = Error::TimeOverflow { index: 4 };
+        assert_eq!(error.to_string(), "Timestamp 4 is out of range");
+
+        let error = Error::VarintOverflow { offset: 12 };
+        assert_eq!(error.to_string(), "Varint at offset 12 overflows 64 bits");
+
+        let error = Error::Misaligned { align: 8 };
+        assert_eq!(error.to_string(), "Bytes are not aligned to 8");
+
+        let error = Error::LimitExceeded { limit: 16 };
+        assert_eq!(error.to_string(), "Input exceeds the limit of 16 elements");
+
+        let error = Error::InvalidHex { offset: 5 };
+        assert_eq!(error.to_string(), "Invalid hex digit at offset 5");
+
+        let error = Error::InvalidBase64 { offset: 7 };
+        assert_eq!(error.to_string(), "Invalid base64 at offset 7");
+
+        let error = Error::ChecksumMismatch {
+            expected: 0xCBF4_3926,
+            actual: 0x1234,
+        };
+        assert_eq!(error.to_string(), "Checksum is 0x1234, expected 0xcbf43926");
+
+        let error = Error::InvalidDiscriminant {
+            name: "Opcode",
+            offset: 3,
+            value: -1,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Invalid discriminant -1 for Opcode at offset 3"
+        );
+
+        let error = Error::InvalidOffset {
+            offset: 8,
+            value: 300,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Table entry 300 at offset 8 is out of order or out of bounds"
+        );
+
+        let error = ValidationError::Rejected {
+            index: 2,
+            value: 300u32,
+        };
+        assert_eq!(error.to_string(), "Element 2 was rejected: 300");
+    }
+}
@@ -0,0 +1,128 @@
+//! Support functions of the `extern "C"` form of [`bytes_to_type!`](crate::bytes_to_type).
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::ffi::c_int;
+
+use crate::Result;
+
+/// Returned when the conversion succeeded.
+pub const OK: c_int = 0;
+
+/// Returned when `out` or `out_len` is null, or `bytes` is null with a nonzero length.
+pub const NULL_POINTER: c_int = -1;
+
+/// Returned when the length of the input is not a multiple of the size of the element type.
+pub const LENGTH_MISMATCH: c_int = -2;
+
+/// Converts `len` bytes at `bytes` with `convert`, handing the elements over to the caller
+/// through `out` and `out_len`.
+///
+/// # Safety
+///
+/// `bytes` must be valid for reads of `len` bytes unless `len` is zero, and `out` and `out_len`
+/// must be null or valid for writes.
+pub unsafe fn convert<T>(
+    bytes: *const u8,
+    len: usize,
+    out: *mut *mut T,
+    out_len: *mut usize,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> c_int {
+    if out.is_null() || out_len.is_null() || (bytes.is_null() && len != 0) {
+        return NULL_POINTER;
+    }
+
+    let bytes = if len == 0 {
+        &[]
+    } else {
+        // SAFETY: the caller guarantees that `bytes` is valid for reads of `len` bytes.
+        unsafe { core::slice::from_raw_parts(bytes, len) }
+    };
+
+    let Ok(values) = convert(bytes) else {
+        return LENGTH_MISMATCH;
+    };
+
+    // Boxing the slice drops the spare capacity, so the elements can be freed from their count.
+    let count = values.len();
+    let values = Box::into_raw(values.into_boxed_slice());
+
+    // SAFETY: the caller guarantees that `out` and `out_len` are valid for writes.
+    unsafe {
+        *out_len = count;
+        *out = values as *mut T;
+    }
+
+    OK
+}
+
+/// Frees `len` elements handed over by [`convert`].
+///
+/// # Safety
+///
+/// `values` must be null or have been returned by [`convert`] along with `len`, and must not be
+/// used afterwards.
+pub unsafe fn free<T>(values: *mut T, len: usize) {
+    if values.is_null() {
+        return;
+    }
+
+    // SAFETY: the caller guarantees that `values` holds the `len` elements of a boxed slice.
+    drop(unsafe { Box::from_raw(core::ptr::slice_from_raw_parts_mut(values, len)) });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_hands_elements_over_to_the_caller() {
+        let (mut out, mut out_len) = (core::ptr::null_mut(), 0);
+        let bytes = [0, 1, 0, 2];
+
+        unsafe {
+            let status = convert(
+                bytes.as_ptr(),
+                4,
+                &mut out,
+                &mut out_len,
+                crate::bytes_to_vec_be,
+            );
+            assert_eq!(status, OK);
+            assert_eq!(core::slice::from_raw_parts(out, out_len), [1u16, 2]);
+            free(out, out_len);
+
+            let status = convert(
+                core::ptr::null(),
+                0,
+                &mut out,
+                &mut out_len,
+                crate::bytes_to_vec,
+            );
+            assert_eq!((status, out_len), (OK, 0));
+            free(out, out_len);
+
+            assert_eq!(
+                convert(
+                    bytes.as_ptr(),
+                    3,
+                    &mut out,
+                    &mut out_len,
+                    crate::bytes_to_vec::<u16>
+                ),
+                LENGTH_MISMATCH
+            );
+            assert_eq!(
+                convert(
+                    bytes.as_ptr(),
+                    4,
+                    core::ptr::null_mut(),
+                    &mut out_len,
+                    crate::bytes_to_vec::<u16>
+                ),
+                NULL_POINTER
+            );
+        }
+    }
+}
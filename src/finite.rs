@@ -0,0 +1,180 @@
+use alloc::vec::Vec;
+
+use crate::convert::check_length;
+use crate::from_bytes::sealed;
+use crate::{Error, FromBytes, Result};
+
+/// A floating-point type whose non-finite values can be filtered while decoding, by
+/// [`bytes_to_finite`] and its variants.
+///
+/// This trait is sealed and implemented for `f32` and `f64`.
+pub trait FloatFromBytes: FromBytes + sealed::Sealed {
+    /// Returns whether the value is neither `NaN` nor infinite.
+    #[doc(hidden)]
+    fn is_finite(self) -> bool;
+}
+
+impl FloatFromBytes for f32 {
+    #[inline]
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl FloatFromBytes for f64 {
+    #[inline]
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+/// What [`bytes_to_finite`] does with the `NaN` and infinite values it decodes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NonFinite<T> {
+    /// Fails with [`Error::NonFinite`], listing the offsets of every non-finite value.
+    Reject,
+    /// Leaves the non-finite values out of the output.
+    Skip,
+    /// Replaces each non-finite value with the given one.
+    Replace(T),
+}
+
+/// The floats decoded by [`bytes_to_finite`], along with the offsets of the non-finite values that
+/// were skipped or replaced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finite<T> {
+    /// The decoded values.
+    pub values: Vec<T>,
+    /// The offsets of the non-finite values in the input, in bytes.
+    pub offsets: Vec<usize>,
+}
+
+fn bytes_to_finite_with<T: FloatFromBytes>(
+    bytes: &[u8],
+    policy: NonFinite<T>,
+    decode: fn(&[u8]) -> T,
+) -> Result<Finite<T>> {
+    check_length::<T>(bytes)?;
+
+    let size = core::mem::size_of::<T>();
+    let mut values = Vec::with_capacity(bytes.len() / size);
+    let mut offsets = Vec::new();
+
+    for (index, chunk) in bytes.chunks_exact(size).enumerate() {
+        let value = decode(chunk);
+
+        if value.is_finite() {
+            values.push(value);
+            continue;
+        }
+
+        offsets.push(index * size);
+
+        if let NonFinite::Replace(replacement) = policy {
+            values.push(replacement);
+        }
+    }
+
+    if matches!(policy, NonFinite::Reject) && !offsets.is_empty() {
+        return Err(Error::NonFinite { offsets });
+    }
+
+    Ok(Finite { values, offsets })
+}
+
+/// Converts a byte slice into a vector of floats in native byte order, handling `NaN` and
+/// infinite values according to `policy`.
+///
+/// Corrupt or uninitialized data often decodes to non-finite floats, which then propagate through
+/// every computation they take part in. Checking them while decoding catches them at the boundary
+/// instead, and the offsets of the offending values are reported whatever the policy.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_finite_le, Error, NonFinite};
+///
+/// let bytes: Vec<u8> = [1.5f32, f32::NAN, 2.5, f32::INFINITY]
+///     .iter()
+///     .flat_map(|value| value.to_le_bytes())
+///     .collect();
+///
+/// let skipped = bytes_to_finite_le::<f32>(&bytes, NonFinite::Skip).unwrap();
+/// assert_eq!(skipped.values, vec![1.5, 2.5]);
+/// assert_eq!(skipped.offsets, vec![4, 12]);
+///
+/// let replaced = bytes_to_finite_le::<f32>(&bytes, NonFinite::Replace(0.0)).unwrap();
+/// assert_eq!(replaced.values, vec![1.5, 0.0, 2.5, 0.0]);
+///
+/// assert_eq!(
+///     bytes_to_finite_le::<f32>(&bytes, NonFinite::Reject),
+///     Err(Error::NonFinite { offsets: vec![4, 12] })
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of
+/// `T`, or [`Error::NonFinite`] if `policy` is [`NonFinite::Reject`] and any value is `NaN` or
+/// infinite.
+pub fn bytes_to_finite<T: FloatFromBytes>(bytes: &[u8], policy: NonFinite<T>) -> Result<Finite<T>> {
+    bytes_to_finite_with(bytes, policy, T::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of floats, decoding each element as little-endian and
+/// handling `NaN` and infinite values according to `policy`.
+///
+/// # Errors
+///
+/// See [`bytes_to_finite`].
+pub fn bytes_to_finite_le<T: FloatFromBytes>(
+    bytes: &[u8],
+    policy: NonFinite<T>,
+) -> Result<Finite<T>> {
+    bytes_to_finite_with(bytes, policy, T::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of floats, decoding each element as big-endian and
+/// handling `NaN` and infinite values according to `policy`.
+///
+/// # Errors
+///
+/// See [`bytes_to_finite`].
+pub fn bytes_to_finite_be<T: FloatFromBytes>(
+    bytes: &[u8],
+    policy: NonFinite<T>,
+) -> Result<Finite<T>> {
+    bytes_to_finite_with(bytes, policy, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_handles_non_finite_floats() {
+        let mut bytes = 1.0f64.to_be_bytes().to_vec();
+        bytes.extend(f64::NEG_INFINITY.to_be_bytes());
+
+        assert_eq!(
+            bytes_to_finite_be::<f64>(&bytes, NonFinite::Replace(-1.0)).unwrap(),
+            Finite {
+                values: vec![1.0, -1.0],
+                offsets: vec![8]
+            }
+        );
+        assert_eq!(
+            bytes_to_finite_be::<f64>(&bytes[..8], NonFinite::Reject).unwrap(),
+            Finite {
+                values: vec![1.0],
+                offsets: vec![]
+            }
+        );
+        assert_eq!(
+            bytes_to_finite::<f32>(&bytes[..6], NonFinite::Skip),
+            Err(Error::length_mismatch(6, 4))
+        );
+    }
+}
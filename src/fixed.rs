@@ -0,0 +1,195 @@
+use alloc::vec::Vec;
+
+use crate::convert::check_length;
+use crate::from_bytes::sealed;
+use crate::{FromBytes, Result};
+
+/// An integer type storing fixed-point numbers, decoded by [`bytes_to_fixed_as_f64`] and
+/// [`bytes_to_fixed_as_f32`].
+///
+/// This trait is sealed and implemented for `i8`, `i16`, `i32`, `i64`, `u8`, `u16`, `u32` and
+/// `u64`. Signed types hold `Qm.n` numbers in two's complement, and unsigned types hold `UQm.n`
+/// numbers.
+pub trait FixedPoint: FromBytes + sealed::Sealed {
+    /// Converts the raw integer to `f64`, rounding to the nearest value for 64-bit integers.
+    #[doc(hidden)]
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! impl_fixed_point {
+    ($($type:ty),*) => {
+        $(
+            impl FixedPoint for $type {
+                #[inline]
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_point!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Returns the value of the least significant bit of a number with `frac_bits` fractional bits.
+fn resolution(frac_bits: u32) -> f64 {
+    assert!(
+        frac_bits < 128,
+        "Number of fractional bits must be less than 128, got {}",
+        frac_bits
+    );
+
+    1.0 / (1u128 << frac_bits) as f64
+}
+
+fn bytes_to_fixed_with<T: FixedPoint, F>(
+    bytes: &[u8],
+    frac_bits: u32,
+    decode: fn(&[u8]) -> T,
+    convert: fn(f64) -> F,
+) -> Result<Vec<F>> {
+    check_length::<T>(bytes)?;
+
+    let resolution = resolution(frac_bits);
+
+    Ok(bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        .map(|chunk| convert(decode(chunk).to_f64() * resolution))
+        .collect())
+}
+
+/// Converts a byte slice of fixed-point numbers with `frac_bits` fractional bits, stored as `T`
+/// in native byte order, into a vector of `f64`.
+///
+/// A `Qm.n` number is decoded with `T` a signed integer of `1 + m + n` bits and `frac_bits` set to
+/// `n`, so `Q15` samples are decoded as `i16` with 15 fractional bits and `Q31` samples as `i32`
+/// with 31. Each raw integer is scaled by `2^-frac_bits`, which is exact except for 64-bit
+/// integers with more than 53 significant bits.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_fixed_as_f64_le;
+///
+/// // Q15 samples: 0x4000 is 0.5 and 0x8000 is -1.0.
+/// let bytes = [0x00, 0x40, 0x00, 0x80];
+///
+/// assert_eq!(bytes_to_fixed_as_f64_le::<i16>(&bytes, 15).unwrap(), vec![0.5, -1.0]);
+/// // The same bytes as Q7.8 numbers.
+/// assert_eq!(bytes_to_fixed_as_f64_le::<i16>(&bytes, 8).unwrap(), vec![64.0, -128.0]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not
+/// a multiple of the size of `T`.
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is 128 or more.
+pub fn bytes_to_fixed_as_f64<T: FixedPoint>(bytes: &[u8], frac_bits: u32) -> Result<Vec<f64>> {
+    bytes_to_fixed_with(bytes, frac_bits, T::from_ne_slice, |value| value)
+}
+
+/// Converts a byte slice of little-endian fixed-point numbers into a vector of `f64`.
+///
+/// # Errors
+///
+/// See [`bytes_to_fixed_as_f64`].
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is 128 or more.
+pub fn bytes_to_fixed_as_f64_le<T: FixedPoint>(bytes: &[u8], frac_bits: u32) -> Result<Vec<f64>> {
+    bytes_to_fixed_with(bytes, frac_bits, T::from_le_slice, |value| value)
+}
+
+/// Converts a byte slice of big-endian fixed-point numbers into a vector of `f64`.
+///
+/// # Errors
+///
+/// See [`bytes_to_fixed_as_f64`].
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is 128 or more.
+pub fn bytes_to_fixed_as_f64_be<T: FixedPoint>(bytes: &[u8], frac_bits: u32) -> Result<Vec<f64>> {
+    bytes_to_fixed_with(bytes, frac_bits, T::from_be_slice, |value| value)
+}
+
+/// Converts a byte slice of fixed-point numbers with `frac_bits` fractional bits, stored as `T`
+/// in native byte order, into a vector of `f32`.
+///
+/// The numbers are scaled as with [`bytes_to_fixed_as_f64`] and then rounded to the nearest
+/// `f32`, so `Q31` samples keep 24 significant bits.
+///
+/// # Errors
+///
+/// See [`bytes_to_fixed_as_f64`].
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is 128 or more.
+pub fn bytes_to_fixed_as_f32<T: FixedPoint>(bytes: &[u8], frac_bits: u32) -> Result<Vec<f32>> {
+    bytes_to_fixed_with(bytes, frac_bits, T::from_ne_slice, |value| value as f32)
+}
+
+/// Converts a byte slice of little-endian fixed-point numbers into a vector of `f32`.
+///
+/// # Errors
+///
+/// See [`bytes_to_fixed_as_f64`].
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is 128 or more.
+pub fn bytes_to_fixed_as_f32_le<T: FixedPoint>(bytes: &[u8], frac_bits: u32) -> Result<Vec<f32>> {
+    bytes_to_fixed_with(bytes, frac_bits, T::from_le_slice, |value| value as f32)
+}
+
+/// Converts a byte slice of big-endian fixed-point numbers into a vector of `f32`.
+///
+/// # Errors
+///
+/// See [`bytes_to_fixed_as_f64`].
+///
+/// # Panics
+///
+/// Panics if `frac_bits` is 128 or more.
+pub fn bytes_to_fixed_as_f32_be<T: FixedPoint>(bytes: &[u8], frac_bits: u32) -> Result<Vec<f32>> {
+    bytes_to_fixed_with(bytes, frac_bits, T::from_be_slice, |value| value as f32)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_scales_fixed_point_numbers() {
+        assert_eq!(
+            bytes_to_fixed_as_f32_be::<i32>(&[0xC0, 0, 0, 0, 0x7F, 0xFF, 0xFF, 0xFF], 31).unwrap(),
+            vec![-0.5, 1.0]
+        );
+        assert_eq!(
+            bytes_to_fixed_as_f64::<u8>(&[3, 255], 2).unwrap(),
+            vec![0.75, 63.75]
+        );
+        assert_eq!(
+            bytes_to_fixed_as_f64_be::<u16>(&[0, 3], 0).unwrap(),
+            vec![3.0]
+        );
+        assert_eq!(
+            bytes_to_fixed_as_f64_le::<i64>(&[0; 4], 32),
+            Err(Error::length_mismatch(4, 8))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of fractional bits must be less than 128")]
+    fn it_panics_with_too_many_fractional_bits() {
+        let _ = bytes_to_fixed_as_f64::<i16>(&[], 128);
+    }
+}
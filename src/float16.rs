@@ -0,0 +1,109 @@
+use alloc::vec::Vec;
+
+use half::{bf16, f16};
+
+use crate::{iter_elements, iter_elements_be, iter_elements_le, Result};
+
+/// Converts a byte slice of [`f16`](half::f16) values in native byte order into a vector of `f32`,
+/// available with the `half` feature.
+///
+/// Widening while decoding avoids an intermediate `Vec<f16>` when the values are processed as
+/// `f32` anyway, as is common with the weights of machine learning models.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// odd.
+pub fn bytes_to_f16_as_f32(bytes: &[u8]) -> Result<Vec<f32>> {
+    Ok(iter_elements::<f16>(bytes)?.map(f16::to_f32).collect())
+}
+
+/// Converts a byte slice of little-endian [`f16`](half::f16) values into a vector of `f32`,
+/// available with the `half` feature.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_f16_as_f32_le;
+///
+/// assert_eq!(bytes_to_f16_as_f32_le(&[0x00, 0x3C, 0x00, 0xC0]).unwrap(), vec![1.0, -2.0]);
+/// ```
+///
+/// # Errors
+///
+/// See [`bytes_to_f16_as_f32`].
+pub fn bytes_to_f16_as_f32_le(bytes: &[u8]) -> Result<Vec<f32>> {
+    Ok(iter_elements_le::<f16>(bytes)?.map(f16::to_f32).collect())
+}
+
+/// Converts a byte slice of big-endian [`f16`](half::f16) values into a vector of `f32`, available
+/// with the `half` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_f16_as_f32`].
+pub fn bytes_to_f16_as_f32_be(bytes: &[u8]) -> Result<Vec<f32>> {
+    Ok(iter_elements_be::<f16>(bytes)?.map(f16::to_f32).collect())
+}
+
+/// Converts a byte slice of [`bf16`](half::bf16) values in native byte order into a vector of
+/// `f32`, available with the `half` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// odd.
+pub fn bytes_to_bf16_as_f32(bytes: &[u8]) -> Result<Vec<f32>> {
+    Ok(iter_elements::<bf16>(bytes)?.map(bf16::to_f32).collect())
+}
+
+/// Converts a byte slice of little-endian [`bf16`](half::bf16) values into a vector of `f32`,
+/// available with the `half` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_bf16_as_f32`].
+pub fn bytes_to_bf16_as_f32_le(bytes: &[u8]) -> Result<Vec<f32>> {
+    Ok(iter_elements_le::<bf16>(bytes)?.map(bf16::to_f32).collect())
+}
+
+/// Converts a byte slice of big-endian [`bf16`](half::bf16) values into a vector of `f32`,
+/// available with the `half` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_bf16_as_f32`].
+pub fn bytes_to_bf16_as_f32_be(bytes: &[u8]) -> Result<Vec<f32>> {
+    Ok(iter_elements_be::<bf16>(bytes)?.map(bf16::to_f32).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::bytes_to_vec_be;
+
+    #[test]
+    fn it_decodes_half_precision_floats() {
+        let bytes = [0x3C, 0x00, 0x3F, 0x80];
+
+        assert_eq!(
+            bytes_to_vec_be::<f16>(&bytes).unwrap(),
+            vec![f16::ONE, f16::from_bits(0x3F80)]
+        );
+        assert_eq!(
+            bytes_to_vec_be::<bf16>(&bytes).unwrap(),
+            vec![bf16::from_bits(0x3C00), bf16::ONE]
+        );
+    }
+
+    #[test]
+    fn it_widens_half_precision_floats_to_f32() {
+        let bytes = [0x3F, 0x80, 0xC0, 0x00];
+
+        assert_eq!(bytes_to_bf16_as_f32_be(&bytes).unwrap(), vec![1.0, -2.0]);
+        assert_eq!(bytes_to_f16_as_f32_be(&bytes).unwrap(), vec![1.875, -2.0]);
+        assert!(bytes_to_f16_as_f32_le(&bytes[1..]).is_err());
+    }
+}
@@ -0,0 +1,366 @@
+use alloc::vec::Vec;
+
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, ByteCursor, FromBytes, Result};
+
+/// The encoding of an integer field in a framed format, such as the element count used by
+/// [`decode_length_prefixed`] or the tag and length fields read by [`TlvReader`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum LenKind {
+    /// A single byte.
+    U8,
+    /// A little-endian `u16`.
+    U16Le,
+    /// A big-endian `u16`.
+    U16Be,
+    /// A little-endian `u32`.
+    U32Le,
+    /// A big-endian `u32`.
+    U32Be,
+    /// A little-endian `u64`.
+    U64Le,
+    /// A big-endian `u64`.
+    U64Be,
+}
+
+impl LenKind {
+    /// Returns the size of the field, in bytes.
+    fn size(self) -> usize {
+        match self {
+            LenKind::U8 => 1,
+            LenKind::U16Le | LenKind::U16Be => 2,
+            LenKind::U32Le | LenKind::U32Be => 4,
+            LenKind::U64Le | LenKind::U64Be => 8,
+        }
+    }
+
+    /// Reads the field.
+    fn read_u64(self, cursor: &mut ByteCursor<'_>) -> Result<u64> {
+        Ok(match self {
+            LenKind::U8 => cursor.read::<u8>()?.into(),
+            LenKind::U16Le => cursor.read_le::<u16>()?.into(),
+            LenKind::U16Be => cursor.read_be::<u16>()?.into(),
+            LenKind::U32Le => cursor.read_le::<u32>()?.into(),
+            LenKind::U32Be => cursor.read_be::<u32>()?.into(),
+            LenKind::U64Le => cursor.read_le::<u64>()?,
+            LenKind::U64Be => cursor.read_be::<u64>()?,
+        })
+    }
+
+    /// Reads the field as a length, saturating lengths which don't fit in a `usize`.
+    fn read(self, cursor: &mut ByteCursor<'_>) -> Result<usize> {
+        Ok(usize::try_from(self.read_u64(cursor)?).unwrap_or(usize::MAX))
+    }
+}
+
+/// Reads an element count followed by that many elements from `cursor`.
+fn read_frame<T: FromBytes>(
+    cursor: &mut ByteCursor<'_>,
+    len: LenKind,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> Result<Vec<T>> {
+    let count = len.read(cursor)?;
+
+    convert(cursor.read_bytes(count.saturating_mul(core::mem::size_of::<T>()))?)
+}
+
+fn decode_length_prefixed_with<T: FromBytes>(
+    bytes: &[u8],
+    len: LenKind,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> Result<(Vec<T>, &[u8])> {
+    let mut cursor = ByteCursor::new(bytes);
+    let values = read_frame(&mut cursor, len, convert)?;
+
+    Ok((values, cursor.remaining()))
+}
+
+/// Decodes a frame made of an element count followed by that many elements of `T` in native byte
+/// order, returning the elements along with the bytes after the frame.
+///
+/// The count is checked against the length of the input before anything is decoded, so a corrupt
+/// prefix cannot trigger a huge allocation.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{decode_length_prefixed_be, LenKind};
+///
+/// let bytes = [0, 0, 0, 2, 0, 1, 0, 2, 9];
+/// let (values, rest) = decode_length_prefixed_be::<u16>(&bytes, LenKind::U32Be).unwrap();
+///
+/// assert_eq!(values, vec![1, 2]);
+/// assert_eq!(rest, &[9]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedEnd`](crate::Error::UnexpectedEnd) if the input ends within the
+/// prefix or before the last element of the frame.
+pub fn decode_length_prefixed<T: FromBytes>(bytes: &[u8], len: LenKind) -> Result<(Vec<T>, &[u8])> {
+    decode_length_prefixed_with(bytes, len, bytes_to_vec::<T>)
+}
+
+/// Decodes a frame made of an element count followed by that many little-endian elements of `T`,
+/// returning the elements along with the bytes after the frame.
+///
+/// # Errors
+///
+/// See [`decode_length_prefixed`].
+pub fn decode_length_prefixed_le<T: FromBytes>(
+    bytes: &[u8],
+    len: LenKind,
+) -> Result<(Vec<T>, &[u8])> {
+    decode_length_prefixed_with(bytes, len, bytes_to_vec_le::<T>)
+}
+
+/// Decodes a frame made of an element count followed by that many big-endian elements of `T`,
+/// returning the elements along with the bytes after the frame.
+///
+/// # Errors
+///
+/// See [`decode_length_prefixed`].
+pub fn decode_length_prefixed_be<T: FromBytes>(
+    bytes: &[u8],
+    len: LenKind,
+) -> Result<(Vec<T>, &[u8])> {
+    decode_length_prefixed_with(bytes, len, bytes_to_vec_be::<T>)
+}
+
+fn decode_nested_with<T: FromBytes>(
+    bytes: &[u8],
+    len: LenKind,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> Result<(Vec<Vec<T>>, &[u8])> {
+    let mut cursor = ByteCursor::new(bytes);
+    let count = len.read(&mut cursor)?;
+
+    // Every inner frame takes at least its prefix, which bounds the allocation by the input.
+    let mut lists = Vec::with_capacity(count.min(cursor.remaining().len() / len.size()));
+
+    for _ in 0..count {
+        lists.push(read_frame(&mut cursor, len, convert)?);
+    }
+
+    Ok((lists, cursor.remaining()))
+}
+
+/// Decodes a frame made of a list count followed by that many length-prefixed lists of `T` in
+/// native byte order, returning the lists along with the bytes after the frame.
+///
+/// The outer count and the count of each inner list are encoded as `len`, and each inner list is
+/// laid out as with [`decode_length_prefixed`]. Every count is checked against the bytes left in
+/// the input before anything is allocated for it, so a corrupt count cannot trigger a huge
+/// allocation.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{decode_nested_le, LenKind};
+///
+/// let bytes = [3, 2, 1, 0, 2, 0, 0, 1, 3, 0, 9];
+/// let (lists, rest) = decode_nested_le::<u16>(&bytes, LenKind::U8).unwrap();
+///
+/// assert_eq!(lists, vec![vec![1, 2], vec![], vec![3]]);
+/// assert_eq!(rest, &[9]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedEnd`](crate::Error::UnexpectedEnd), with the offset from the start
+/// of `bytes`, if the input ends within a prefix or before the last element of an inner list.
+pub fn decode_nested<T: FromBytes>(bytes: &[u8], len: LenKind) -> Result<(Vec<Vec<T>>, &[u8])> {
+    decode_nested_with(bytes, len, bytes_to_vec::<T>)
+}
+
+/// Decodes a frame made of a list count followed by that many length-prefixed lists of
+/// little-endian elements of `T`, returning the lists along with the bytes after the frame.
+///
+/// # Errors
+///
+/// See [`decode_nested`].
+pub fn decode_nested_le<T: FromBytes>(bytes: &[u8], len: LenKind) -> Result<(Vec<Vec<T>>, &[u8])> {
+    decode_nested_with(bytes, len, bytes_to_vec_le::<T>)
+}
+
+/// Decodes a frame made of a list count followed by that many length-prefixed lists of
+/// big-endian elements of `T`, returning the lists along with the bytes after the frame.
+///
+/// # Errors
+///
+/// See [`decode_nested`].
+pub fn decode_nested_be<T: FromBytes>(bytes: &[u8], len: LenKind) -> Result<(Vec<Vec<T>>, &[u8])> {
+    decode_nested_with(bytes, len, bytes_to_vec_be::<T>)
+}
+
+/// An iterator over the records of a type-length-value encoded buffer, yielding each tag along
+/// with its value bytes.
+///
+/// The widths and byte orders of the tag and length fields are configured with [`LenKind`], and the
+/// length counts the bytes of the value. The value bytes can be passed on to the element decoders,
+/// such as [`bytes_to_vec_be`](crate::bytes_to_vec_be), to decode the payload.
+///
+/// If a record is truncated, the iterator yields
+/// [`Error::UnexpectedEnd`](crate::Error::UnexpectedEnd) with the offset relative to the start of
+/// the buffer, and then stops.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_be, LenKind, TlvReader};
+///
+/// let bytes = [1, 0, 4, 0, 1, 0, 2, 7, 0, 0];
+/// let mut reader = TlvReader::new(&bytes, LenKind::U8, LenKind::U16Be);
+///
+/// let (tag, value) = reader.next().unwrap().unwrap();
+/// assert_eq!(tag, 1);
+/// assert_eq!(bytes_to_vec_be::<u16>(value).unwrap(), vec![1, 2]);
+///
+/// assert_eq!(reader.next().unwrap().unwrap(), (7, &[][..]));
+/// assert!(reader.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct TlvReader<'a> {
+    cursor: ByteCursor<'a>,
+    tag: LenKind,
+    len: LenKind,
+    failed: bool,
+}
+
+impl<'a> TlvReader<'a> {
+    /// Creates a reader over `bytes`, with tag and length fields encoded as `tag` and `len`.
+    pub fn new(bytes: &'a [u8], tag: LenKind, len: LenKind) -> Self {
+        Self {
+            cursor: ByteCursor::new(bytes),
+            tag,
+            len,
+            failed: false,
+        }
+    }
+
+    /// Returns the bytes that have not been read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.cursor.remaining()
+    }
+
+    fn read_record(&mut self) -> Result<(u64, &'a [u8])> {
+        let tag = self.tag.read_u64(&mut self.cursor)?;
+        let len = self.len.read(&mut self.cursor)?;
+
+        Ok((tag, self.cursor.read_bytes(len)?))
+    }
+}
+
+impl<'a> Iterator for TlvReader<'a> {
+    type Item = Result<(u64, &'a [u8])>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.cursor.is_empty() {
+            return None;
+        }
+
+        let record = self.read_record();
+        self.failed = record.is_err();
+
+        Some(record)
+    }
+}
+
+impl core::iter::FusedIterator for TlvReader<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_decodes_consecutive_frames() {
+        let bytes = [2, 1, 0, 2, 0, 1, 0, 3, 0];
+
+        let (first, rest) = decode_length_prefixed_le::<u16>(&bytes, LenKind::U8).unwrap();
+        assert_eq!(first, vec![1, 2]);
+
+        let (second, rest) = decode_length_prefixed_le::<i8>(rest, LenKind::U16Le).unwrap();
+        assert_eq!(second, vec![3]);
+        assert_eq!(rest, &[0]);
+    }
+
+    #[test]
+    fn it_returns_error_if_prefix_exceeds_input() {
+        let bytes = [0, 0, 0, 3, 0, 1, 0, 2];
+
+        assert_eq!(
+            decode_length_prefixed_be::<u16>(&bytes, LenKind::U32Be),
+            Err(Error::UnexpectedEnd {
+                offset: 4,
+                needed: 6,
+                remaining: 4
+            })
+        );
+        assert_eq!(
+            decode_length_prefixed::<u64>(&[0xFF; 8], LenKind::U64Le),
+            Err(Error::UnexpectedEnd {
+                offset: 8,
+                needed: usize::MAX,
+                remaining: 0
+            })
+        );
+        assert!(decode_length_prefixed::<u8>(&[0], LenKind::U16Be).is_err());
+    }
+
+    #[test]
+    fn it_decodes_nested_lists() {
+        let bytes = [0, 2, 0, 1, 0, 0, 0, 5, 0, 0];
+
+        let (lists, rest) = decode_nested_be::<u32>(&bytes, LenKind::U16Be).unwrap();
+        assert_eq!(lists, vec![vec![5], vec![]]);
+        assert!(rest.is_empty());
+
+        assert_eq!(
+            decode_nested::<u8>(&[2, 1, 7, 3, 8], LenKind::U8),
+            Err(Error::UnexpectedEnd {
+                offset: 4,
+                needed: 3,
+                remaining: 1
+            })
+        );
+        assert_eq!(
+            decode_nested_le::<u16>(&[0xFF, 0xFF, 0xFF, 0xFF, 0, 0, 0, 0], LenKind::U32Le),
+            Err(Error::UnexpectedEnd {
+                offset: 8,
+                needed: 4,
+                remaining: 0
+            })
+        );
+    }
+
+    #[test]
+    fn it_reads_tlv_records() {
+        let bytes = [2, 0, 1, 0, 0, 0, 9, 3, 0, 0, 0, 0, 0];
+        let mut reader = TlvReader::new(&bytes, LenKind::U16Le, LenKind::U32Le);
+
+        assert_eq!(reader.next(), Some(Ok((2, &[9][..]))));
+        assert_eq!(reader.next(), Some(Ok((3, &[][..]))));
+        assert_eq!(reader.next(), None);
+    }
+
+    #[test]
+    fn it_stops_after_truncated_tlv_record() {
+        let bytes = [1, 1, 5, 2, 3, 0, 1];
+        let mut reader = TlvReader::new(&bytes, LenKind::U8, LenKind::U8);
+
+        assert_eq!(reader.next(), Some(Ok((1, &[5][..]))));
+        assert_eq!(
+            reader.next(),
+            Some(Err(Error::UnexpectedEnd {
+                offset: 5,
+                needed: 3,
+                remaining: 2
+            }))
+        );
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.remaining(), &[0, 1]);
+    }
+}
@@ -0,0 +1,220 @@
+use alloc::vec::Vec;
+
+use crate::Result;
+
+pub(crate) mod sealed {
+    pub trait Sealed {}
+}
+
+/// A type that can be decoded from raw bytes.
+///
+/// `FromBytes` is implemented for all primitive numeric types: `u8`, `u16`, `u32`, `u64`, `u128`,
+/// `usize`, `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `f32` and `f64`. Every bit pattern of the
+/// right size is a valid value of these types, which is what makes reinterpreting bytes as them
+/// sound. With the `half` feature, it is also implemented for [`half::f16`] and [`half::bf16`].
+/// It is also implemented for the types storing a value in a fixed byte order, such as
+/// [`U32Be`](crate::U32Be), which decode the same way in every byte order, and for
+/// [`Wrapping<T>`](core::num::Wrapping) and [`Saturating<T>`](core::num::Saturating) over any
+/// other `FromBytes` type.
+///
+/// With the `derive` feature, `FromBytes` can also be derived for `#[repr(C)]` structs made of
+/// other `FromBytes` types, which checks the requirements below at compile time.
+///
+/// # Safety
+///
+/// Decoding reinterprets raw bytes as values of `Self`, so implementing `FromBytes` requires that
+/// every bit pattern of `size_of::<Self>()` bytes is a valid value of `Self`, and that `Self`
+/// contains no padding, so all of its bytes are initialized. Prefer deriving the trait to
+/// implementing it by hand.
+///
+/// Implementations without `unsafe` are rejected, so a type breaking these requirements, such as
+/// one wrapping a `bool`, cannot be decoded from safe code:
+///
+/// ```compile_fail
+/// #[derive(Clone, Copy)]
+/// struct Flag(bool);
+///
+/// impl bytes_to_type::FromBytes for Flag {
+///     fn from_le_slice(bytes: &[u8]) -> Self {
+///         Flag(bytes[0] != 0)
+///     }
+///
+///     fn from_be_slice(bytes: &[u8]) -> Self {
+///         Flag(bytes[0] != 0)
+///     }
+/// }
+/// ```
+///
+/// # Compile-Time Checks
+///
+/// Since `FromBytes` is only implemented for types meeting these requirements, types for which
+/// some bit patterns are invalid or which own resources, such as `String`, `bool` or references,
+/// are rejected when the crate is compiled rather than causing undefined behavior at runtime:
+///
+/// ```compile_fail
+/// bytes_to_type::bytes_to_type!(String);
+/// ```
+///
+/// ```compile_fail
+/// bytes_to_type::bytes_to_type!(&'static u32);
+/// ```
+///
+/// Such types are decoded by dedicated functions instead, such as
+/// [`bytes_to_bool`](crate::bytes_to_bool) or [`bytes_to_char`](crate::bytes_to_char), which
+/// validate every element.
+///
+/// # Generic Code
+///
+/// Libraries building on this crate can be generic over the element type with a `FromBytes`
+/// bound, decoding with [`from_byte_slice`](Self::from_byte_slice) and its `_le`/`_be` variants
+/// or with any function of this crate. Every `FromBytes` type also implements
+/// [`ToBytes`](crate::ToBytes), which encodes values back to bytes.
+///
+/// ```
+/// use bytes_to_type::{FromBytes, Result};
+///
+/// fn sum<T: FromBytes + Into<f64>>(bytes: &[u8]) -> Result<f64> {
+///     Ok(T::from_byte_slice_le(bytes)?.into_iter().map(Into::into).sum())
+/// }
+///
+/// assert_eq!(sum::<u16>(&[1, 0, 2, 0]).unwrap(), 3.0);
+/// assert_eq!(sum::<f32>(&1.5f32.to_le_bytes()).unwrap(), 1.5);
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be decoded from raw bytes",
+    label = "`{Self}` does not implement `FromBytes`",
+    note = "only primitive numeric types and `#[derive(FromBytes)]` structs can be reinterpreted from bytes, since every bit pattern must be a valid value"
+)]
+pub unsafe trait FromBytes: Copy {
+    /// Whether `Self` is a primitive whose byte order is swapped by reversing all of its bytes.
+    #[doc(hidden)]
+    const IS_PRIMITIVE: bool = false;
+
+    /// Decodes a value from exactly `size_of::<Self>()` bytes in native byte order.
+    #[doc(hidden)]
+    #[inline]
+    fn from_ne_slice(bytes: &[u8]) -> Self {
+        read_ne(bytes)
+    }
+
+    /// Decodes a value from exactly `size_of::<Self>()` little-endian bytes.
+    #[doc(hidden)]
+    fn from_le_slice(bytes: &[u8]) -> Self;
+
+    /// Decodes a value from exactly `size_of::<Self>()` big-endian bytes.
+    #[doc(hidden)]
+    fn from_be_slice(bytes: &[u8]) -> Self;
+
+    /// Converts a byte slice into a vector of `Self` in native byte order, like
+    /// [`bytes_to_vec`](crate::bytes_to_vec).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+    /// not a multiple of the size of `Self`.
+    #[inline]
+    fn from_byte_slice(bytes: &[u8]) -> Result<Vec<Self>> {
+        crate::bytes_to_vec(bytes)
+    }
+
+    /// Converts a byte slice into a vector of `Self`, decoding each element as little-endian,
+    /// like [`bytes_to_vec_le`](crate::bytes_to_vec_le).
+    ///
+    /// # Errors
+    ///
+    /// See [`from_byte_slice`](Self::from_byte_slice).
+    #[inline]
+    fn from_byte_slice_le(bytes: &[u8]) -> Result<Vec<Self>> {
+        crate::bytes_to_vec_le(bytes)
+    }
+
+    /// Converts a byte slice into a vector of `Self`, decoding each element as big-endian, like
+    /// [`bytes_to_vec_be`](crate::bytes_to_vec_be).
+    ///
+    /// # Errors
+    ///
+    /// See [`from_byte_slice`](Self::from_byte_slice).
+    #[inline]
+    fn from_byte_slice_be(bytes: &[u8]) -> Result<Vec<Self>> {
+        crate::bytes_to_vec_be(bytes)
+    }
+}
+
+/// Reads a value in native byte order with a single unaligned load.
+#[cfg(not(feature = "safe-only"))]
+#[inline]
+fn read_ne<T: FromBytes>(bytes: &[u8]) -> T {
+    assert_eq!(bytes.len(), core::mem::size_of::<T>());
+
+    // SAFETY: the length was checked above, and every bit pattern is a valid value of a
+    // `FromBytes` type.
+    unsafe { core::ptr::read_unaligned(bytes.as_ptr() as *const T) }
+}
+
+/// Reads a value in native byte order by decoding each of its fields, without `unsafe` code.
+#[cfg(feature = "safe-only")]
+#[inline]
+fn read_ne<T: FromBytes>(bytes: &[u8]) -> T {
+    if cfg!(target_endian = "little") {
+        T::from_le_slice(bytes)
+    } else {
+        T::from_be_slice(bytes)
+    }
+}
+
+macro_rules! impl_from_bytes {
+    ($($type:ty),*) => {
+        $(
+            impl sealed::Sealed for $type {}
+
+            // SAFETY: primitive numbers have no padding, and every bit pattern is a valid value.
+            unsafe impl FromBytes for $type {
+                const IS_PRIMITIVE: bool = true;
+
+                #[inline]
+                fn from_ne_slice(bytes: &[u8]) -> Self {
+                    <$type>::from_ne_bytes(bytes.try_into().unwrap())
+                }
+
+                #[inline]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    <$type>::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                #[inline]
+                fn from_be_slice(bytes: &[u8]) -> Self {
+                    <$type>::from_be_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(feature = "half")]
+impl_from_bytes!(half::f16, half::bf16);
+
+macro_rules! impl_from_bytes_transparent {
+    ($($wrapper:ident),*) => {
+        $(
+            unsafe impl<T: FromBytes> FromBytes for core::num::$wrapper<T> {
+                const IS_PRIMITIVE: bool = T::IS_PRIMITIVE;
+
+                #[inline]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    core::num::$wrapper(T::from_le_slice(bytes))
+                }
+
+                #[inline]
+                fn from_be_slice(bytes: &[u8]) -> Self {
+                    core::num::$wrapper(T::from_be_slice(bytes))
+                }
+            }
+        )*
+    };
+}
+
+// SAFETY: both wrappers are `#[repr(transparent)]`, so they have the layout and the valid bit
+// patterns of the wrapped type.
+impl_from_bytes_transparent!(Wrapping, Saturating);
@@ -0,0 +1,120 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{Error, FromBytes, Result};
+
+fn bytes_to_vec_gather_with<T: FromBytes>(
+    slices: &[&[u8]],
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<T>> {
+    let size = core::mem::size_of::<T>();
+    let len = slices
+        .iter()
+        .fold(0usize, |len, slice| len.saturating_add(slice.len()));
+
+    if !len.is_multiple_of(size) {
+        return Err(Error::length_mismatch(len, size));
+    }
+
+    let mut values = Vec::with_capacity(len / size);
+    let mut scratch = vec![0; size];
+    let mut pending = 0;
+
+    for &slice in slices {
+        let mut slice = slice;
+
+        if pending > 0 {
+            // The element straddles a slice boundary, so stitch it together from several slices.
+            let count = slice.len().min(size - pending);
+            scratch[pending..pending + count].copy_from_slice(&slice[..count]);
+            pending += count;
+            slice = &slice[count..];
+
+            if pending < size {
+                continue;
+            }
+
+            values.push(decode(&scratch));
+        }
+
+        let chunks = slice.chunks_exact(size);
+        let tail = chunks.remainder();
+
+        values.extend(chunks.map(decode));
+        scratch[..tail.len()].copy_from_slice(tail);
+        pending = tail.len();
+    }
+
+    Ok(values)
+}
+
+/// Converts a logical byte stream spread over several slices into a vector of `T` in native byte
+/// order.
+///
+/// This decodes discontiguous buffers, such as the two halves of a ring buffer or the buffers of
+/// a vectored read, without concatenating them first. Elements straddling a slice boundary are
+/// stitched together before being decoded, and empty slices are skipped.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_vec_be_gather;
+///
+/// let slices: [&[u8]; 3] = [&[0, 1, 0], &[], &[2, 0, 3]];
+///
+/// assert_eq!(bytes_to_vec_be_gather::<u16>(&slices).unwrap(), vec![1, 2, 3]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the total length of `slices` is not a multiple of the size
+/// of `T`.
+pub fn bytes_to_vec_gather<T: FromBytes>(slices: &[&[u8]]) -> Result<Vec<T>> {
+    bytes_to_vec_gather_with(slices, T::from_ne_slice)
+}
+
+/// Converts a logical byte stream spread over several slices into a vector of `T`, decoding each
+/// element as little-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_gather`].
+pub fn bytes_to_vec_le_gather<T: FromBytes>(slices: &[&[u8]]) -> Result<Vec<T>> {
+    bytes_to_vec_gather_with(slices, T::from_le_slice)
+}
+
+/// Converts a logical byte stream spread over several slices into a vector of `T`, decoding each
+/// element as big-endian.
+///
+/// # Errors
+///
+/// See [`bytes_to_vec_gather`].
+pub fn bytes_to_vec_be_gather<T: FromBytes>(slices: &[&[u8]]) -> Result<Vec<T>> {
+    bytes_to_vec_gather_with(slices, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_stitches_elements_across_slices() {
+        let bytes = [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0];
+        let slices = [&bytes[..1], &bytes[1..2], &bytes[2..6], &[], &bytes[6..]];
+
+        assert_eq!(bytes_to_vec_le_gather::<u32>(&slices).unwrap(), [1, 2, 3]);
+        assert_eq!(
+            bytes_to_vec_gather::<u16>(&slices).unwrap(),
+            crate::bytes_to_vec::<u16>(&bytes).unwrap()
+        );
+        assert!(bytes_to_vec_gather::<u64>(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_returns_error_if_total_length_is_not_a_multiple_of_type_size() {
+        assert_eq!(
+            bytes_to_vec_gather::<u16>(&[&[1, 2], &[3]]),
+            Err(Error::length_mismatch(3, 2))
+        );
+    }
+}
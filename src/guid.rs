@@ -0,0 +1,87 @@
+use alloc::vec::Vec;
+
+use uuid::Uuid;
+
+use crate::convert::decode_records;
+use crate::Result;
+
+/// Converts a byte slice into a vector of [`Uuid`]s stored in the big-endian RFC 4122 layout,
+/// available with the `uuid` feature.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_uuid;
+/// use uuid::Uuid;
+///
+/// let bytes = [
+///     0x67, 0xE5, 0x50, 0x44, 0x10, 0xB1, 0x42, 0x6F, 0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0,
+///     0xC8,
+/// ];
+///
+/// assert_eq!(
+///     bytes_to_uuid(&bytes).unwrap(),
+///     vec![Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 16.
+pub fn bytes_to_uuid(bytes: &[u8]) -> Result<Vec<Uuid>> {
+    decode_records(bytes, Uuid::from_bytes)
+}
+
+/// Converts a byte slice into a vector of [`Uuid`]s stored in the mixed-endian layout of
+/// Microsoft GUIDs, available with the `uuid` feature.
+///
+/// In this layout, used by Windows structures and disk formats such as GPT, the first three
+/// fields of the GUID are little-endian while the last 8 bytes are stored as is.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_guid;
+/// use uuid::Uuid;
+///
+/// let bytes = [
+///     0x44, 0x50, 0xE5, 0x67, 0xB1, 0x10, 0x6F, 0x42, 0x92, 0x47, 0xBB, 0x68, 0x0E, 0x5F, 0xE0,
+///     0xC8,
+/// ];
+///
+/// assert_eq!(
+///     bytes_to_guid(&bytes).unwrap(),
+///     vec![Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 16.
+pub fn bytes_to_guid(bytes: &[u8]) -> Result<Vec<Uuid>> {
+    decode_records(bytes, Uuid::from_bytes_le)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_converts_bytes_to_uuids_and_guids() {
+        let uuid = Uuid::from_u128(0x00112233_4455_6677_8899_AABBCCDDEEFF);
+        let mut bytes = uuid.as_bytes().to_vec();
+        bytes.extend(uuid.to_bytes_le());
+
+        assert_eq!(bytes_to_uuid(&bytes[..16]).unwrap(), vec![uuid]);
+        assert_eq!(bytes_to_guid(&bytes[16..]).unwrap(), vec![uuid]);
+        assert_eq!(bytes_to_uuid(&bytes).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_uuid_size() {
+        assert_eq!(bytes_to_uuid(&[0; 20]), Err(Error::length_mismatch(20, 16)));
+    }
+}
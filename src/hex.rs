@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+use crate::{bytes_to_vec_endian, Endianness, Error, FromBytes, Result};
+
+fn hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Decodes a hex string into bytes.
+///
+/// The string is split into tokens at whitespace and commas, and an optional `0x` or `0X` prefix
+/// is stripped from every token, so hex dumps such as `de ad be ef` and C array initializers such
+/// as `0xDE, 0xAD` are accepted along with plain `deadbeef`. The digits of every token are decoded
+/// two at a time in the order they are written, with uppercase and lowercase digits accepted.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::hex_to_bytes;
+///
+/// assert_eq!(hex_to_bytes("0xCAFE, 0x01\n02 ff").unwrap(), vec![0xCA, 0xFE, 0x01, 0x02, 0xFF]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidHex`] with the offset of the first character that is not a hex digit,
+/// or of the last digit of a token with an odd number of digits.
+pub fn hex_to_bytes(s: &str) -> Result<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(s.len() / 2);
+
+    for token in s.split(|c: char| c.is_whitespace() || c == ',') {
+        let mut offset = token.as_ptr() as usize - s.as_ptr() as usize;
+        let digits = match token.strip_prefix("0x").or(token.strip_prefix("0X")) {
+            Some(digits) => {
+                offset += 2;
+                digits
+            }
+            None => token,
+        };
+
+        for (index, pair) in digits.as_bytes().chunks(2).enumerate() {
+            let offset = offset + index * 2;
+            let high = hex_digit(pair[0]).ok_or(Error::InvalidHex { offset })?;
+            let low = pair
+                .get(1)
+                .and_then(|&digit| hex_digit(digit))
+                .ok_or(Error::InvalidHex {
+                    offset: offset + pair.len() - 1,
+                })?;
+
+            bytes.push(high << 4 | low);
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Decodes a hex string into a vector of `T`, decoding each element in the given byte order.
+///
+/// The string is decoded into bytes as by [`hex_to_bytes`], which are then converted as by
+/// [`bytes_to_vec_endian`].
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{hex_to_vec, Endianness};
+///
+/// assert_eq!(hex_to_vec::<u16>("0x0102 0x0304", Endianness::Big).unwrap(), vec![0x0102, 0x0304]);
+/// assert_eq!(hex_to_vec::<u16>("01 00 ff ff", Endianness::Little).unwrap(), vec![1, 0xFFFF]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidHex`] with the offset of the first invalid character in `s`, or
+/// [`Error::LengthMismatch`] if the number of decoded bytes is not a multiple of the size of `T`,
+/// in which case offsets refer to the decoded bytes.
+pub fn hex_to_vec<T: FromBytes>(s: &str, endianness: Endianness) -> Result<Vec<T>> {
+    bytes_to_vec_endian(&hex_to_bytes(s)?, endianness)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_hex_in_several_layouts() {
+        assert_eq!(hex_to_bytes("").unwrap(), Vec::<u8>::new());
+        assert_eq!(hex_to_bytes("aBcD").unwrap(), vec![0xAB, 0xCD]);
+        assert_eq!(
+            hex_to_bytes("  0X01,0x02\t\t03 ,, 04 ").unwrap(),
+            vec![1, 2, 3, 4]
+        );
+        assert_eq!(
+            hex_to_vec::<u32>("ff ff ff ff 00 00 00 80", Endianness::Little).unwrap(),
+            vec![u32::MAX, 0x8000_0000]
+        );
+    }
+
+    #[test]
+    fn it_returns_error_with_offset_of_invalid_digit() {
+        assert_eq!(hex_to_bytes("00 0g"), Err(Error::InvalidHex { offset: 4 }));
+        assert_eq!(
+            hex_to_bytes("0x12 0xz1"),
+            Err(Error::InvalidHex { offset: 7 })
+        );
+        assert_eq!(hex_to_bytes("éa"), Err(Error::InvalidHex { offset: 0 }));
+        assert_eq!(
+            hex_to_bytes("12 345 67"),
+            Err(Error::InvalidHex { offset: 5 })
+        );
+        assert_eq!(
+            hex_to_vec::<u16>("01 02 03", Endianness::Big),
+            Err(Error::length_mismatch(3, 2))
+        );
+    }
+}
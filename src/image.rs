@@ -0,0 +1,253 @@
+//! Decoding of raw framebuffer bytes into typed pixels.
+//!
+//! Framebuffers and camera frames often pad each row to an alignment boundary, so the distance
+//! between the starts of two rows, the stride, can exceed the width of the image times the size of
+//! a pixel. The functions of this module take the stride into account and skip the padding.
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::image;
+//!
+//! // A 2x2 RGB24 image with rows padded to 8 bytes.
+//! let bytes = [255, 0, 0, 0, 255, 0, 0xEE, 0xEE, 0, 0, 255, 9, 9, 9];
+//!
+//! assert_eq!(
+//!     image::decode_rgb24(&bytes, 2, 2, 8).unwrap(),
+//!     vec![[255, 0, 0], [0, 255, 0], [0, 0, 255], [9, 9, 9]]
+//! );
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{Error, Result};
+
+/// Decodes the pixels of an image of `height` rows of `width` pixels of `N` bytes each, with rows
+/// starting `stride` bytes apart.
+///
+/// The padding after the last row may be missing.
+fn decode_pixels<P, const N: usize>(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+    decode: fn([u8; N]) -> P,
+) -> Result<Vec<P>> {
+    let row = width * N;
+    assert!(
+        stride >= row,
+        "Row stride {} is shorter than a row of {} bytes",
+        stride,
+        row
+    );
+
+    let expected = match height {
+        0 => 0,
+        _ => stride * (height - 1) + row,
+    };
+
+    if bytes.len() < expected {
+        return Err(Error::Truncated {
+            len: bytes.len(),
+            expected,
+        });
+    }
+
+    let mut pixels = Vec::with_capacity(width * height);
+
+    for index in 0..height {
+        let start = index * stride;
+        pixels.extend(
+            bytes[start..start + row]
+                .chunks_exact(N)
+                .map(|pixel| decode(pixel.try_into().expect("Chunks have N bytes"))),
+        );
+    }
+
+    Ok(pixels)
+}
+
+/// Decodes an image of 24-bit RGB pixels into one `[r, g, b]` array per pixel, in row-major
+/// order.
+///
+/// `stride` is the distance between the starts of two rows in bytes, which is `width * 3` for an
+/// image without row padding.
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is too short to hold `height` rows.
+///
+/// # Panics
+///
+/// Panics if `stride` is shorter than a row.
+pub fn decode_rgb24(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<Vec<[u8; 3]>> {
+    decode_pixels(bytes, width, height, stride, |pixel| pixel)
+}
+
+/// Decodes an image of little-endian 16-bit RGB565 pixels, in row-major order.
+///
+/// The pixels can be expanded to 8 bits per channel with [`unpack_rgb565`].
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is too short to hold `height` rows.
+///
+/// # Panics
+///
+/// Panics if `stride` is shorter than a row.
+pub fn decode_rgb565_le(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<Vec<u16>> {
+    decode_pixels(bytes, width, height, stride, u16::from_le_bytes)
+}
+
+/// Decodes an image of big-endian 16-bit RGB565 pixels, in row-major order.
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is too short to hold `height` rows.
+///
+/// # Panics
+///
+/// Panics if `stride` is shorter than a row.
+pub fn decode_rgb565_be(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<Vec<u16>> {
+    decode_pixels(bytes, width, height, stride, u16::from_be_bytes)
+}
+
+/// Expands an RGB565 pixel to 8 bits per channel, returning it as `[r, g, b]`.
+///
+/// The high bits of each channel are replicated into the low bits, so full intensity maps to
+/// `255`.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::image;
+///
+/// assert_eq!(image::unpack_rgb565(0xF800), [255, 0, 0]);
+/// assert_eq!(image::unpack_rgb565(0x07E0), [0, 255, 0]);
+/// assert_eq!(image::unpack_rgb565(0x8410), [132, 130, 132]);
+/// ```
+pub fn unpack_rgb565(pixel: u16) -> [u8; 3] {
+    let red = (pixel >> 11) as u8;
+    let green = (pixel >> 5) as u8 & 0x3F;
+    let blue = pixel as u8 & 0x1F;
+
+    [
+        (red << 3) | (red >> 2),
+        (green << 2) | (green >> 4),
+        (blue << 3) | (blue >> 2),
+    ]
+}
+
+/// Decodes an image of little-endian `f32` RGBA pixels into one `[r, g, b, a]` array per pixel,
+/// in row-major order.
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is too short to hold `height` rows.
+///
+/// # Panics
+///
+/// Panics if `stride` is shorter than a row.
+pub fn decode_rgba_f32_le(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<Vec<[f32; 4]>> {
+    decode_pixels(bytes, width, height, stride, |pixel: [u8; 16]| {
+        core::array::from_fn(|index| {
+            f32::from_le_bytes(pixel[index * 4..index * 4 + 4].try_into().expect("4 bytes"))
+        })
+    })
+}
+
+/// Decodes an image of big-endian `f32` RGBA pixels into one `[r, g, b, a]` array per pixel, in
+/// row-major order.
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is too short to hold `height` rows.
+///
+/// # Panics
+///
+/// Panics if `stride` is shorter than a row.
+pub fn decode_rgba_f32_be(
+    bytes: &[u8],
+    width: usize,
+    height: usize,
+    stride: usize,
+) -> Result<Vec<[f32; 4]>> {
+    decode_pixels(bytes, width, height, stride, |pixel: [u8; 16]| {
+        core::array::from_fn(|index| {
+            f32::from_be_bytes(pixel[index * 4..index * 4 + 4].try_into().expect("4 bytes"))
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_skips_row_padding() {
+        let bytes = [0x00, 0xF8, 0xFF, 0xFF, 0xE0, 0x07];
+
+        assert_eq!(
+            decode_rgb565_le(&bytes, 1, 2, 4).unwrap(),
+            vec![0xF800, 0x07E0]
+        );
+        assert_eq!(
+            decode_rgb565_be(&bytes, 3, 1, 6).unwrap(),
+            vec![0x00F8, 0xFFFF, 0xE007]
+        );
+        assert_eq!(unpack_rgb565(0xFFFF), [255, 255, 255]);
+    }
+
+    #[test]
+    fn it_decodes_float_pixels() {
+        let bytes: Vec<u8> = [0.0f32, 0.25, 0.5, 1.0]
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect();
+
+        assert_eq!(
+            decode_rgba_f32_be(&bytes, 1, 1, 16).unwrap(),
+            vec![[0.0, 0.25, 0.5, 1.0]]
+        );
+        assert!(decode_rgba_f32_le(&[], 4, 0, 64).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_returns_error_if_rows_are_truncated() {
+        assert_eq!(
+            decode_rgb24(&[0; 10], 2, 2, 8),
+            Err(Error::Truncated {
+                len: 10,
+                expected: 14
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Row stride 5 is shorter than a row of 6 bytes")]
+    fn it_panics_on_short_stride() {
+        let _ = decode_rgb24(&[0; 12], 2, 2, 5);
+    }
+}
@@ -0,0 +1,275 @@
+use alloc::vec::Vec;
+
+use crate::convert::{check_length, is_byte_sized};
+use crate::trace::{self, ConversionPath};
+use crate::{Error, FromBytes, Result};
+
+/// Writes the elements held by `bytes` to `out`, copying the bytes as they are if `native` is
+/// set, and decoding each element with `decode` otherwise.
+///
+/// # Safety
+///
+/// The length of `bytes` must be a multiple of the size of `T`, and `out` must be valid for writes
+/// of all of its elements.
+#[cfg_attr(feature = "safe-only", allow(dead_code))]
+pub(crate) unsafe fn write_elements<T: FromBytes>(
+    bytes: &[u8],
+    out: *mut T,
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) {
+    let size = core::mem::size_of::<T>();
+    let native = native || is_byte_sized::<T>();
+
+    #[cfg(all(feature = "simd", not(feature = "safe-only")))]
+    if !native && T::IS_PRIMITIVE && matches!(size, 2 | 4 | 8 | 16) {
+        trace::conversion::<T>(bytes.len(), ConversionPath::Swapped);
+
+        // SAFETY: the caller guarantees that `out` has room for `bytes`, and any bytes written to
+        // primitives form valid values.
+        unsafe {
+            core::ptr::copy_nonoverlapping(bytes.as_ptr(), out as *mut u8, bytes.len());
+            crate::simd::swap_bytes(
+                core::slice::from_raw_parts_mut(out as *mut u8, bytes.len()),
+                size,
+            );
+        }
+
+        return;
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    if native {
+        trace::conversion::<T>(bytes.len(), ConversionPath::Copied);
+
+        // SAFETY: the caller guarantees that `out` has room for `bytes`, and every bit pattern is
+        // a valid value of a `FromBytes` type.
+        unsafe { core::ptr::copy_nonoverlapping(bytes.as_ptr(), out as *mut u8, bytes.len()) };
+        return;
+    }
+
+    #[cfg(feature = "safe-only")]
+    let _ = native;
+
+    trace::conversion::<T>(bytes.len(), ConversionPath::Elementwise);
+
+    for (index, element) in bytes.chunks_exact(size).enumerate() {
+        // SAFETY: the caller guarantees that `out` has room for one element per chunk.
+        unsafe { out.add(index).write(decode(element)) };
+    }
+}
+
+/// Decodes the elements held by `bytes` one by one with safe code, used instead of
+/// [`write_elements`] with the `safe-only` feature.
+#[cfg(feature = "safe-only")]
+pub(crate) fn decode_elements<'a, T: FromBytes + 'a>(
+    bytes: &'a [u8],
+    decode: fn(&[u8]) -> T,
+) -> impl ExactSizeIterator<Item = T> + 'a {
+    trace::conversion::<T>(bytes.len(), ConversionPath::Elementwise);
+
+    bytes.chunks_exact(core::mem::size_of::<T>()).map(decode)
+}
+
+fn bytes_to_vec_into_with<T: FromBytes>(
+    bytes: &[u8],
+    out: &mut Vec<T>,
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<usize> {
+    check_length::<T>(bytes)?;
+
+    let count = bytes.len() / core::mem::size_of::<T>();
+    out.reserve(count);
+
+    #[cfg(not(feature = "safe-only"))]
+    // SAFETY: the length was checked above, and `count` elements were reserved past the end of
+    // `out`, which are all initialized before the length is updated.
+    unsafe {
+        write_elements(bytes, out.as_mut_ptr().add(out.len()), native, decode);
+        out.set_len(out.len() + count);
+    }
+
+    #[cfg(feature = "safe-only")]
+    {
+        let _ = native;
+        out.extend(decode_elements(bytes, decode));
+    }
+
+    Ok(count)
+}
+
+fn bytes_to_slice_into_with<T: FromBytes>(
+    bytes: &[u8],
+    out: &mut [T],
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<usize> {
+    check_length::<T>(bytes)?;
+
+    let count = bytes.len() / core::mem::size_of::<T>();
+
+    if count > out.len() {
+        return Err(Error::LimitExceeded { limit: out.len() });
+    }
+
+    #[cfg(not(feature = "safe-only"))]
+    // SAFETY: the length was checked above, and `out` has room for `count` elements.
+    unsafe {
+        write_elements(bytes, out.as_mut_ptr(), native, decode)
+    };
+
+    #[cfg(feature = "safe-only")]
+    {
+        let _ = native;
+
+        for (slot, value) in out.iter_mut().zip(decode_elements(bytes, decode)) {
+            *slot = value;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Decodes a byte slice in native byte order and appends the elements to `out`, returning the
+/// number of elements appended.
+///
+/// Clearing and refilling the same vector for every frame reuses its allocation, so a steady
+/// stream of frames can be decoded without allocating.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_vec_le_into;
+///
+/// let mut samples = Vec::with_capacity(1024);
+///
+/// for frame in [[1, 0, 2, 0], [3, 0, 4, 0]] {
+///     samples.clear();
+///     assert_eq!(bytes_to_vec_le_into::<u16>(&frame, &mut samples).unwrap(), 2);
+/// }
+///
+/// assert_eq!(samples, vec![3, 4]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// leaving `out` unchanged.
+pub fn bytes_to_vec_into<T: FromBytes>(bytes: &[u8], out: &mut Vec<T>) -> Result<usize> {
+    bytes_to_vec_into_with(bytes, out, true, T::from_ne_slice)
+}
+
+/// Decodes a byte slice as little-endian and appends the elements to `out`, returning the number of
+/// elements appended.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// leaving `out` unchanged.
+pub fn bytes_to_vec_le_into<T: FromBytes>(bytes: &[u8], out: &mut Vec<T>) -> Result<usize> {
+    bytes_to_vec_into_with(bytes, out, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Decodes a byte slice as big-endian and appends the elements to `out`, returning the number of
+/// elements appended.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// leaving `out` unchanged.
+pub fn bytes_to_vec_be_into<T: FromBytes>(bytes: &[u8], out: &mut Vec<T>) -> Result<usize> {
+    bytes_to_vec_into_with(bytes, out, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+/// Decodes a byte slice in native byte order into the start of `out`, returning the number of
+/// elements written.
+///
+/// This never allocates. Elements of `out` past the decoded ones are left untouched.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_slice_be_into;
+///
+/// let mut buffer = [0u32; 4];
+/// let count = bytes_to_slice_be_into(&[0, 0, 0, 1, 0, 0, 0, 2], &mut buffer).unwrap();
+///
+/// assert_eq!(&buffer[..count], &[1, 2]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// and [`Error::LimitExceeded`] if `out` is too short to hold all elements. `out` is left unchanged
+/// in both cases.
+pub fn bytes_to_slice_into<T: FromBytes>(bytes: &[u8], out: &mut [T]) -> Result<usize> {
+    bytes_to_slice_into_with(bytes, out, true, T::from_ne_slice)
+}
+
+/// Decodes a byte slice as little-endian into the start of `out`, returning the number of elements
+/// written.
+///
+/// # Errors
+///
+/// See [`bytes_to_slice_into`].
+pub fn bytes_to_slice_le_into<T: FromBytes>(bytes: &[u8], out: &mut [T]) -> Result<usize> {
+    bytes_to_slice_into_with(bytes, out, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Decodes a byte slice as big-endian into the start of `out`, returning the number of elements
+/// written.
+///
+/// # Errors
+///
+/// See [`bytes_to_slice_into`].
+pub fn bytes_to_slice_be_into<T: FromBytes>(bytes: &[u8], out: &mut [T]) -> Result<usize> {
+    bytes_to_slice_into_with(bytes, out, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_appends_to_vectors() {
+        let mut values = vec![7u32];
+
+        assert_eq!(bytes_to_vec_be_into(&[0, 0, 0, 1], &mut values).unwrap(), 1);
+        assert_eq!(bytes_to_vec_le_into(&[2, 0, 0, 0], &mut values).unwrap(), 1);
+        assert_eq!(
+            bytes_to_vec_into(&3u32.to_ne_bytes(), &mut values).unwrap(),
+            1
+        );
+        assert_eq!(values, vec![7, 1, 2, 3]);
+
+        assert!(bytes_to_vec_into(&[0; 3], &mut values).is_err());
+        assert_eq!(values.len(), 4);
+    }
+
+    #[test]
+    fn it_decodes_long_inputs_into_slices() {
+        let bytes: Vec<u8> = (0..64u16).flat_map(u16::to_be_bytes).collect();
+        let mut values = [0u16; 70];
+
+        assert_eq!(bytes_to_slice_be_into(&bytes, &mut values).unwrap(), 64);
+        assert!(values[..64].iter().copied().eq(0..64));
+        assert_eq!(values[64..], [0; 6]);
+
+        assert_eq!(bytes_to_slice_le_into(&bytes[..2], &mut values).unwrap(), 1);
+        assert_eq!(values[..2], [0, 1]);
+    }
+
+    #[test]
+    fn it_returns_error_if_slice_is_too_short() {
+        let mut values = [9u8; 2];
+
+        assert_eq!(
+            bytes_to_slice_into(&[1, 2, 3], &mut values),
+            Err(Error::LimitExceeded { limit: 2 })
+        );
+        assert_eq!(values, [9, 9]);
+    }
+}
@@ -0,0 +1,252 @@
+use std::io::{self, Read, Write};
+
+use crate::convert::as_bytes;
+use crate::{
+    bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, swap_bytes_in_place, Endianness, Error,
+    FromBytes, Result,
+};
+
+/// The number of bytes [`write_elements`] encodes before each write.
+const WRITE_BUFFER_SIZE: usize = 8 * 1024;
+
+/// Reads everything from `reader`, failing if it holds more than `max_elements` elements of `T`.
+fn read_bytes<T, R: Read>(reader: R, max_elements: Option<usize>) -> io::Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+
+    match max_elements {
+        Some(limit) => {
+            let max_bytes = limit.saturating_mul(core::mem::size_of::<T>()) as u64;
+
+            reader
+                .take(max_bytes.saturating_add(1))
+                .read_to_end(&mut bytes)?;
+
+            if bytes.len() as u64 > max_bytes {
+                return Err(invalid_data(Error::LimitExceeded { limit }));
+            }
+        }
+        None => {
+            let mut reader = reader;
+
+            reader.read_to_end(&mut bytes)?;
+        }
+    }
+
+    Ok(bytes)
+}
+
+pub(crate) fn invalid_data(error: Error) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn read_with<T, R: Read>(
+    reader: R,
+    max_elements: Option<usize>,
+    convert: fn(&[u8]) -> Result<Vec<T>>,
+) -> io::Result<Vec<T>> {
+    let bytes = read_bytes::<T, R>(reader, max_elements)?;
+
+    convert(&bytes).map_err(invalid_data)
+}
+
+/// Reads all bytes from `reader` and converts them into a vector of `T` in native byte order.
+///
+/// Short reads and interrupted reads are retried until the reader reaches end of file.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::read_to_vec_le;
+///
+/// let file: &[u8] = &[1, 0, 2, 0];
+/// let values = read_to_vec_le::<u16, _>(file).expect("Failed to read u16 values");
+///
+/// assert_eq!(values, vec![1, 2]);
+/// ```
+///
+/// # Errors
+///
+/// Returns any I/O error produced by `reader`. If the total number of bytes read is not a multiple
+/// of the size of `T`, returns an error of kind [`io::ErrorKind::InvalidData`] wrapping
+/// [`Error::LengthMismatch`].
+pub fn read_to_vec<T: FromBytes, R: Read>(reader: R) -> io::Result<Vec<T>> {
+    read_with(reader, None, bytes_to_vec::<T>)
+}
+
+/// Reads all bytes from `reader` and converts them into a vector of `T`, decoding each element as
+/// little-endian.
+///
+/// # Errors
+///
+/// See [`read_to_vec`].
+pub fn read_to_vec_le<T: FromBytes, R: Read>(reader: R) -> io::Result<Vec<T>> {
+    read_with(reader, None, bytes_to_vec_le::<T>)
+}
+
+/// Reads all bytes from `reader` and converts them into a vector of `T`, decoding each element as
+/// big-endian.
+///
+/// # Errors
+///
+/// See [`read_to_vec`].
+pub fn read_to_vec_be<T: FromBytes, R: Read>(reader: R) -> io::Result<Vec<T>> {
+    read_with(reader, None, bytes_to_vec_be::<T>)
+}
+
+/// Like [`read_to_vec`], but reads at most `max_elements` elements to bound the allocation.
+///
+/// # Errors
+///
+/// In addition to the errors of [`read_to_vec`], returns an error of kind
+/// [`io::ErrorKind::InvalidData`] wrapping [`Error::LimitExceeded`] if `reader` holds more than
+/// `max_elements` elements.
+pub fn read_to_vec_max<T: FromBytes, R: Read>(
+    reader: R,
+    max_elements: usize,
+) -> io::Result<Vec<T>> {
+    read_with(reader, Some(max_elements), bytes_to_vec::<T>)
+}
+
+/// Like [`read_to_vec_le`], but reads at most `max_elements` elements to bound the allocation.
+///
+/// # Errors
+///
+/// See [`read_to_vec_max`].
+pub fn read_to_vec_le_max<T: FromBytes, R: Read>(
+    reader: R,
+    max_elements: usize,
+) -> io::Result<Vec<T>> {
+    read_with(reader, Some(max_elements), bytes_to_vec_le::<T>)
+}
+
+/// Like [`read_to_vec_be`], but reads at most `max_elements` elements to bound the allocation.
+///
+/// # Errors
+///
+/// See [`read_to_vec_max`].
+pub fn read_to_vec_be_max<T: FromBytes, R: Read>(
+    reader: R,
+    max_elements: usize,
+) -> io::Result<Vec<T>> {
+    read_with(reader, Some(max_elements), bytes_to_vec_be::<T>)
+}
+
+/// Encodes `values` in the given byte order and writes them to `writer`, the counterpart of
+/// [`read_to_vec`] and its variants.
+///
+/// Elements are encoded into a buffer of about 8 KiB, which is written with a single
+/// [`Write::write_all`] call whenever it fills up, so `writer` doesn't need to be wrapped in an
+/// [`io::BufWriter`]. The writer is not flushed.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{read_to_vec_be, write_elements, Endianness};
+///
+/// let mut file = Vec::new();
+/// write_elements(&mut file, (1..=3u16).map(|value| value * 100), Endianness::Big).unwrap();
+///
+/// assert_eq!(file, [0, 100, 0, 200, 1, 44]);
+/// assert_eq!(read_to_vec_be::<u16, _>(&file[..]).unwrap(), vec![100, 200, 300]);
+/// ```
+///
+/// # Errors
+///
+/// Returns any I/O error produced by `writer`, in which case some of the elements before the
+/// failing write may already have been written.
+pub fn write_elements<T: FromBytes, W: Write>(
+    mut writer: W,
+    values: impl IntoIterator<Item = T>,
+    endianness: Endianness,
+) -> io::Result<()> {
+    let capacity = (WRITE_BUFFER_SIZE / core::mem::size_of::<T>().max(1)).max(1);
+    let mut buffer = Vec::with_capacity(capacity);
+    let mut values = values.into_iter();
+
+    loop {
+        buffer.extend(values.by_ref().take(capacity));
+
+        if buffer.is_empty() {
+            return Ok(());
+        }
+
+        if endianness != Endianness::NATIVE {
+            swap_bytes_in_place(&mut buffer);
+        }
+
+        writer.write_all(as_bytes(&buffer))?;
+        buffer.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A reader returning at most one byte per call.
+    struct ShortReader<'a>(&'a [u8]);
+
+    impl Read for ShortReader<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            match (self.0.split_first(), buf.first_mut()) {
+                (Some((&byte, rest)), Some(slot)) => {
+                    *slot = byte;
+                    self.0 = rest;
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+    }
+
+    #[test]
+    fn it_handles_short_reads() {
+        let reader = ShortReader(&[0, 0, 0, 1, 0, 0, 0, 2]);
+
+        assert_eq!(read_to_vec_be::<u32, _>(reader).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_type_size() {
+        let error = read_to_vec::<u32, _>(&[1, 2, 3][..]).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.into_inner().unwrap().downcast_ref::<Error>(),
+            Some(&Error::length_mismatch(3, 4))
+        );
+    }
+
+    #[test]
+    fn it_writes_elements_in_buffered_chunks() {
+        let mut bytes = Vec::new();
+
+        write_elements(&mut bytes, 0..5000u32, Endianness::Little).unwrap();
+        assert_eq!(bytes.len(), 20_000);
+        assert_eq!(&bytes[4..8], [1, 0, 0, 0]);
+        assert_eq!(
+            read_to_vec_le::<u32, _>(&bytes[..]).unwrap(),
+            (0..5000).collect::<Vec<_>>()
+        );
+
+        let mut bytes = Vec::new();
+        write_elements(&mut bytes, core::iter::empty::<f64>(), Endianness::Big).unwrap();
+        assert!(bytes.is_empty());
+    }
+
+    #[test]
+    fn it_enforces_max_elements() {
+        let bytes = [1, 0, 2, 0, 3, 0];
+
+        assert_eq!(
+            read_to_vec_le_max::<u16, _>(&bytes[..], 3).unwrap(),
+            vec![1, 2, 3]
+        );
+
+        let error = read_to_vec_le_max::<u16, _>(&bytes[..], 2).unwrap_err();
+        assert_eq!(
+            error.into_inner().unwrap().downcast_ref::<Error>(),
+            Some(&Error::LimitExceeded { limit: 2 })
+        );
+    }
+}
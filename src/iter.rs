@@ -0,0 +1,133 @@
+use core::iter::FusedIterator;
+use core::slice::ChunksExact;
+
+use crate::convert::check_length;
+use crate::{FromBytes, Result};
+
+/// A lazy iterator decoding elements of `T` from a byte slice.
+///
+/// This struct is created by [`iter_elements`], [`iter_elements_le`] and [`iter_elements_be`].
+#[derive(Debug, Clone)]
+pub struct Elements<'a, T> {
+    chunks: ChunksExact<'a, u8>,
+    decode: fn(&[u8]) -> T,
+}
+
+impl<'a, T: FromBytes> Elements<'a, T> {
+    fn new(bytes: &'a [u8], decode: fn(&[u8]) -> T) -> Result<Self> {
+        check_length::<T>(bytes)?;
+
+        Ok(Self {
+            chunks: bytes.chunks_exact(core::mem::size_of::<T>()),
+            decode,
+        })
+    }
+}
+
+impl<T> Iterator for Elements<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.chunks.next().map(self.decode)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.chunks.nth(n).map(self.decode)
+    }
+}
+
+impl<T> DoubleEndedIterator for Elements<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.chunks.next_back().map(self.decode)
+    }
+}
+
+impl<T> ExactSizeIterator for Elements<'_, T> {}
+
+impl<T> FusedIterator for Elements<'_, T> {}
+
+/// Returns an iterator decoding elements of `T` from `bytes` in native byte order, on the fly.
+///
+/// Unlike [`bytes_to_vec`](crate::bytes_to_vec), no intermediate vector is allocated, which makes
+/// this a better fit for pipelines that immediately map or filter the decoded values.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::iter_elements_le;
+///
+/// let bytes = vec![1, 0, 2, 0, 3, 0];
+/// let sum: u16 = iter_elements_le::<u16>(&bytes).unwrap().sum();
+///
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn iter_elements<T: FromBytes>(bytes: &[u8]) -> Result<Elements<'_, T>> {
+    Elements::new(bytes, T::from_ne_slice)
+}
+
+/// Returns an iterator decoding elements of `T` from `bytes` as little-endian, on the fly.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn iter_elements_le<T: FromBytes>(bytes: &[u8]) -> Result<Elements<'_, T>> {
+    Elements::new(bytes, T::from_le_slice)
+}
+
+/// Returns an iterator decoding elements of `T` from `bytes` as big-endian, on the fly.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn iter_elements_be<T: FromBytes>(bytes: &[u8]) -> Result<Elements<'_, T>> {
+    Elements::new(bytes, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_elements_lazily() {
+        let bytes = [0, 1, 0, 2, 0, 3];
+        let mut elements = iter_elements_be::<u16>(&bytes).unwrap();
+
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements.next(), Some(1));
+        assert_eq!(elements.next_back(), Some(3));
+        assert_eq!(elements.len(), 1);
+        assert_eq!(elements.collect::<Vec<_>>(), [2]);
+    }
+
+    #[test]
+    fn it_matches_native_conversion() {
+        let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        assert_eq!(
+            iter_elements::<u32>(&bytes).unwrap().collect::<Vec<_>>(),
+            crate::bytes_to_vec::<u32>(&bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_type_size() {
+        assert!(iter_elements_le::<u32>(&[1, 2, 3]).is_err());
+    }
+}
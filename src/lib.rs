@@ -1,10 +1,292 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
+extern crate alloc;
+
 pub use paste::paste;
 
+mod aligned;
+#[cfg(feature = "allocator_api")]
+mod allocator;
+#[cfg(feature = "arrayvec")]
+mod array_vec;
+#[cfg(feature = "arrow")]
+mod arrow;
+#[cfg(feature = "tokio")]
+mod async_io;
+mod atomic;
+pub mod audio;
+#[cfg(feature = "base64")]
+mod base64;
+pub mod bits;
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "num-traits")]
+mod cast;
+mod checked;
+mod checksum;
+#[cfg(feature = "tokio")]
+mod codec;
+#[cfg(any(feature = "flate2", feature = "lz4", feature = "zstd"))]
+mod compressed;
+mod constant;
+mod convert;
+mod converter;
+mod cursor;
+#[cfg(feature = "serde")]
+mod de;
+mod decoder;
+mod dynamic;
+mod endian;
+mod enums;
+mod error;
+mod ffi;
+mod finite;
+mod fixed;
+#[cfg(feature = "half")]
+mod float16;
+mod framing;
+mod from_bytes;
+mod gather;
+#[cfg(feature = "uuid")]
+mod guid;
+mod hex;
+pub mod image;
+mod into;
+#[cfg(feature = "std")]
+mod io;
+mod iter;
+#[cfg(feature = "memmap2")]
+mod mapped;
+mod net;
+#[cfg(feature = "npy")]
+pub mod npy;
+mod odd_width;
+mod offsets;
+mod ordered;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "bytemuck")]
+mod pod;
+pub mod prelude;
+#[cfg(feature = "numpy")]
+mod python;
+mod record;
+mod records;
+mod ring;
+mod roundtrip;
+#[cfg(feature = "ndarray")]
+mod shaped;
+mod shared;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "smallvec")]
+mod small_vec;
+mod split;
+mod strided;
+mod swap;
+mod text;
+mod time;
+mod to_bytes;
+mod trace;
+pub mod varint;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "wav")]
+pub mod wav;
+#[cfg(feature = "zerocopy")]
+mod zero_copy;
+
+pub use aligned::{
+    bytes_to_vec_aligned, bytes_to_vec_aligned_be, bytes_to_vec_aligned_le, AlignedVec,
+};
+#[cfg(feature = "allocator_api")]
+pub use allocator::{bytes_to_vec_be_in, bytes_to_vec_in, bytes_to_vec_le_in};
+#[cfg(feature = "arrayvec")]
+pub use array_vec::{
+    bytes_to_arrayvec, bytes_to_arrayvec_be, bytes_to_arrayvec_be_into, bytes_to_arrayvec_into,
+    bytes_to_arrayvec_le, bytes_to_arrayvec_le_into,
+};
+#[cfg(feature = "arrow")]
+pub use arrow::{
+    buffer_to_scalar_buffer, bytes_to_primitive_array, bytes_to_primitive_array_be,
+    bytes_to_primitive_array_le, bytes_to_scalar_buffer, bytes_to_scalar_buffer_be,
+    bytes_to_scalar_buffer_le,
+};
+#[cfg(feature = "tokio")]
+pub use async_io::{read_elements, read_elements_be, read_elements_le};
+pub use atomic::{bytes_to_atomic, bytes_to_atomic_be, bytes_to_atomic_le, AtomicFromBytes};
+#[cfg(feature = "base64")]
+pub use base64::{base64_to_vec, base64_to_vec_be, base64_to_vec_le};
+#[cfg(feature = "bytes")]
+pub use buf::{buf_to_vec, buf_to_vec_be, buf_to_vec_le};
+#[cfg(feature = "num-traits")]
+pub use cast::{bytes_to_vec_be_cast, bytes_to_vec_cast, bytes_to_vec_le_cast};
+pub use checked::{
+    bytes_to_bool, bytes_to_bool_nonzero, bytes_to_char, bytes_to_char_be, bytes_to_char_le,
+    bytes_to_nonzero, bytes_to_nonzero_be, bytes_to_nonzero_le, iter_bool, iter_char, iter_char_be,
+    iter_char_le, iter_nonzero, iter_nonzero_be, iter_nonzero_le, CheckedElements,
+    NonZeroFromBytes,
+};
+pub use checksum::{
+    bytes_to_vec_verified, bytes_to_vec_verified_be, bytes_to_vec_verified_le, strip_checksum,
+    verify_checksum, Checksum,
+};
+#[cfg(feature = "tokio")]
+pub use codec::ElementDecoder;
+#[cfg(any(feature = "flate2", feature = "lz4", feature = "zstd"))]
+pub use compressed::{decode_compressed, decode_compressed_be, decode_compressed_le, Compression};
+pub use convert::{
+    bytes_to_array, bytes_to_array_be, bytes_to_array_le, bytes_to_chunks, bytes_to_chunks_be,
+    bytes_to_chunks_le, bytes_to_cow, bytes_to_mapped, bytes_to_mapped_be, bytes_to_mapped_le,
+    bytes_to_slice, bytes_to_slice_mut, bytes_to_vec, bytes_to_vec_be, bytes_to_vec_be_in_range,
+    bytes_to_vec_be_partial, bytes_to_vec_be_validated, bytes_to_vec_in_range, bytes_to_vec_le,
+    bytes_to_vec_le_in_range, bytes_to_vec_le_partial, bytes_to_vec_le_validated,
+    bytes_to_vec_partial, bytes_to_vec_unchecked, bytes_to_vec_validated, conversion_path,
+    vec_bytes_to_vec,
+};
+pub use converter::{Converter, ValidatingConverter};
+pub use cursor::ByteCursor;
+#[cfg(feature = "serde")]
+pub use de::{
+    deserialize_elements, deserialize_elements_be, deserialize_elements_le, DeserializeError,
+    ElementDeserializer,
+};
+pub use decoder::Decoder;
+pub use dynamic::{decode_dynamic, ScalarType, TypedVec};
+pub use endian::{
+    bytes_to_vec_endian, bytes_to_vec_ordered, detect_bom, detect_magic, detect_marker, BigEndian,
+    ByteOrder, Endianness, LittleEndian, NativeEndian,
+};
+pub use enums::{
+    bytes_to_enum, bytes_to_enum_be, bytes_to_enum_le, iter_enum, iter_enum_be, iter_enum_le,
+    EnumFromBytes,
+};
+pub use error::{Error, Result, ValidationError};
+pub use finite::{
+    bytes_to_finite, bytes_to_finite_be, bytes_to_finite_le, Finite, FloatFromBytes, NonFinite,
+};
+pub use fixed::{
+    bytes_to_fixed_as_f32, bytes_to_fixed_as_f32_be, bytes_to_fixed_as_f32_le,
+    bytes_to_fixed_as_f64, bytes_to_fixed_as_f64_be, bytes_to_fixed_as_f64_le, FixedPoint,
+};
+#[cfg(feature = "half")]
+pub use float16::{
+    bytes_to_bf16_as_f32, bytes_to_bf16_as_f32_be, bytes_to_bf16_as_f32_le, bytes_to_f16_as_f32,
+    bytes_to_f16_as_f32_be, bytes_to_f16_as_f32_le,
+};
+pub use framing::{
+    decode_length_prefixed, decode_length_prefixed_be, decode_length_prefixed_le, decode_nested,
+    decode_nested_be, decode_nested_le, LenKind, TlvReader,
+};
+pub use from_bytes::FromBytes;
+pub use gather::{bytes_to_vec_be_gather, bytes_to_vec_gather, bytes_to_vec_le_gather};
+#[cfg(feature = "uuid")]
+pub use guid::{bytes_to_guid, bytes_to_uuid};
+pub use hex::{hex_to_bytes, hex_to_vec};
+pub use into::{
+    bytes_to_slice_be_into, bytes_to_slice_into, bytes_to_slice_le_into, bytes_to_vec_be_into,
+    bytes_to_vec_into, bytes_to_vec_le_into,
+};
+#[cfg(feature = "std")]
+pub use io::{
+    read_to_vec, read_to_vec_be, read_to_vec_be_max, read_to_vec_le, read_to_vec_le_max,
+    read_to_vec_max, write_elements,
+};
+pub use iter::{iter_elements, iter_elements_be, iter_elements_le, Elements};
+#[cfg(feature = "memmap2")]
+pub use mapped::{mmap_to_slice, MappedSlice};
+pub use net::{bytes_to_ipv4, bytes_to_ipv6, bytes_to_socketaddrv4, bytes_to_socketaddrv6};
+pub use odd_width::{
+    bytes_to_i24_be, bytes_to_i24_le, bytes_to_i48_be, bytes_to_i48_le, bytes_to_u24_be,
+    bytes_to_u24_le, bytes_to_u48_be, bytes_to_u48_le,
+};
+pub use offsets::{bytes_to_offset_slices, bytes_to_offset_slices_be, bytes_to_offset_slices_le};
+pub use ordered::{
+    F32Be, F32Le, F64Be, F64Le, I128Be, I128Le, I16Be, I16Le, I32Be, I32Le, I64Be, I64Le, U128Be,
+    U128Le, U16Be, U16Le, U32Be, U32Le, U64Be, U64Le,
+};
+#[cfg(feature = "rayon")]
+pub use parallel::{par_bytes_to_vec, par_bytes_to_vec_be, par_bytes_to_vec_le};
+#[cfg(feature = "bytemuck")]
+pub use pod::{pod_as_bytes, pod_to_slice, pod_to_vec};
+#[cfg(feature = "numpy")]
+pub use python::{bytes_to_numpy, bytes_to_numpy_be, bytes_to_numpy_le};
+pub use records::{bytes_to_records, Projected, Records};
+pub use ring::{iter_ring, iter_ring_be, iter_ring_le, RingElements};
+pub use roundtrip::roundtrip;
+#[cfg(feature = "ndarray")]
+pub use shaped::{
+    bytes_to_array2, bytes_to_array2_be, bytes_to_array2_le, bytes_to_ndarray, bytes_to_ndarray_be,
+    bytes_to_ndarray_le,
+};
+#[cfg(target_has_atomic = "ptr")]
+pub use shared::{bytes_to_arc, bytes_to_arc_be, bytes_to_arc_le};
+pub use shared::{
+    bytes_to_box, bytes_to_box_be, bytes_to_box_le, bytes_to_rc, bytes_to_rc_be, bytes_to_rc_le,
+};
+#[cfg(feature = "smallvec")]
+pub use small_vec::{bytes_to_smallvec, bytes_to_smallvec_be, bytes_to_smallvec_le};
+pub use split::{element_count, split_at_elements};
+pub use strided::{bytes_to_strided, bytes_to_strided_be, bytes_to_strided_le};
+pub use swap::swap_bytes_in_place;
+pub use text::{
+    bytes_to_string_utf16_be, bytes_to_string_utf16_le, bytes_to_string_utf32_be,
+    bytes_to_string_utf32_le,
+};
+pub use time::{bytes_to_duration, bytes_to_duration_be, bytes_to_duration_le, TimeUnit};
+#[cfg(feature = "std")]
+pub use time::{bytes_to_system_time, bytes_to_system_time_be, bytes_to_system_time_le};
+pub use to_bytes::ToBytes;
+pub use trace::ConversionPath;
+#[cfg(feature = "wasm")]
+pub use wasm::{
+    bytes_to_typed_array, bytes_to_typed_array_be, bytes_to_typed_array_le, TypedArrayElement,
+};
+#[cfg(feature = "zerocopy")]
+pub use zero_copy::{zerocopy_as_bytes, zerocopy_to_slice, zerocopy_to_vec};
+
+/// Derive macro generating a [`FromBytes`] implementation and conversion functions for a
+/// `#[repr(C)]` struct, available with the `derive` feature.
+#[cfg(feature = "derive")]
+pub use bytes_to_type_derive::FromBytes;
+
+/// Re-export of the [`anyhow`] crate, available with the `anyhow` feature.
+///
+/// [`Error`] implements [`core::error::Error`], so it converts into [`anyhow::Error`] with the `?`
+/// operator. This re-export lets code written against the `anyhow::Result` signatures of earlier
+/// versions keep naming them without depending on `anyhow` directly.
+#[cfg(feature = "anyhow")]
+pub use anyhow;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+
+    pub use crate::convert::as_bytes;
+    pub use crate::strided::record_count;
+
+    pub mod ffi {
+        pub use crate::ffi::*;
+    }
+
+    #[cfg(feature = "wasm")]
+    pub use {js_sys, wasm_bindgen};
+
+    pub mod constant {
+        pub use crate::constant::*;
+    }
+
+    pub mod record {
+        pub use crate::record::*;
+    }
+}
+
 /// Provides a macro to generate a function converting byte slices to vectors of a specified type.
 ///
 /// The `bytes_to_type!` macro generates a conversion function that transforms a byte slice (`&[u8]`)
 /// into a `Vec<T>`, where `T` is a type specified as an argument to the macro. The resulting function
-/// returns a `Result<Vec<T>, anyhow::Error>` to gracefully handle potential conversion failures.
+/// returns a `Result<Vec<T>, bytes_to_type::Error>` to gracefully handle potential conversion failures.
 ///
 /// # Example
 ///
@@ -12,7 +294,7 @@ pub use paste::paste;
 /// use bytes_to_type::bytes_to_type;
 ///
 /// // This macro generates a function with the signature:
-/// // pub fn bytes_to_u32(bytes: &[u8]) -> Result<Vec<u32>, anyhow::Error>
+/// // pub fn bytes_to_u32(bytes: &[u8]) -> Result<Vec<u32>, bytes_to_type::Error>
 /// bytes_to_type!(u32);
 ///
 /// let bytes = vec![1, 2, 3, 4, 5, 6, 7, 8];
@@ -25,42 +307,1193 @@ pub use paste::paste;
 ///
 /// The generated function ensures the safe conversion of byte slices into `Vec<T>` while preserving
 /// the byte order. If the length of the byte slice is not a multiple of the size of `T` or if the
-/// conversion fails due to other reasons, the function returns an `Err` variant containing an error
-/// message.
+/// conversion fails due to other reasons, the function returns an `Err` variant containing an
+/// [`Error`] describing the failure.
 ///
-/// # Usage Note
+/// # Endianness
 ///
-/// Be mindful of the byte order and potential alignment issues during conversions to prevent unintended
-/// results or panics during runtime.
+/// `bytes_to_<type>` reinterprets the bytes in the native byte order of the target platform. To get
+/// the same result on every platform, the macro also generates `bytes_to_<type>_le` and
+/// `bytes_to_<type>_be`, which decode each element with `from_le_bytes` and `from_be_bytes`
+/// respectively:
 ///
-
+/// ```
+/// use bytes_to_type::bytes_to_type;
+///
+/// bytes_to_type!(u16);
+///
+/// let bytes = vec![1, 2, 3, 4];
+///
+/// assert_eq!(bytes_to_u16_le(&bytes).unwrap(), vec![0x0201, 0x0403]);
+/// assert_eq!(bytes_to_u16_be(&bytes).unwrap(), vec![0x0102, 0x0304]);
+/// ```
+///
+/// When the byte order is only known at runtime, for example from a header,
+/// `bytes_to_<type>_endian` takes it as an [`Endianness`] argument:
+///
+/// ```
+/// use bytes_to_type::{bytes_to_type, Endianness};
+///
+/// bytes_to_type!(u32);
+///
+/// assert_eq!(bytes_to_u32_endian(&[0, 0, 0, 1], Endianness::Big).unwrap(), vec![1]);
+/// ```
+///
+/// # Multiple Types
+///
+/// Several types can be passed to a single invocation, which generates the functions for each of
+/// them. The generated functions are thin wrappers around the generic functions of this crate, so
+/// no conversion code is duplicated between the types. Only `bytes_to_<type>` itself is checked by
+/// the `dead_code` lint, so private invocations don't warn about the variants they leave unused:
+///
+/// ```
+/// use bytes_to_type::bytes_to_type;
+///
+/// bytes_to_type!(u8, u16, u32, u64, f32, f64);
+///
+/// assert_eq!(bytes_to_u16_le(&[1, 0]).unwrap(), vec![1]);
+/// assert_eq!(bytes_to_f32_be(&[63, 128, 0, 0]).unwrap(), vec![1.0]);
+/// ```
+///
+/// # Parallel Conversions
+///
+/// With the `rayon` feature, the macro also generates `par_bytes_to_<type>`,
+/// `par_bytes_to_<type>_le` and `par_bytes_to_<type>_be`, which decode chunks of the input in
+/// parallel on the rayon thread pool.
+///
+/// # Visibility and Naming
+///
+/// The generated functions are `pub` by default. Each type can be preceded by a restricted
+/// visibility such as `pub(crate)` or `pub(self)`, which is applied to all of its generated
+/// functions instead, and followed by `as <name>` to replace the `bytes_to_<type>` prefix of the
+/// generated function names:
+///
+/// ```
+/// mod samples {
+///     use bytes_to_type::bytes_to_type;
+///
+///     // Generates `decode_samples`, `decode_samples_le`, `decode_samples_be` and so on,
+///     // visible only within the crate.
+///     bytes_to_type!(pub(crate) i16 as decode_samples);
+/// }
+///
+/// assert_eq!(samples::decode_samples_le(&[255, 255]).unwrap(), vec![-1]);
+/// ```
+///
+/// # Wrapper Types
+///
+/// [`Wrapping<T>`](core::num::Wrapping) and [`Saturating<T>`](core::num::Saturating) can be
+/// listed like any other type. Since a function name cannot be built from a generic path, the
+/// generated functions are named after the wrapper and the wrapped type, such as
+/// `bytes_to_wrapping_u32`, unless a name is given with `as`:
+///
+/// ```
+/// use std::num::Wrapping;
+///
+/// use bytes_to_type::bytes_to_type;
+///
+/// bytes_to_type!(Wrapping<u16>);
+///
+/// let sequence = bytes_to_wrapping_u16_be(&[0xFF, 0xFF]).unwrap();
+///
+/// assert_eq!(sequence[0] + Wrapping(1), Wrapping(0));
+/// ```
+///
+/// Atomic integers are not `Copy` and are decoded by [`bytes_to_atomic`] instead.
+///
+/// # Associated Functions
+///
+/// Wrapping the list of types in `impl <Type> { ... }` generates the functions as associated
+/// functions of your own type instead of free functions at module scope:
+///
+/// ```
+/// use bytes_to_type::bytes_to_type;
+///
+/// pub struct SampleBuffer;
+///
+/// bytes_to_type!(impl SampleBuffer { u16 as from_raw, pub(crate) f32 });
+///
+/// assert_eq!(SampleBuffer::from_raw_be(&[0, 1]).unwrap(), vec![1]);
+/// assert_eq!(SampleBuffer::bytes_to_f32_le(&[0, 0, 128, 63]).unwrap(), vec![1.0]);
+/// ```
+///
+/// # Modules
+///
+/// Wrapping the list of types in `mod <name> { ... }` generates the functions in a new module,
+/// which can be preceded by attributes and a visibility. Types defined in the enclosing module are
+/// in scope within it. This keeps the functions of several invocations for the same type apart,
+/// even when a module glob-imports another:
+///
+/// ```
+/// mod sensors {
+///     use bytes_to_type::bytes_to_type;
+///
+///     bytes_to_type!(u32);
+///
+///     pub mod network {
+///         use super::*;
+///
+///         // A plain `bytes_to_type!(u32)` would collide with the glob-imported functions.
+///         bytes_to_type!(pub mod conv { u32, f32 });
+///     }
+/// }
+///
+/// assert_eq!(sensors::network::conv::bytes_to_u32_be(&[0, 0, 0, 1]).unwrap(), vec![1]);
+/// assert_eq!(sensors::bytes_to_u32_le(&[1, 0, 0, 0]).unwrap(), vec![1]);
+/// ```
+///
+/// # C Interface
+///
+/// Wrapping a list of primitive types in `extern "C" { ... }` additionally exports C functions
+/// for each of them, so the conversions can be called from C or Python's `ctypes` when the crate
+/// is built as a `cdylib`. For `u32`, these are:
+///
+/// ```c
+/// int bytes_to_u32(const uint8_t *bytes, size_t len, uint32_t **out, size_t *out_len);
+/// int bytes_to_u32_le(const uint8_t *bytes, size_t len, uint32_t **out, size_t *out_len);
+/// int bytes_to_u32_be(const uint8_t *bytes, size_t len, uint32_t **out, size_t *out_len);
+/// void bytes_to_u32_free(uint32_t *values, size_t len);
+/// ```
+///
+/// The conversions return `0` on success and store the elements in `*out` and their count in
+/// `*out_len`. The elements must then be released with the `_free` function. They return `-1` if
+/// a pointer is null, except `bytes` for an empty input, and `-2` if the length of the input is
+/// not a multiple of the size of the type. `as <name>` renames the C functions too, and since C
+/// symbols are global, each name can only be exported once per linked binary:
+///
+/// ```
+/// use bytes_to_type::bytes_to_type;
+///
+/// bytes_to_type!(extern "C" { u16 as ffi_u16 });
+///
+/// // The C functions as declared by a foreign caller.
+/// mod c {
+///     extern "C" {
+///         pub fn ffi_u16_be(bytes: *const u8, len: usize, out: *mut *mut u16, out_len: *mut usize)
+///             -> i32;
+///         pub fn ffi_u16_free(values: *mut u16, len: usize);
+///     }
+/// }
+///
+/// let (mut out, mut out_len) = (std::ptr::null_mut(), 0);
+/// let status = unsafe { c::ffi_u16_be([0, 1, 0, 2].as_ptr(), 4, &mut out, &mut out_len) };
+///
+/// assert_eq!(status, 0);
+/// assert_eq!(unsafe { std::slice::from_raw_parts(out, out_len) }, [1, 2]);
+/// unsafe { c::ffi_u16_free(out, out_len) };
+///
+/// // The Rust functions are generated as usual.
+/// assert_eq!(ffi_u16_le(&[2, 0]).unwrap(), vec![2]);
+/// ```
+///
+/// This form relies on `unsafe` code, even with the `safe-only` feature.
+///
+/// # WebAssembly
+///
+/// With the `wasm` feature, wrapping a list of primitive types in `wasm { ... }` generates
+/// `#[wasm_bindgen]` functions taking a JavaScript `Uint8Array` and returning the matching typed
+/// array, such as a `Float32Array` for `f32`, instead of the usual functions. They are named like
+/// the usual ones and call [`bytes_to_typed_array`] and its `_le` and `_be` variants, which view
+/// aligned little-endian input in place. Your crate doesn't need to depend on `wasm-bindgen`
+/// itself:
+///
+/// ```
+/// use bytes_to_type::bytes_to_type;
+///
+/// // Generates `decode_samples`, `decode_samples_le` and `decode_samples_be`, callable from
+/// // JavaScript and returning a `Float32Array`.
+/// # #[cfg(feature = "wasm")]
+/// bytes_to_type!(wasm { f32 as decode_samples, u32 });
+/// ```
+///
+/// # Trailing Data
+///
+/// `bytes_to_<type>_partial`, `bytes_to_<type>_le_partial` and `bytes_to_<type>_be_partial` never
+/// fail. They decode as many whole elements as possible and return them along with the bytes left
+/// over at the end of the input.
+///
+/// # Reusing Buffers
+///
+/// `bytes_to_<type>_into`, `bytes_to_<type>_le_into` and `bytes_to_<type>_be_into` append the
+/// decoded elements to an existing `Vec` instead of allocating a new one, and
+/// `bytes_to_<type>_into_slice` and its `_le` and `_be` variants write them to the start of a
+/// `&mut [T]` without allocating at all. All of them return the number of decoded elements:
+///
+/// ```
+/// use bytes_to_type::bytes_to_type;
+///
+/// bytes_to_type!(u32);
+///
+/// let mut frame = [0; 16];
+/// let count = bytes_to_u32_le_into_slice(&[1, 0, 0, 0, 2, 0, 0, 0], &mut frame).unwrap();
+///
+/// assert_eq!(&frame[..count], &[1, 2]);
+/// ```
+///
+/// # Unchecked Conversions
+///
+/// `bytes_to_<type>_unchecked` is an `unsafe` variant of `bytes_to_<type>` for hot loops where the
+/// input is already validated upstream. It skips the length check and returns the vector
+/// directly, and calling it with a length that is not a multiple of the size of `T` is undefined
+/// behavior. See [`bytes_to_vec_unchecked`].
+///
+/// # Safe-Only Mode
+///
+/// With the `safe-only` feature, the generated code contains no `unsafe` blocks, and the copying
+/// conversions decode elements one by one with safe code instead of copying the input in bulk.
+/// `bytes_to_<type>_unchecked` keeps its `unsafe` signature, so enabling the feature doesn't
+/// change how it is called, but checks the length and panics on a mismatch.
+///
+/// The only exceptions are `bytes_to_<type>_slice` and `bytes_to_<type>_slice_mut`, which view the
+/// input in place and therefore still rely on `unsafe` code inside this crate, like
+/// [`bytes_to_slice`]. They are generated with every feature set, so enabling `safe-only` never
+/// removes a function its dependents call.
+///
+/// # Validation
+///
+/// `bytes_to_<type>_validated`, `bytes_to_<type>_le_validated` and `bytes_to_<type>_be_validated`
+/// take a predicate which is run on every element as it is decoded, and return a
+/// [`ValidationError`] with the index and value of the first rejected element:
+///
+/// ```
+/// use bytes_to_type::{bytes_to_type, ValidationError};
+///
+/// bytes_to_type!(u16);
+///
+/// const MAX_ID: u16 = 100;
+///
+/// assert_eq!(
+///     bytes_to_u16_be_validated(&[0, 7, 1, 0], |&id| id <= MAX_ID),
+///     Err(ValidationError::Rejected { index: 1, value: 256 })
+/// );
+/// ```
+///
+/// # Zero-Copy Views
+///
+/// `bytes_to_<type>_slice` borrows the input as a `&[T]` instead of copying it into a vector, and
+/// `bytes_to_<type>_slice_mut` borrows a `&mut [u8]` as a `&mut [T]`. In addition to the length
+/// check, they return an error if the input is not aligned for `T`.
+///
+/// The macro accepts any type implementing [`FromBytes`], and other types, such as `String` or
+/// references, fail to compile with an error pointing at the invocation. If you need a conversion
+/// for several types without defining a function for each of them, use the generic
+/// [`bytes_to_vec`], [`bytes_to_vec_le`] and [`bytes_to_vec_be`] functions instead.
+///
+/// # `no_std` Support
+///
+/// The generated functions only rely on `core` and `alloc`, so the macro can be used in
+/// `#![no_std]` crates that link `alloc`. Disable the default `std` feature to build this crate
+/// without the standard library.
+///
+/// # Usage Note
+///
+/// Be mindful of the byte order during conversions to prevent unintended results. The input of the
+/// vector-returning functions doesn't need to be aligned for `T`: aligned input takes a fast path
+/// that copies the whole slice at once, and unaligned input is copied as a whole into a freshly
+/// allocated, aligned vector. See [`conversion_path`] for the details.
+/// Single-byte types such as `u8` and `i8` have no byte order, so all of their conversions,
+/// including the `_le` and `_be` ones, amount to a plain copy of the input.
 #[macro_export]
 macro_rules! bytes_to_type {
-    ($type:ty) => {
+    () => {};
+    (impl $target:ty { $($body:tt)* }) => {
+        impl $target {
+            $crate::bytes_to_type!($($body)*);
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis mod $module:ident { $($body:tt)* }) => {
+        $(#[$meta])*
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            $crate::bytes_to_type!($($body)*);
+        }
+    };
+    (extern "C" { $($type:ident $(as $name:ident)?),* $(,)? }) => {
+        $crate::bytes_to_type!($($type $(as $name)?),*);
+        $($crate::__bytes_to_type_ffi!($type $(as $name)?);)*
+    };
+    (wasm { $($type:ident $(as $name:ident)?),* $(,)? }) => {
+        $($crate::__bytes_to_type_wasm!($type $(as $name)?);)*
+    };
+    (@single [$($vis:tt)*] $type:ty as $name:ident) => {
+        $crate::bytes_to_type!(@emit [$($vis)*] $type, $name);
+    };
+    (@single [$($vis:tt)*] $type:ty) => {
+        $crate::bytes_to_type!(@emit [$($vis)*] $type, bytes_to_ $type);
+    };
+    (@emit [$($vis:tt)*] $type:ty, $($name:tt)+) => {
+        $crate::paste! {
+            $($vis)* fn [<$($name)+>](bytes: &[u8]) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::bytes_to_vec::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _le>](bytes: &[u8]) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::bytes_to_vec_le::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _be>](bytes: &[u8]) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::bytes_to_vec_be::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _endian>](
+                bytes: &[u8],
+                endianness: $crate::Endianness,
+            ) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::bytes_to_vec_endian::<$type>(bytes, endianness)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _into>](
+                bytes: &[u8],
+                out: &mut $crate::__private::Vec<$type>,
+            ) -> $crate::Result<usize> {
+                $crate::bytes_to_vec_into::<$type>(bytes, out)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _le_into>](
+                bytes: &[u8],
+                out: &mut $crate::__private::Vec<$type>,
+            ) -> $crate::Result<usize> {
+                $crate::bytes_to_vec_le_into::<$type>(bytes, out)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _be_into>](
+                bytes: &[u8],
+                out: &mut $crate::__private::Vec<$type>,
+            ) -> $crate::Result<usize> {
+                $crate::bytes_to_vec_be_into::<$type>(bytes, out)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _into_slice>](
+                bytes: &[u8],
+                out: &mut [$type],
+            ) -> $crate::Result<usize> {
+                $crate::bytes_to_slice_into::<$type>(bytes, out)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _le_into_slice>](
+                bytes: &[u8],
+                out: &mut [$type],
+            ) -> $crate::Result<usize> {
+                $crate::bytes_to_slice_le_into::<$type>(bytes, out)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _be_into_slice>](
+                bytes: &[u8],
+                out: &mut [$type],
+            ) -> $crate::Result<usize> {
+                $crate::bytes_to_slice_be_into::<$type>(bytes, out)
+            }
+
+            $crate::__bytes_to_type_unchecked!([$($vis)*] $type, $($name)+);
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _partial>](
+                bytes: &[u8],
+            ) -> ($crate::__private::Vec<$type>, &[u8]) {
+                $crate::bytes_to_vec_partial::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _le_partial>](
+                bytes: &[u8],
+            ) -> ($crate::__private::Vec<$type>, &[u8]) {
+                $crate::bytes_to_vec_le_partial::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _be_partial>](
+                bytes: &[u8],
+            ) -> ($crate::__private::Vec<$type>, &[u8]) {
+                $crate::bytes_to_vec_be_partial::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _validated>](
+                bytes: &[u8],
+                predicate: impl FnMut(&$type) -> bool,
+            ) -> $crate::Result<$crate::__private::Vec<$type>, $crate::ValidationError<$type>> {
+                $crate::bytes_to_vec_validated::<$type, _>(bytes, predicate)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _le_validated>](
+                bytes: &[u8],
+                predicate: impl FnMut(&$type) -> bool,
+            ) -> $crate::Result<$crate::__private::Vec<$type>, $crate::ValidationError<$type>> {
+                $crate::bytes_to_vec_le_validated::<$type, _>(bytes, predicate)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _be_validated>](
+                bytes: &[u8],
+                predicate: impl FnMut(&$type) -> bool,
+            ) -> $crate::Result<$crate::__private::Vec<$type>, $crate::ValidationError<$type>> {
+                $crate::bytes_to_vec_be_validated::<$type, _>(bytes, predicate)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _slice>](bytes: &[u8]) -> $crate::Result<&[$type]> {
+                $crate::bytes_to_slice::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<$($name)+ _slice_mut>](
+                bytes: &mut [u8],
+            ) -> $crate::Result<&mut [$type]> {
+                $crate::bytes_to_slice_mut::<$type>(bytes)
+            }
+        }
+
+        $crate::__bytes_to_type_rayon!([$($vis)*] $type, $($name)+);
+    };
+    (pub($($restriction:tt)+) $wrapper:ident<$inner:ident> $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_type!(
+            @emit [pub($($restriction)+)] $wrapper<$inner>, bytes_to_ $wrapper:snake _ $inner
+        );
+        $($crate::bytes_to_type!($($rest)*);)?
+    };
+    (pub $wrapper:ident<$inner:ident> $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_type!(@emit [pub] $wrapper<$inner>, bytes_to_ $wrapper:snake _ $inner);
+        $($crate::bytes_to_type!($($rest)*);)?
+    };
+    ($wrapper:ident<$inner:ident> $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_type!(@emit [pub] $wrapper<$inner>, bytes_to_ $wrapper:snake _ $inner);
+        $($crate::bytes_to_type!($($rest)*);)?
+    };
+    (pub($($restriction:tt)+) $type:ty $(as $name:ident)? $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_type!(@single [pub($($restriction)+)] $type $(as $name)?);
+        $($crate::bytes_to_type!($($rest)*);)?
+    };
+    (pub $type:ty $(as $name:ident)? $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_type!(@single [pub] $type $(as $name)?);
+        $($crate::bytes_to_type!($($rest)*);)?
+    };
+    ($type:ty $(as $name:ident)? $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_type!(@single [pub] $type $(as $name)?);
+        $($crate::bytes_to_type!($($rest)*);)?
+    };
+}
+
+#[cfg(not(feature = "safe-only"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_unchecked {
+    ([$($vis:tt)*] $type:ty, $($name:tt)+) => {
+        $crate::paste! {
+            /// # Safety
+            ///
+            /// The length of `bytes` must be a multiple of the size of the element type.
+            #[allow(dead_code)]
+            $($vis)* unsafe fn [<$($name)+ _unchecked>](bytes: &[u8]) -> $crate::__private::Vec<$type> {
+                unsafe { $crate::bytes_to_vec_unchecked::<$type>(bytes) }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "safe-only")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_unchecked {
+    ([$($vis:tt)*] $type:ty, $($name:tt)+) => {
+        $crate::paste! {
+            /// # Safety
+            ///
+            /// The length of `bytes` must be a multiple of the size of the element type. With the
+            /// `safe-only` feature, a length mismatch panics instead.
+            #[allow(dead_code)]
+            $($vis)* unsafe fn [<$($name)+ _unchecked>](bytes: &[u8]) -> $crate::__private::Vec<$type> {
+                $crate::bytes_to_vec::<$type>(bytes).expect("the length of the bytes is not a multiple of the element size")
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_ffi {
+    ($type:ident as $name:ident) => {
+        $crate::__bytes_to_type_ffi!(@emit $type, $name);
+    };
+    ($type:ident) => {
+        $crate::__bytes_to_type_ffi!(@emit $type, bytes_to_ $type);
+    };
+    (@emit $type:ident, $($name:tt)+) => {
         $crate::paste! {
-            pub fn [<bytes_to_$type>](bytes: &[u8]) -> anyhow::Result<Vec<$type>> {
-                if bytes.len() % std::mem::size_of::<$type>() != 0 {
-                    return Err(anyhow::anyhow!(
-                        "Bytes length is not a multiple of {}",
-                        std::mem::size_of::<$type>()
-                    ));
+            #[doc(hidden)]
+            pub mod [<__ffi_ $($name)+>] {
+                $crate::__bytes_to_type_ffi!(@convert $type, [<$($name)+>], bytes_to_vec);
+                $crate::__bytes_to_type_ffi!(@convert $type, [<$($name)+ _le>], bytes_to_vec_le);
+                $crate::__bytes_to_type_ffi!(@convert $type, [<$($name)+ _be>], bytes_to_vec_be);
+
+                /// # Safety
+                ///
+                /// `values` must be null or have been returned by a conversion along with `len`.
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$($name)+ _free>](values: *mut $type, len: usize) {
+                    unsafe { $crate::__private::ffi::free(values, len) }
                 }
+            }
+        }
+    };
+    (@convert $type:ident, $name:ident, $convert:ident) => {
+        /// # Safety
+        ///
+        /// `bytes` must be valid for reads of `len` bytes unless `len` is zero, and `out` and
+        /// `out_len` must be null or valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(
+            bytes: *const u8,
+            len: usize,
+            out: *mut *mut $type,
+            out_len: *mut usize,
+        ) -> ::core::ffi::c_int {
+            unsafe {
+                $crate::__private::ffi::convert(bytes, len, out, out_len, $crate::$convert::<$type>)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_rayon {
+    ([$($vis:tt)*] $type:ty, $($name:tt)+) => {
+        $crate::paste! {
+            #[allow(dead_code)]
+            $($vis)* fn [<par_ $($name)+>](
+                bytes: &[u8],
+            ) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::par_bytes_to_vec::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<par_ $($name)+ _le>](
+                bytes: &[u8],
+            ) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::par_bytes_to_vec_le::<$type>(bytes)
+            }
+
+            #[allow(dead_code)]
+            $($vis)* fn [<par_ $($name)+ _be>](
+                bytes: &[u8],
+            ) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::par_bytes_to_vec_be::<$type>(bytes)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "rayon"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_rayon {
+    ($($tokens:tt)*) => {};
+}
+
+#[cfg(feature = "wasm")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_wasm {
+    ($type:ident as $name:ident) => {
+        $crate::__bytes_to_type_wasm!(@emit $type, $name);
+    };
+    ($type:ident) => {
+        $crate::__bytes_to_type_wasm!(@emit $type, bytes_to_ $type);
+    };
+    (@emit $type:ident, $($name:tt)+) => {
+        $crate::paste! {
+            $crate::__bytes_to_type_wasm!(@convert $type, [<$($name)+>], bytes_to_typed_array);
+            $crate::__bytes_to_type_wasm!(@convert $type, [<$($name)+ _le>], bytes_to_typed_array_le);
+            $crate::__bytes_to_type_wasm!(@convert $type, [<$($name)+ _be>], bytes_to_typed_array_be);
+        }
+    };
+    (@convert $type:ident, $name:ident, $convert:ident) => {
+        #[$crate::__private::wasm_bindgen::prelude::wasm_bindgen(
+            wasm_bindgen = $crate::__private::wasm_bindgen
+        )]
+        pub fn $name(
+            bytes: &$crate::__private::js_sys::Uint8Array,
+        ) -> ::core::result::Result<
+            <$type as $crate::TypedArrayElement>::Array,
+            $crate::__private::wasm_bindgen::JsError,
+        > {
+            $crate::$convert::<$type>(bytes)
+        }
+    };
+}
+
+#[cfg(not(feature = "wasm"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_to_type_wasm {
+    ($($tokens:tt)*) => {
+        ::core::compile_error!("the `wasm` form of `bytes_to_type!` requires the `wasm` feature");
+    };
+}
+
+/// Provides a macro to generate functions converting slices of a specified type back to bytes.
+///
+/// The `type_to_bytes!` macro is the counterpart of [`bytes_to_type!`]. It generates functions that
+/// encode a slice of `T` (`&[T]`) into a `Vec<u8>`, using the same byte order conventions as the
+/// decoding functions, so that a round trip through both macros yields the original values.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_type, type_to_bytes};
+///
+/// // This macro generates functions with the signatures:
+/// // pub fn f64_to_bytes(values: &[f64]) -> Vec<u8>
+/// // pub fn f64_to_bytes_le(values: &[f64]) -> Vec<u8>
+/// // pub fn f64_to_bytes_be(values: &[f64]) -> Vec<u8>
+/// // pub fn f64_as_bytes(values: &[f64]) -> &[u8]
+/// type_to_bytes!(f64);
+/// bytes_to_type!(f64);
+///
+/// let values = vec![1.5, -2.25];
+/// let bytes = f64_to_bytes_be(&values);
+///
+/// assert_eq!(bytes_to_f64_be(&bytes).unwrap(), values);
+/// ```
+///
+/// # Generated Functions
+///
+/// `<type>_to_bytes` writes the values in the native byte order of the target platform, while
+/// `<type>_to_bytes_le` and `<type>_to_bytes_be` always produce little-endian and big-endian output.
+/// `<type>_as_bytes` borrows the memory of the input slice as bytes in native byte order, without
+/// copying.
+///
+/// Like [`bytes_to_type!`], the macro accepts several comma-separated types at once.
+#[macro_export]
+macro_rules! type_to_bytes {
+    ($($type:ty),+ $(,)?) => {
+        $($crate::type_to_bytes!(@single $type);)+
+    };
+    (@single $type:ty) => {
+        $crate::paste! {
+            pub fn [<$type _to_bytes>](values: &[$type]) -> $crate::__private::Vec<u8> {
+                [<$type _as_bytes>](values).to_vec()
+            }
+
+            pub fn [<$type _to_bytes_le>](values: &[$type]) -> $crate::__private::Vec<u8> {
+                values.iter().flat_map(|value| value.to_le_bytes()).collect()
+            }
+
+            pub fn [<$type _to_bytes_be>](values: &[$type]) -> $crate::__private::Vec<u8> {
+                values.iter().flat_map(|value| value.to_be_bytes()).collect()
+            }
+
+            pub fn [<$type _as_bytes>](values: &[$type]) -> &[u8] {
+                $crate::__private::as_bytes::<$type>(values)
+            }
+        }
+    };
+}
 
-                Ok(unsafe {
-                    std::slice::from_raw_parts(
-                        bytes.as_ptr() as *const $type,
-                        bytes.len() / std::mem::size_of::<$type>(),
-                    )
+/// Provides a macro to define a struct together with a parser for its binary layout.
+///
+/// `byte_record!` works like Python's `struct` module: it describes a record as a list of fields,
+/// each with a kind giving its type and byte order, and generates the struct along with an
+/// associated `parse` function. `parse` decodes the fields one after another from the start of the
+/// input and returns the record along with the bytes left over after it.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::byte_record;
+///
+/// byte_record! {
+///     #[derive(Debug, PartialEq)]
+///     Header {
+///         magic: u32_be,
+///         version: u16_le,
+///         flags: u8,
+///         _pad: skip(1),
+///         timestamp: u64_le,
+///     }
+/// }
+///
+/// let bytes = [
+///     0xCA, 0xFE, 0xBA, 0xBE, 2, 0, 1, 0, 0x10, 0, 0, 0, 0, 0, 0, 0, 0xFF,
+/// ];
+/// let (header, rest) = Header::parse(&bytes).unwrap();
+///
+/// assert_eq!(header.magic, 0xCAFEBABE);
+/// assert_eq!(header.version, 2);
+/// assert_eq!(header.flags, 1);
+/// assert_eq!(header.timestamp, 0x10);
+/// assert_eq!(rest, &[0xFF]);
+/// assert_eq!(Header::SIZE, 16);
+/// ```
+///
+/// # Field Kinds
+///
+/// A field can be one of:
+///
+/// - `u8` or `i8`;
+/// - a primitive followed by `_le`, `_be` or `_ne` for little-endian, big-endian or native byte
+///   order, such as `u16_le`, `i64_be` or `f32_ne`. The supported primitives are `u16`, `u32`,
+///   `u64`, `u128`, `i16`, `i32`, `i64`, `i128`, `f32` and `f64`;
+/// - `skip(n)`, which skips `n` bytes of padding or reserved space without adding a field to the
+///   struct.
+///
+/// # Generated Items
+///
+/// The struct and its fields are `pub` by default. A restricted visibility such as `pub(crate)`
+/// can precede the name of the struct, and applies to the struct, its fields and the generated
+/// items. Attributes such as `#[derive(...)]` and doc comments are forwarded to the struct.
+///
+/// Besides the struct, the macro generates the associated `SIZE` constant, the number of bytes
+/// taken by a record including skipped bytes, and the `parse` function, which returns
+/// [`Error::Truncated`] if the input is shorter than `SIZE`.
+#[macro_export]
+macro_rules! byte_record {
+    ($(#[$meta:meta])* pub($($restriction:tt)+) $name:ident { $($fields:tt)* }) => {
+        $crate::byte_record!(@munch [$(#[$meta])*] [pub($($restriction)+)] $name [] [] $($fields)*);
+    };
+    ($(#[$meta:meta])* pub $name:ident { $($fields:tt)* }) => {
+        $crate::byte_record!(@munch [$(#[$meta])*] [pub] $name [] [] $($fields)*);
+    };
+    ($(#[$meta:meta])* $name:ident { $($fields:tt)* }) => {
+        $crate::byte_record!(@munch [$(#[$meta])*] [pub] $name [] [] $($fields)*);
+    };
+    (@munch $meta:tt $vis:tt $name:ident [$($field:tt)*] [$($step:tt)*]
+        $skipped:ident : skip($count:expr) $(, $($rest:tt)*)?) => {
+        $crate::byte_record!(@munch $meta $vis $name [$($field)*] [$($step)* (skip $count)] $($($rest)*)?);
+    };
+    (@munch $meta:tt $vis:tt $name:ident [$($field:tt)*] [$($step:tt)*]
+        $field_name:ident : $kind:ident $(, $($rest:tt)*)?) => {
+        $crate::byte_record!(
+            @munch $meta $vis $name [$($field)* ($field_name $kind)] [$($step)* (read $field_name $kind)] $($($rest)*)?
+        );
+    };
+    (@munch [$($meta:tt)*] [$vis:vis] $name:ident [$(($field_name:ident $kind:ident))*] [$($step:tt)*]) => {
+        $($meta)*
+        $vis struct $name {
+            $($vis $field_name: $crate::__private::record::$kind,)*
+        }
+
+        impl $name {
+            /// The number of bytes taken by an encoded record.
+            $vis const SIZE: usize = 0 $(+ $crate::byte_record!(@size $step))*;
+
+            /// Parses a record from the start of `bytes`, returning it along with the remaining
+            /// bytes.
+            $vis fn parse(bytes: &[u8]) -> $crate::Result<(Self, &[u8])> {
+                if bytes.len() < Self::SIZE {
+                    return Err($crate::Error::Truncated {
+                        len: bytes.len(),
+                        expected: Self::SIZE,
+                    });
                 }
-                .to_vec())
+
+                $($crate::byte_record!(@step bytes $step);)*
+
+                Ok((Self { $($field_name),* }, bytes))
+            }
+        }
+    };
+    (@size (skip $count:expr)) => {
+        $count
+    };
+    (@size (read $field_name:ident $kind:ident)) => {
+        ::core::mem::size_of::<$crate::__private::record::$kind>()
+    };
+    (@step $bytes:ident (skip $count:expr)) => {
+        let $bytes = &$bytes[$count..];
+    };
+    (@step $bytes:ident (read $field_name:ident $kind:ident)) => {
+        let ($field_name, $bytes) =
+            $bytes.split_at(::core::mem::size_of::<$crate::__private::record::$kind>());
+        let $field_name = $crate::__private::record::$kind($field_name);
+    };
+}
+
+/// Provides a macro to define a fieldless enum together with the validation needed to decode it.
+///
+/// Reinterpreting an integer that is not one of its discriminants as an enum is undefined
+/// behavior, so enums cannot implement [`FromBytes`]. `bytes_to_enum!` defines the enum with the
+/// given `#[repr]` integer type and implements [`EnumFromBytes`] for it, checking every decoded
+/// integer against the discriminants. The enum is then decoded with
+/// [`bytes_to_enum`](fn@bytes_to_enum) and [`iter_enum`] and their `_le`/`_be` variants, which
+/// report invalid values with [`Error::InvalidDiscriminant`].
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_enum, Error};
+///
+/// bytes_to_enum! {
+///     #[derive(Debug, PartialEq)]
+///     Opcode: u8 {
+///         Nop = 0x00,
+///         Load = 0x10,
+///         Store,
+///     }
+/// }
+///
+/// assert_eq!(
+///     bytes_to_enum::<Opcode>(&[0x10, 0x11, 0x00]).unwrap(),
+///     vec![Opcode::Load, Opcode::Store, Opcode::Nop]
+/// );
+/// assert_eq!(
+///     bytes_to_enum::<Opcode>(&[0x00, 0x42]).unwrap_err().to_string(),
+///     "Invalid discriminant 66 for Opcode at offset 1"
+/// );
+/// ```
+///
+/// # Generated Items
+///
+/// The enum is `pub` by default, and a restricted visibility such as `pub(crate)` can precede its
+/// name. The integer type follows the name, and can be `u8`, `u16`, `u32`, `u64`, `i8`, `i16`,
+/// `i32` or `i64`. Attributes such as `#[derive(...)]` and doc comments are forwarded to the enum
+/// and its variants, and variants without an explicit discriminant follow the previous one as in
+/// a regular enum.
+#[macro_export]
+macro_rules! bytes_to_enum {
+    ($(#[$meta:meta])* pub($($restriction:tt)+) $name:ident : $repr:ident { $($variants:tt)* }) => {
+        $crate::bytes_to_enum!(@emit [$(#[$meta])*] [pub($($restriction)+)] $name $repr $($variants)*);
+    };
+    ($(#[$meta:meta])* pub $name:ident : $repr:ident { $($variants:tt)* }) => {
+        $crate::bytes_to_enum!(@emit [$(#[$meta])*] [pub] $name $repr $($variants)*);
+    };
+    ($(#[$meta:meta])* $name:ident : $repr:ident { $($variants:tt)* }) => {
+        $crate::bytes_to_enum!(@emit [$(#[$meta])*] [pub] $name $repr $($variants)*);
+    };
+    (@emit [$($meta:tt)*] [$vis:vis] $name:ident $repr:ident
+        $($(#[$variant_meta:meta])* $variant:ident $(= $discriminant:expr)?),* $(,)?) => {
+        $($meta)*
+        #[repr($repr)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant $(= $discriminant)?,)*
+        }
+
+        impl $crate::EnumFromBytes for $name {
+            const NAME: &'static str = stringify!($name);
+
+            type Repr = $repr;
+
+            fn from_repr(value: $repr) -> ::core::option::Option<Self> {
+                $(
+                    if value == $name::$variant as $repr {
+                        return ::core::option::Option::Some($name::$variant);
+                    }
+                )*
+
+                ::core::option::Option::None
+            }
+        }
+    };
+}
+
+/// Provides a macro to decode a byte literal into an array at compile time.
+///
+/// The conversion functions of this crate run at runtime, which on embedded targets means copying
+/// a lookup table into RAM at startup. `bytes_to_const!` decodes a constant byte slice, such as
+/// the result of `include_bytes!`, in a `const` context instead, so the table is stored already
+/// decoded in the binary. An input whose length is not a multiple of the element size fails to
+/// compile.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_const;
+///
+/// // Would usually be `include_bytes!("sine.bin")`.
+/// const SINE_BYTES: &[u8] = &[0, 0, 0x7F, 0xFF, 0, 0, 0x80, 0x01];
+///
+/// bytes_to_const! {
+///     static SINE: [i16_be] = SINE_BYTES;
+/// }
+///
+/// const SCALE: [u16; 2] = bytes_to_const!(u16_le, &[0x00, 0x01, 0x00, 0x02]);
+///
+/// assert_eq!(SINE, [0, 32767, 0, -32767]);
+/// assert_eq!(SCALE, [256, 512]);
+/// ```
+///
+/// # Forms
+///
+/// `bytes_to_const!(<kind>, <bytes>)` is a constant expression evaluating to an array, whose
+/// length is the number of elements in `<bytes>`. `bytes_to_const! { static NAME: [<kind>] =
+/// <bytes>; }` defines a `static` or `const` item holding that array, without spelling out its
+/// length, and accepts any visibility and attributes before `static` or `const`.
+///
+/// The element kinds are those of [`byte_record!`]: `u8`, `i8`, and `u16`, `u32`, `u64`, `u128`,
+/// `i16`, `i32`, `i64`, `i128`, `f32` or `f64` followed by `_le`, `_be` or `_ne`.
+///
+/// ```compile_fail
+/// // Three bytes don't make a whole number of `u16` elements.
+/// bytes_to_type::bytes_to_const! {
+///     static TABLE: [u16_le] = &[1, 2, 3];
+/// }
+/// ```
+#[macro_export]
+macro_rules! bytes_to_const {
+    ($(#[$meta:meta])* $vis:vis static $name:ident : [$kind:ident] = $bytes:expr $(;)?) => {
+        $(#[$meta])*
+        $vis static $name: [$crate::__private::constant::$kind; $crate::bytes_to_const!(@len $kind, $bytes)] =
+            $crate::bytes_to_const!($kind, $bytes);
+    };
+    ($(#[$meta:meta])* $vis:vis const $name:ident : [$kind:ident] = $bytes:expr $(;)?) => {
+        $(#[$meta])*
+        $vis const $name: [$crate::__private::constant::$kind; $crate::bytes_to_const!(@len $kind, $bytes)] =
+            $crate::bytes_to_const!($kind, $bytes);
+    };
+    (@len $kind:ident, $bytes:expr) => {
+        $bytes.len() / ::core::mem::size_of::<$crate::__private::constant::$kind>()
+    };
+    ($kind:ident, $bytes:expr $(,)?) => {
+        $crate::__private::constant::$kind::<{ $crate::bytes_to_const!(@len $kind, $bytes) }>($bytes)
+    };
+}
+
+/// Provides a macro to embed a file in the executable as a slice of elements.
+///
+/// `include_typed_bytes!("<path>" as <type>)` works like `include_bytes!`, resolving the path
+/// relative to the current file, but evaluates to a `&'static [T]` borrowing the embedded bytes
+/// in native byte order. The bytes are stored with the alignment of `T`, so no copy is made at
+/// runtime, and a file whose length is not a multiple of the size of `T` fails to compile. `T` can
+/// be any [`FromBytes`] type, including derived structs.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::include_typed_bytes;
+///
+/// static WORDS: &[u32] = include_typed_bytes!("../LICENSE" as u32);
+///
+/// assert_eq!(WORDS.len(), include_bytes!("../LICENSE").len() / 4);
+/// ```
+///
+/// ```compile_fail
+/// // The file is 1076 bytes long, which is not a multiple of 8.
+/// static WORDS: &[u64] = bytes_to_type::include_typed_bytes!("../LICENSE" as u64);
+/// ```
+///
+/// Files written in a fixed byte order that may differ from the target's are decoded at compile
+/// time with [`bytes_to_const!`] instead, as in `bytes_to_const!(u32_be,
+/// include_bytes!("table.bin"))`.
+#[macro_export]
+macro_rules! include_typed_bytes {
+    ($path:literal as $type:ty) => {{
+        const ALIGNED: &$crate::__private::constant::Aligned<$type, [u8]> =
+            &$crate::__private::constant::Aligned {
+                align: [],
+                bytes: *include_bytes!($path),
+            };
+        const VALUES: &[$type] = $crate::__private::constant::aligned_slice::<$type>(ALIGNED);
+
+        VALUES
+    }};
+}
+
+/// Provides a macro to split a buffer of interleaved records into one vector per field.
+///
+/// `deinterleave!` takes a byte slice and the list of field types making up each record, and
+/// returns a `Result` holding a tuple with one `Vec` per field, converting an array of structs into
+/// a struct of arrays. All fields are decoded in a single pass over the input. Fields are decoded
+/// in native byte order, unless `le` or `be` follows the list of types.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::deinterleave;
+///
+/// let bytes = [
+///     0, 0, 0x80, 0x3F, 0, 0, 0, 0x40, 7, 0, // 1.0, 2.0, 7
+///     0, 0, 0x40, 0x40, 0, 0, 0x80, 0x40, 8, 0, // 3.0, 4.0, 8
+/// ];
+/// let (x, y, id) = deinterleave!(&bytes, [f32, f32, u16], le).unwrap();
+///
+/// assert_eq!(x, vec![1.0, 3.0]);
+/// assert_eq!(y, vec![2.0, 4.0]);
+/// assert_eq!(id, vec![7, 8]);
+/// ```
+///
+/// # Errors
+///
+/// Evaluates to [`Error::LengthMismatch`] if the length of the input is not a multiple of the size
+/// of a record.
+///
+/// # Limits
+///
+/// A record can have up to 16 fields.
+#[macro_export]
+macro_rules! deinterleave {
+    ($bytes:expr, [$($type:ty),+ $(,)?], le $(,)?) => {
+        $crate::deinterleave!(@start $bytes, from_le_slice, [$($type),+])
+    };
+    ($bytes:expr, [$($type:ty),+ $(,)?], be $(,)?) => {
+        $crate::deinterleave!(@start $bytes, from_be_slice, [$($type),+])
+    };
+    ($bytes:expr, [$($type:ty),+ $(,)?] $(,)?) => {
+        $crate::deinterleave!(@start $bytes, from_ne_slice, [$($type),+])
+    };
+    (@start $bytes:expr, $decode:ident, [$($type:ty),+]) => {
+        $crate::deinterleave!(
+            @name $bytes, $decode, [] [$($type),+]
+            [c0 c1 c2 c3 c4 c5 c6 c7 c8 c9 c10 c11 c12 c13 c14 c15]
+        )
+    };
+    (@name $bytes:expr, $decode:ident, [$($column:tt)*] [$type:ty $(, $rest:ty)*]
+        [$name:ident $($names:ident)*]) => {
+        $crate::deinterleave!(
+            @name $bytes, $decode, [$($column)* ($name $type)] [$($rest),*] [$($names)*]
+        )
+    };
+    (@name $bytes:expr, $decode:ident, [$(($name:ident $type:ty))+] [] [$($names:ident)*]) => {{
+        let bytes: &[u8] = $bytes;
+        let size = 0 $(+ ::core::mem::size_of::<$type>())+;
+
+        $crate::__private::record_count(bytes, size).map(|count| {
+            $(let mut $name = $crate::__private::Vec::<$type>::with_capacity(count);)+
+
+            for record in bytes.chunks_exact(size) {
+                let offset = 0;
+                $(
+                    let end = offset + ::core::mem::size_of::<$type>();
+                    $name.push(<$type as $crate::FromBytes>::$decode(&record[offset..end]));
+                    let offset = end;
+                )+
+                let _ = offset;
+            }
+
+            ($($name,)+)
+        })
+    }};
+}
+
+/// Provides a macro to generate conversion functions for any [`bytemuck::Pod`] type, available
+/// with the `bytemuck` feature.
+///
+/// While [`bytes_to_type!`] is limited to [`FromBytes`] types, `bytes_to_pod!` accepts your own
+/// `Pod` types and relies on the trait for the soundness of the conversion. Since `Pod` types don't
+/// carry any byte order information, only native byte order conversions are generated: for a type
+/// `MyPoint`, the macro generates `bytes_to_my_point`, which copies the bytes into a vector, and
+/// the zero-copy `bytes_to_my_point_slice`. Visibility and names can be customized the same way as
+/// with [`bytes_to_type!`].
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_pod;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(C)]
+/// struct MyPoint {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// unsafe impl bytemuck::Zeroable for MyPoint {}
+/// unsafe impl bytemuck::Pod for MyPoint {}
+///
+/// bytes_to_pod!(MyPoint);
+///
+/// let bytes = bytemuck::bytes_of(&MyPoint { x: 1.0, y: 2.0 }).to_vec();
+///
+/// assert_eq!(bytes_to_my_point(&bytes).unwrap(), vec![MyPoint { x: 1.0, y: 2.0 }]);
+/// ```
+#[cfg(feature = "bytemuck")]
+#[macro_export]
+macro_rules! bytes_to_pod {
+    () => {};
+    (@single [$($vis:tt)*] $type:ty as $name:ident) => {
+        $crate::bytes_to_pod!(@emit [$($vis)*] $type, $name);
+    };
+    (@single [$($vis:tt)*] $type:ty) => {
+        $crate::bytes_to_pod!(@emit [$($vis)*] $type, bytes_to_ $type:snake);
+    };
+    (@emit [$($vis:tt)*] $type:ty, $($name:tt)+) => {
+        $crate::paste! {
+            $($vis)* fn [<$($name)+>](bytes: &[u8]) -> $crate::Result<$crate::__private::Vec<$type>> {
+                $crate::pod_to_vec::<$type>(bytes)
+            }
+
+            $($vis)* fn [<$($name)+ _slice>](bytes: &[u8]) -> $crate::Result<&[$type]> {
+                $crate::pod_to_slice::<$type>(bytes)
             }
         }
     };
+    (pub($($restriction:tt)+) $type:ty $(as $name:ident)? $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_pod!(@single [pub($($restriction)+)] $type $(as $name)?);
+        $($crate::bytes_to_pod!($($rest)*);)?
+    };
+    (pub $type:ty $(as $name:ident)? $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_pod!(@single [pub] $type $(as $name)?);
+        $($crate::bytes_to_pod!($($rest)*);)?
+    };
+    ($type:ty $(as $name:ident)? $(, $($rest:tt)*)?) => {
+        $crate::bytes_to_pod!(@single [pub] $type $(as $name)?);
+        $($crate::bytes_to_pod!($($rest)*);)?
+    };
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use alloc::vec;
+
+    use generated::*;
+
+    use crate::Error;
+
+    /// Not every generated function is exercised by the tests.
+    #[allow(dead_code)]
+    mod generated {
+        bytes_to_type!(u32, pub(crate) i16, pub u16 as decode_samples, pub(super) u64,);
+        type_to_bytes!(u32, i16,);
+
+        byte_record! {
+            #[derive(Debug, PartialEq)]
+            pub(crate) Header {
+                magic: u32_be,
+                version: u16_le,
+                _reserved: skip(2),
+                offset: i64_ne,
+                scale: f32_be,
+                flags: u8,
+            }
+        }
+
+        pub struct Frame;
+
+        bytes_to_type!(impl Frame { pub(crate) i32 as from_raw, u8 });
+
+        use core::num::{Saturating, Wrapping};
+
+        bytes_to_type!(Wrapping<u32>, pub(crate) Saturating<i16>, Wrapping<u8> as wrapped_bytes);
+
+        bytes_to_type!(extern "C" { u8 as ffi_bytes, f64 });
+
+        #[cfg(feature = "wasm")]
+        bytes_to_type!(wasm { f32 as wasm_floats, i64 });
+
+        pub struct Sample(());
+
+        bytes_to_type!(
+            /// Functions that would collide with the ones above outside of a module.
+            pub(crate) mod conv { u32, impl Sample { pub i16 } }
+        );
+    }
+
+    bytes_to_const! {
+        /// The table of a CRC, as it would be embedded in firmware.
+        static TABLE: [u32_be] = &[0, 0, 0, 1, 0xFF, 0xFF, 0xFF, 0xFF];
+    }
 
     #[test]
     fn it_works() {
@@ -81,4 +1514,224 @@ mod tests {
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn it_decodes_little_and_big_endian() {
+        let bytes = vec![1, 2, 3, 4];
+
+        assert_eq!(bytes_to_u32_le(&bytes).unwrap(), vec![0x04030201]);
+        assert_eq!(bytes_to_u32_be(&bytes).unwrap(), vec![0x01020304]);
+    }
+
+    #[test]
+    fn it_returns_error_from_endian_variants_if_bytes_length_is_not_a_multiple_of_type_size() {
+        let bytes = vec![1, 2, 3];
+
+        assert!(bytes_to_u32_le(&bytes).is_err());
+        assert!(bytes_to_u32_be(&bytes).is_err());
+    }
+
+    #[test]
+    fn it_encodes_values_to_bytes() {
+        let values = vec![0x04030201];
+
+        assert_eq!(u32_to_bytes_le(&values), vec![1, 2, 3, 4]);
+        assert_eq!(u32_to_bytes_be(&values), vec![4, 3, 2, 1]);
+        assert_eq!(u32_to_bytes(&values), u32_as_bytes(&values));
+    }
+
+    #[test]
+    fn it_roundtrips_values_through_bytes() {
+        let values = vec![1, 2, u32::MAX];
+
+        assert_eq!(bytes_to_u32(&u32_to_bytes(&values)).unwrap(), values);
+        assert_eq!(bytes_to_u32_le(&u32_to_bytes_le(&values)).unwrap(), values);
+        assert_eq!(bytes_to_u32_be(&u32_to_bytes_be(&values)).unwrap(), values);
+    }
+
+    #[test]
+    fn it_borrows_bytes_as_slice() {
+        let values = vec![1, 2, u32::MAX];
+
+        assert_eq!(
+            bytes_to_u32_slice(u32_as_bytes(&values)).unwrap(),
+            &values[..]
+        );
+    }
+
+    #[cfg(feature = "safe-only")]
+    #[test]
+    fn it_generates_every_function_in_safe_only_mode() {
+        let bytes = [1, 0, 0, 0];
+
+        assert_eq!(bytes_to_u32_le(&bytes).unwrap(), vec![1]);
+        assert_eq!(
+            unsafe { bytes_to_u32_unchecked(&bytes) },
+            vec![u32::from_ne_bytes(bytes)]
+        );
+        assert_eq!(
+            bytes_to_u32_le_validated(&bytes, |&value| value == 1).unwrap(),
+            vec![1]
+        );
+
+        // The views are generated as well, so they can be called whatever the feature set.
+        let _: fn(&[u8]) -> crate::Result<&[u32]> = bytes_to_u32_slice;
+        let _: fn(&mut [u8]) -> crate::Result<&mut [u32]> = bytes_to_u32_slice_mut;
+        assert_eq!(ffi_bytes_slice(&bytes).unwrap(), &bytes[..]);
+    }
+
+    #[test]
+    fn it_converts_bytes_with_unchecked_variants() {
+        let values = vec![1, 2, u32::MAX];
+
+        assert_eq!(
+            unsafe { bytes_to_u32_unchecked(u32_as_bytes(&values)) },
+            values
+        );
+    }
+
+    #[test]
+    fn it_validates_elements_with_validated_variants() {
+        assert_eq!(
+            bytes_to_u32_le_validated(&[1, 0, 0, 0], |&value| value == 1),
+            Ok(vec![1])
+        );
+        assert!(decode_samples_validated(&[0, 0], |_| false).is_err());
+    }
+
+    #[test]
+    fn it_returns_remaining_bytes_from_partial_variants() {
+        let bytes = vec![0, 0, 0, 1, 2, 3];
+
+        assert_eq!(bytes_to_u32_be_partial(&bytes), (vec![1], &[2, 3][..]));
+        assert_eq!(
+            bytes_to_u32_le_partial(&bytes),
+            (vec![0x01000000], &[2, 3][..])
+        );
+        assert_eq!(bytes_to_u32_partial(&bytes[..3]), (vec![], &[0, 0, 0][..]));
+    }
+
+    #[test]
+    fn it_generates_functions_for_multiple_types() {
+        let values = vec![-1, 2];
+
+        assert_eq!(bytes_to_i16_be(&i16_to_bytes_be(&values)).unwrap(), values);
+        assert_eq!(bytes_to_u32_le(&[1, 0, 0, 0]).unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn it_generates_functions_with_custom_names() {
+        assert_eq!(decode_samples_be(&[0, 1]).unwrap(), vec![1]);
+        assert_eq!(decode_samples_le_partial(&[1, 0, 2]), (vec![1], &[2][..]));
+    }
+
+    #[test]
+    fn it_parses_records_and_returns_remaining_bytes() {
+        let mut bytes = vec![0xCA, 0xFE, 0xBA, 0xBE, 3, 0, 0xFF, 0xFF];
+        bytes.extend((-2i64).to_ne_bytes());
+        bytes.extend(1.5f32.to_be_bytes());
+        bytes.extend([7, 42]);
+
+        let (header, rest) = Header::parse(&bytes).unwrap();
+
+        assert_eq!(
+            header,
+            Header {
+                magic: 0xCAFEBABE,
+                version: 3,
+                offset: -2,
+                scale: 1.5,
+                flags: 7,
+            }
+        );
+        assert_eq!(rest, &[42]);
+        assert_eq!(Header::SIZE, 21);
+    }
+
+    #[test]
+    fn it_returns_error_if_record_is_truncated() {
+        assert_eq!(
+            Header::parse(&[0; 20]),
+            Err(Error::Truncated {
+                len: 20,
+                expected: 21
+            })
+        );
+    }
+
+    #[test]
+    fn it_deinterleaves_records() {
+        let bytes = [1, 0, 2, 3, 4, 5, 0, 6, 0xFF, 0xFE];
+
+        let (a, b, c) = crate::deinterleave!(&bytes, [u8, u16, i16], be).unwrap();
+        assert_eq!(a, vec![1, 5]);
+        assert_eq!(b, vec![2, 6]);
+        assert_eq!(c, vec![0x0304, -2]);
+
+        let (a,) = crate::deinterleave!(&bytes[..4], [u16], le).unwrap();
+        assert_eq!(a, vec![1, 0x0302]);
+
+        assert_eq!(
+            crate::deinterleave!(&bytes[..9], [u8, u16, i16]),
+            Err(Error::LengthMismatch {
+                len: 9,
+                size: 5,
+                remainder: 4
+            })
+        );
+    }
+
+    #[test]
+    fn it_generates_associated_functions() {
+        assert_eq!(Frame::from_raw_le(&[255, 255, 255, 255]).unwrap(), vec![-1]);
+        assert_eq!(Frame::from_raw_be(&[0, 0, 1, 0]).unwrap(), vec![256]);
+        assert_eq!(Frame::bytes_to_u8_partial(&[3]), (vec![3], &[][..]));
+    }
+
+    #[test]
+    fn it_generates_functions_for_wrapper_types() {
+        use core::num::{Saturating, Wrapping};
+
+        assert_eq!(
+            bytes_to_wrapping_u32_be(&[0, 0, 0, 1]).unwrap(),
+            vec![Wrapping(1)]
+        );
+        assert_eq!(
+            bytes_to_saturating_i16_le(&[255, 127]).unwrap(),
+            vec![Saturating(i16::MAX)]
+        );
+        assert_eq!(wrapped_bytes(&[7]).unwrap(), vec![Wrapping(7)]);
+    }
+
+    #[test]
+    fn it_generates_functions_in_a_module() {
+        assert_eq!(conv::bytes_to_u32_be(&[0, 0, 0, 1]).unwrap(), vec![1]);
+        assert_eq!(bytes_to_u32_le(&[1, 0, 0, 0]).unwrap(), vec![1]);
+        assert_eq!(
+            generated::Sample::bytes_to_i16_be(&[255, 254]).unwrap(),
+            vec![-2]
+        );
+    }
+
+    #[test]
+    fn it_decodes_byte_literals_at_compile_time() {
+        const FLOATS: [f32; 1] = bytes_to_const!(f32_le, &[0, 0, 0x80, 0x3F]);
+        const SIGNED: [i8; 2] = bytes_to_const!(i8, &[0xFF, 1]);
+
+        assert_eq!(TABLE, [1, u32::MAX]);
+        assert_eq!(FLOATS, [1.0]);
+        assert_eq!(SIGNED, [-1, 1]);
+        assert_eq!(bytes_to_const!(u128_ne, &[0; 32]), [0, 0]);
+    }
+
+    #[test]
+    fn it_includes_files_as_slices() {
+        let words = include_typed_bytes!("../LICENSE" as u32);
+
+        assert_eq!(words.as_ptr() as usize % core::mem::align_of::<u32>(), 0);
+        assert_eq!(
+            words,
+            crate::bytes_to_vec::<u32>(include_bytes!("../LICENSE")).unwrap()
+        );
+    }
 }
@@ -0,0 +1,162 @@
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::{bytes_to_slice, FromBytes};
+
+/// A memory-mapped file viewed as a slice of `T` in native byte order, returned by
+/// [`mmap_to_slice`].
+///
+/// The file stays mapped for as long as the `MappedSlice` is alive, and is unmapped when it is
+/// dropped.
+pub struct MappedSlice<T> {
+    map: Mmap,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+impl<T: FromBytes> MappedSlice<T> {
+    /// Views an existing memory map as a slice of `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] wrapping
+    /// [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of the map is not a
+    /// multiple of the size of `T`, or [`Error::Misaligned`](crate::Error::Misaligned) if it is
+    /// not aligned for `T`.
+    pub fn new(map: Mmap) -> io::Result<Self> {
+        let len = if map.is_empty() {
+            0
+        } else {
+            bytes_to_slice::<T>(&map)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?
+                .len()
+        };
+
+        Ok(MappedSlice {
+            map,
+            len,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns the underlying memory map.
+    pub fn into_inner(self) -> Mmap {
+        self.map
+    }
+}
+
+impl<T> Deref for MappedSlice<T> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        if self.len == 0 {
+            return &[];
+        }
+
+        // SAFETY: the length and alignment of the map were checked in `new`, the map lives as
+        // long as `self`, and every bit pattern is a valid value of a `FromBytes` type.
+        unsafe { core::slice::from_raw_parts(self.map.as_ptr() as *const T, self.len) }
+    }
+}
+
+impl<T> AsRef<[T]> for MappedSlice<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for MappedSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Maps the file at `path` into memory and views it as a slice of `T` in native byte order,
+/// without copying.
+///
+/// This is the most efficient way to read large files of elements, since only the pages that are
+/// accessed are read from disk. Memory maps are page-aligned, so the alignment check only fails
+/// for types aligned to more than a page. The contents of the file are not converted, so the
+/// file must be in the native byte order of the platform, or `T` must store its value in a fixed
+/// byte order, such as [`U32Be`](crate::U32Be). This function is available with the `memmap2`
+/// feature.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::mmap_to_slice;
+///
+/// # let path = std::env::temp_dir().join("bytes_to_type_mmap_doctest.bin");
+/// # std::fs::write(&path, [1u16, 2, 3].map(u16::to_ne_bytes).concat()).unwrap();
+/// // SAFETY: the file is not modified while it is mapped.
+/// let values = unsafe { mmap_to_slice::<u16>(&path) }.expect("Failed to map file");
+///
+/// assert_eq!(&values[..], &[1, 2, 3]);
+/// # drop(values);
+/// # std::fs::remove_file(&path).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns any I/O error produced when opening or mapping the file. If the length of the file is
+/// not a multiple of the size of `T`, returns an error of kind [`io::ErrorKind::InvalidData`]
+/// wrapping [`Error::LengthMismatch`](crate::Error::LengthMismatch).
+///
+/// # Safety
+///
+/// The file must not be modified or truncated, by this or another process, while it is mapped.
+/// Otherwise, the contents of the returned slice may change unexpectedly, or accessing it may
+/// crash the process.
+pub unsafe fn mmap_to_slice<T: FromBytes>(path: impl AsRef<Path>) -> io::Result<MappedSlice<T>> {
+    let file = File::open(path)?;
+    // SAFETY: the caller guarantees that the file is not modified while it is mapped.
+    let map = unsafe { Mmap::map(&file)? };
+
+    MappedSlice::new(map)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("bytes_to_type_{}_{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn it_maps_files_as_slices() {
+        let path = temp_file("values.bin", &[1u32, 2].map(u32::to_ne_bytes).concat());
+        let values = unsafe { mmap_to_slice::<u32>(&path) }.unwrap();
+
+        assert_eq!(&values[..], &[1, 2]);
+        assert_eq!(values.into_inner().len(), 8);
+        std::fs::remove_file(&path).unwrap();
+
+        let path = temp_file("empty.bin", &[]);
+        assert!(unsafe { mmap_to_slice::<u64>(&path) }.unwrap().is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn it_returns_error_if_file_length_is_not_a_multiple_of_type_size() {
+        let path = temp_file("odd.bin", &[0; 5]);
+        let error = unsafe { mmap_to_slice::<u16>(&path) }.unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            error.into_inner().unwrap().downcast_ref::<crate::Error>(),
+            Some(&crate::Error::length_mismatch(5, 2))
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+use core::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+
+use crate::convert::decode_records;
+use crate::Result;
+
+/// Converts a byte slice into a vector of IPv4 addresses, each stored as 4 octets in network byte
+/// order.
+///
+/// # Example
+///
+/// ```
+/// use std::net::Ipv4Addr;
+///
+/// use bytes_to_type::bytes_to_ipv4;
+///
+/// assert_eq!(
+///     bytes_to_ipv4(&[192, 168, 0, 1, 10, 0, 0, 2]).unwrap(),
+///     vec![Ipv4Addr::new(192, 168, 0, 1), Ipv4Addr::new(10, 0, 0, 2)]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 4.
+pub fn bytes_to_ipv4(bytes: &[u8]) -> Result<Vec<Ipv4Addr>> {
+    decode_records(bytes, Ipv4Addr::from)
+}
+
+/// Converts a byte slice into a vector of IPv6 addresses, each stored as 16 octets in network byte
+/// order.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 16.
+pub fn bytes_to_ipv6(bytes: &[u8]) -> Result<Vec<Ipv6Addr>> {
+    decode_records(bytes, Ipv6Addr::from)
+}
+
+/// Converts a byte slice into a vector of IPv4 socket addresses, each stored as the 4 octets of
+/// the address followed by the port as a big-endian `u16`.
+///
+/// This 6-byte layout is the one used by compact peer lists and many other wire formats.
+///
+/// # Example
+///
+/// ```
+/// use std::net::{Ipv4Addr, SocketAddrV4};
+///
+/// use bytes_to_type::bytes_to_socketaddrv4;
+///
+/// assert_eq!(
+///     bytes_to_socketaddrv4(&[127, 0, 0, 1, 0x1F, 0x90]).unwrap(),
+///     vec![SocketAddrV4::new(Ipv4Addr::LOCALHOST, 8080)]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 6.
+pub fn bytes_to_socketaddrv4(bytes: &[u8]) -> Result<Vec<SocketAddrV4>> {
+    decode_records(bytes, |record: [u8; 6]| {
+        let [a, b, c, d, port @ ..] = record;
+
+        SocketAddrV4::new(Ipv4Addr::new(a, b, c, d), u16::from_be_bytes(port))
+    })
+}
+
+/// Converts a byte slice into a vector of IPv6 socket addresses, each stored as the 16 octets of
+/// the address followed by the port as a big-endian `u16`.
+///
+/// The flow information and scope ID of the returned addresses are zero.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 18.
+pub fn bytes_to_socketaddrv6(bytes: &[u8]) -> Result<Vec<SocketAddrV6>> {
+    decode_records(bytes, |record: [u8; 18]| {
+        let (address, port) = record.split_at(16);
+
+        SocketAddrV6::new(
+            Ipv6Addr::from(<[u8; 16]>::try_from(address).unwrap()),
+            u16::from_be_bytes(port.try_into().unwrap()),
+            0,
+            0,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_converts_bytes_to_ip_addresses() {
+        let mut bytes = vec![0; 16];
+        bytes[15] = 1;
+
+        assert_eq!(bytes_to_ipv6(&bytes).unwrap(), vec![Ipv6Addr::LOCALHOST]);
+        assert_eq!(
+            bytes_to_ipv4(&bytes[12..]).unwrap(),
+            vec![Ipv4Addr::new(0, 0, 0, 1)]
+        );
+    }
+
+    #[test]
+    fn it_converts_bytes_to_socket_addresses() {
+        let mut bytes = vec![0; 18];
+        bytes[15] = 1;
+        bytes[16] = 0x01;
+        bytes[17] = 0xBB;
+
+        assert_eq!(
+            bytes_to_socketaddrv6(&bytes).unwrap(),
+            vec![SocketAddrV6::new(Ipv6Addr::LOCALHOST, 443, 0, 0)]
+        );
+        assert_eq!(
+            bytes_to_socketaddrv4(&bytes[12..]).unwrap(),
+            vec![SocketAddrV4::new(Ipv4Addr::new(0, 0, 0, 1), 443)]
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_record_size() {
+        assert_eq!(bytes_to_ipv4(&[1, 2, 3]), Err(Error::length_mismatch(3, 4)));
+        assert_eq!(
+            bytes_to_socketaddrv4(&[0; 8]),
+            Err(Error::length_mismatch(8, 6))
+        );
+    }
+}
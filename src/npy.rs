@@ -0,0 +1,440 @@
+//! Decoding of NumPy `.npy` files, available with the `npy` feature.
+//!
+//! A `.npy` file starts with a magic string and a header describing the array as a Python
+//! dictionary literal, such as `{'descr': '<f4', 'fortran_order': False, 'shape': (2, 3), }`,
+//! followed by the raw elements. The functions of this module parse the header and decode the
+//! elements with [`decode_dynamic`], so arrays written by `numpy.save` can be loaded without
+//! knowing their element type in advance.
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::npy;
+//! use bytes_to_type::TypedVec;
+//!
+//! let mut file = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+//! let header = "{'descr': '<u2', 'fortran_order': False, 'shape': (2, 2), }";
+//! file.extend_from_slice(format!("{:<117}\n", header).as_bytes());
+//! file.extend_from_slice(&[1, 0, 2, 0, 3, 0, 4, 0]);
+//!
+//! let array = npy::decode(&file).unwrap();
+//!
+//! assert_eq!(array.shape, vec![2, 2]);
+//! assert_eq!(array.data, TypedVec::U16(vec![1, 2, 3, 4]));
+//! ```
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{decode_dynamic, Endianness, Error, ScalarType, TypedVec};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The error type returned by the functions of this module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NpyError {
+    /// The elements could not be decoded.
+    Decode(Error),
+    /// The file does not start with the `.npy` magic string, or its header is malformed.
+    InvalidHeader,
+    /// The element type of the array is not supported, such as a structured or object dtype.
+    UnsupportedDtype(String),
+}
+
+impl From<Error> for NpyError {
+    fn from(error: Error) -> Self {
+        NpyError::Decode(error)
+    }
+}
+
+impl fmt::Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NpyError::Decode(error) => error.fmt(f),
+            NpyError::InvalidHeader => f.write_str("Invalid .npy header"),
+            NpyError::UnsupportedDtype(descr) => write!(f, "Unsupported dtype {}", descr),
+        }
+    }
+}
+
+impl core::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            NpyError::Decode(error) => Some(error),
+            NpyError::InvalidHeader | NpyError::UnsupportedDtype(_) => None,
+        }
+    }
+}
+
+/// The header of a `.npy` file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    /// The type of the elements.
+    pub scalar_type: ScalarType,
+    /// The byte order of the elements.
+    pub endianness: Endianness,
+    /// The length of each dimension of the array.
+    pub shape: Vec<usize>,
+    /// Whether the elements are stored in column-major order rather than row-major order.
+    pub fortran_order: bool,
+}
+
+impl Header {
+    /// Returns the number of elements of the array.
+    pub fn len(&self) -> usize {
+        self.shape.iter().product()
+    }
+
+    /// Returns `true` if the array has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// An array decoded from a `.npy` file, returned by [`decode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Array {
+    /// The elements, in the order they are stored in the file.
+    pub data: TypedVec,
+    /// The length of each dimension of the array.
+    pub shape: Vec<usize>,
+    /// Whether the elements are stored in column-major order rather than row-major order.
+    pub fortran_order: bool,
+}
+
+/// A value of the header dictionary.
+enum Value<'a> {
+    String(&'a str),
+    Bool(bool),
+    Tuple(Vec<usize>),
+    Other,
+}
+
+/// A parser for the subset of Python literals used in `.npy` headers.
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        self.rest = self.rest.trim_start();
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+
+        match self.rest.strip_prefix(token) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, token: &str) -> Result<(), NpyError> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(NpyError::InvalidHeader)
+        }
+    }
+
+    fn string(&mut self) -> Result<&'a str, NpyError> {
+        self.skip_whitespace();
+
+        let quote = match self.rest.chars().next() {
+            Some(quote @ ('\'' | '"')) => quote,
+            _ => return Err(NpyError::InvalidHeader),
+        };
+        let (value, rest) = self.rest[1..]
+            .split_once(quote)
+            .ok_or(NpyError::InvalidHeader)?;
+        self.rest = rest;
+
+        Ok(value)
+    }
+
+    fn integer(&mut self) -> Result<usize, NpyError> {
+        self.skip_whitespace();
+
+        let end = self
+            .rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len());
+        let value = self.rest[..end]
+            .parse()
+            .map_err(|_| NpyError::InvalidHeader)?;
+        self.rest = &self.rest[end..];
+
+        Ok(value)
+    }
+
+    fn value(&mut self) -> Result<Value<'a>, NpyError> {
+        self.skip_whitespace();
+
+        if self.rest.starts_with(['\'', '"']) {
+            return self.string().map(Value::String);
+        }
+        if self.eat("True") {
+            return Ok(Value::Bool(true));
+        }
+        if self.eat("False") {
+            return Ok(Value::Bool(false));
+        }
+        if self.eat("(") {
+            let mut values = Vec::new();
+
+            while !self.eat(")") {
+                values.push(self.integer()?);
+
+                if !self.eat(",") {
+                    self.expect(")")?;
+                    break;
+                }
+            }
+
+            return Ok(Value::Tuple(values));
+        }
+
+        // Skip values this parser does not understand, such as the list describing a structured
+        // dtype, up to the next top-level comma or closing brace.
+        let mut depth = 0usize;
+        let end = self
+            .rest
+            .find(|c: char| {
+                match c {
+                    '[' | '(' | '{' => depth += 1,
+                    ']' | ')' if depth > 0 => depth -= 1,
+                    '}' if depth > 0 => depth -= 1,
+                    ',' | '}' => return true,
+                    _ => {}
+                }
+                false
+            })
+            .ok_or(NpyError::InvalidHeader)?;
+        self.rest = &self.rest[end..];
+
+        Ok(Value::Other)
+    }
+}
+
+/// Parses a dtype descriptor such as `<f4` into a scalar type and byte order.
+fn parse_descr(descr: &str) -> Result<(ScalarType, Endianness), NpyError> {
+    let unsupported = || NpyError::UnsupportedDtype(descr.to_string());
+
+    let mut chars = descr.chars();
+    let endianness = match chars.next() {
+        Some('<') => Endianness::Little,
+        Some('>') => Endianness::Big,
+        Some('|' | '=') => Endianness::NATIVE,
+        _ => return Err(unsupported()),
+    };
+
+    let scalar_type = match chars.as_str() {
+        "u1" => ScalarType::U8,
+        "u2" => ScalarType::U16,
+        "u4" => ScalarType::U32,
+        "u8" => ScalarType::U64,
+        "i1" => ScalarType::I8,
+        "i2" => ScalarType::I16,
+        "i4" => ScalarType::I32,
+        "i8" => ScalarType::I64,
+        #[cfg(feature = "half")]
+        "f2" => ScalarType::F16,
+        "f4" => ScalarType::F32,
+        "f8" => ScalarType::F64,
+        _ => return Err(unsupported()),
+    };
+
+    Ok((scalar_type, endianness))
+}
+
+/// Parses the magic string and header of a `.npy` file, returning the header along with the
+/// bytes of the elements that follow it.
+///
+/// Versions 1.0, 2.0 and 3.0 of the format are accepted.
+///
+/// # Errors
+///
+/// Returns [`NpyError::InvalidHeader`] if `bytes` doesn't start with a well-formed header, or
+/// [`NpyError::UnsupportedDtype`] if the element type is not one of the integer and
+/// floating-point types of [`ScalarType`].
+pub fn parse_header(bytes: &[u8]) -> Result<(Header, &[u8]), NpyError> {
+    let rest = bytes.strip_prefix(MAGIC).ok_or(NpyError::InvalidHeader)?;
+
+    let (header_len, rest) = match rest {
+        [1, _, a, b, rest @ ..] => (usize::from(u16::from_le_bytes([*a, *b])), rest),
+        [2 | 3, _, a, b, c, d, rest @ ..] => (u32::from_le_bytes([*a, *b, *c, *d]) as usize, rest),
+        _ => return Err(NpyError::InvalidHeader),
+    };
+    if rest.len() < header_len {
+        return Err(NpyError::InvalidHeader);
+    }
+
+    let (header, data) = rest.split_at(header_len);
+    let header = core::str::from_utf8(header).map_err(|_| NpyError::InvalidHeader)?;
+
+    let mut parser = Parser { rest: header };
+    let mut descr = None;
+    let mut fortran_order = None;
+    let mut shape = None;
+
+    parser.expect("{")?;
+    while !parser.eat("}") {
+        let key = parser.string()?;
+        parser.expect(":")?;
+
+        match (key, parser.value()?) {
+            ("descr", Value::String(value)) => descr = Some(parse_descr(value)?),
+            ("descr", _) => return Err(NpyError::UnsupportedDtype("structured".to_string())),
+            ("fortran_order", Value::Bool(value)) => fortran_order = Some(value),
+            ("shape", Value::Tuple(value)) => shape = Some(value),
+            ("fortran_order" | "shape", _) => return Err(NpyError::InvalidHeader),
+            _ => {}
+        }
+
+        if !parser.eat(",") {
+            parser.expect("}")?;
+            break;
+        }
+    }
+
+    let ((scalar_type, endianness), fortran_order, shape) = match (descr, fortran_order, shape) {
+        (Some(descr), Some(fortran_order), Some(shape)) => (descr, fortran_order, shape),
+        _ => return Err(NpyError::InvalidHeader),
+    };
+
+    Ok((
+        Header {
+            scalar_type,
+            endianness,
+            shape,
+            fortran_order,
+        },
+        data,
+    ))
+}
+
+/// Decodes a `.npy` file into its elements and shape.
+///
+/// # Errors
+///
+/// Returns the errors of [`parse_header`], or [`NpyError::Decode`] wrapping
+/// [`Error::UnexpectedLength`] if the file doesn't hold exactly as many elements as the shape
+/// requires.
+pub fn decode(bytes: &[u8]) -> Result<Array, NpyError> {
+    let (header, data) = parse_header(bytes)?;
+
+    let expected = header
+        .shape
+        .iter()
+        .try_fold(header.scalar_type.size(), |len, &dimension| {
+            len.checked_mul(dimension)
+        });
+    if expected != Some(data.len()) {
+        return Err(NpyError::Decode(Error::UnexpectedLength {
+            len: data.len(),
+            expected: expected.unwrap_or(usize::MAX),
+        }));
+    }
+
+    Ok(Array {
+        data: decode_dynamic(data, header.scalar_type, header.endianness)?,
+        shape: header.shape,
+        fortran_order: header.fortran_order,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    fn npy(version: u8, header: &str, data: &[u8]) -> Vec<u8> {
+        let mut file = MAGIC.to_vec();
+        file.extend_from_slice(&[version, 0]);
+        if version == 1 {
+            file.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        } else {
+            file.extend_from_slice(&(header.len() as u32).to_le_bytes());
+        }
+        file.extend_from_slice(header.as_bytes());
+        file.extend_from_slice(data);
+        file
+    }
+
+    #[test]
+    fn it_decodes_arrays() {
+        let file = npy(
+            2,
+            "{\"shape\": (3,), \"fortran_order\": True, \"descr\": \">i2\"}",
+            &[0, 1, 0xFF, 0xFF, 0, 3],
+        );
+        let array = decode(&file).unwrap();
+
+        assert_eq!(array.data, TypedVec::I16(vec![1, -1, 3]));
+        assert_eq!(array.shape, vec![3]);
+        assert!(array.fortran_order);
+
+        let file = npy(
+            1,
+            "{'descr': '<f8', 'fortran_order': False, 'shape': (), }",
+            &[0; 8],
+        );
+        assert_eq!(decode(&file).unwrap().data, TypedVec::F64(vec![0.0]));
+    }
+
+    #[test]
+    fn it_parses_headers() {
+        let file = npy(
+            3,
+            "{'descr': '|u1', 'fortran_order': False, 'shape': (2, 0, 4), }\n",
+            &[],
+        );
+        let (header, data) = parse_header(&file).unwrap();
+
+        assert_eq!(header.scalar_type, ScalarType::U8);
+        assert_eq!(header.shape, vec![2, 0, 4]);
+        assert!(header.is_empty());
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn it_returns_errors_on_invalid_files() {
+        assert_eq!(parse_header(b"NUMPY").unwrap_err(), NpyError::InvalidHeader);
+        assert_eq!(
+            decode(&npy(1, "{'descr': '<f4', 'shape': (1,)}", &[0; 4])).unwrap_err(),
+            NpyError::InvalidHeader
+        );
+        assert_eq!(
+            decode(&npy(
+                1,
+                "{'descr': '<c8', 'fortran_order': False, 'shape': (1,)}",
+                &[]
+            )),
+            Err(NpyError::UnsupportedDtype("<c8".to_string()))
+        );
+        assert_eq!(
+            decode(&npy(
+                1,
+                "{'descr': [('x', '<i4')], 'fortran_order': False, 'shape': (1,)}",
+                &[]
+            )),
+            Err(NpyError::UnsupportedDtype("structured".to_string()))
+        );
+        assert_eq!(
+            decode(&npy(
+                1,
+                "{'descr': '<u2', 'fortran_order': False, 'shape': (2,)}",
+                &[0; 2]
+            )),
+            Err(NpyError::Decode(Error::UnexpectedLength {
+                len: 2,
+                expected: 4
+            }))
+        );
+    }
+}
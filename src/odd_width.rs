@@ -0,0 +1,168 @@
+use alloc::vec::Vec;
+
+use crate::convert::decode_records;
+use crate::Result;
+
+pub(crate) fn u24_le([a, b, c]: [u8; 3]) -> u32 {
+    u32::from_le_bytes([a, b, c, 0])
+}
+
+pub(crate) fn u24_be([a, b, c]: [u8; 3]) -> u32 {
+    u32::from_be_bytes([0, a, b, c])
+}
+
+fn u48_le([a, b, c, d, e, f]: [u8; 6]) -> u64 {
+    u64::from_le_bytes([a, b, c, d, e, f, 0, 0])
+}
+
+fn u48_be([a, b, c, d, e, f]: [u8; 6]) -> u64 {
+    u64::from_be_bytes([0, 0, a, b, c, d, e, f])
+}
+
+/// Sign-extends the low 24 bits of `value`.
+pub(crate) fn extend_i24(value: u32) -> i32 {
+    ((value << 8) as i32) >> 8
+}
+
+/// Sign-extends the low 48 bits of `value`.
+fn extend_i48(value: u64) -> i64 {
+    ((value << 16) as i64) >> 16
+}
+
+/// Converts a byte slice into a vector of `u32`, decoding each group of 3 bytes as a little-endian
+/// 24-bit unsigned integer.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 3.
+pub fn bytes_to_u24_le(bytes: &[u8]) -> Result<Vec<u32>> {
+    decode_records(bytes, u24_le)
+}
+
+/// Converts a byte slice into a vector of `u32`, decoding each group of 3 bytes as a big-endian
+/// 24-bit unsigned integer.
+///
+/// # Errors
+///
+/// See [`bytes_to_u24_le`].
+pub fn bytes_to_u24_be(bytes: &[u8]) -> Result<Vec<u32>> {
+    decode_records(bytes, u24_be)
+}
+
+/// Converts a byte slice into a vector of `i32`, decoding each group of 3 bytes as a little-endian
+/// 24-bit signed integer with sign extension.
+///
+/// This is the sample format of 24-bit PCM audio.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_i24_le;
+///
+/// let samples = [0xFF, 0xFF, 0x7F, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0xFF];
+///
+/// assert_eq!(bytes_to_i24_le(&samples).unwrap(), vec![8_388_607, -8_388_608, -1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 3.
+pub fn bytes_to_i24_le(bytes: &[u8]) -> Result<Vec<i32>> {
+    decode_records(bytes, |record| extend_i24(u24_le(record)))
+}
+
+/// Converts a byte slice into a vector of `i32`, decoding each group of 3 bytes as a big-endian
+/// 24-bit signed integer with sign extension.
+///
+/// # Errors
+///
+/// See [`bytes_to_i24_le`].
+pub fn bytes_to_i24_be(bytes: &[u8]) -> Result<Vec<i32>> {
+    decode_records(bytes, |record| extend_i24(u24_be(record)))
+}
+
+/// Converts a byte slice into a vector of `u64`, decoding each group of 6 bytes as a little-endian
+/// 48-bit unsigned integer.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 6.
+pub fn bytes_to_u48_le(bytes: &[u8]) -> Result<Vec<u64>> {
+    decode_records(bytes, u48_le)
+}
+
+/// Converts a byte slice into a vector of `u64`, decoding each group of 6 bytes as a big-endian
+/// 48-bit unsigned integer, such as a MAC address.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_u48_be;
+///
+/// let mac = [0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E];
+///
+/// assert_eq!(bytes_to_u48_be(&mac).unwrap(), vec![0x001A_2B3C_4D5E]);
+/// ```
+///
+/// # Errors
+///
+/// See [`bytes_to_u48_le`].
+pub fn bytes_to_u48_be(bytes: &[u8]) -> Result<Vec<u64>> {
+    decode_records(bytes, u48_be)
+}
+
+/// Converts a byte slice into a vector of `i64`, decoding each group of 6 bytes as a little-endian
+/// 48-bit signed integer with sign extension.
+///
+/// # Errors
+///
+/// See [`bytes_to_u48_le`].
+pub fn bytes_to_i48_le(bytes: &[u8]) -> Result<Vec<i64>> {
+    decode_records(bytes, |record| extend_i48(u48_le(record)))
+}
+
+/// Converts a byte slice into a vector of `i64`, decoding each group of 6 bytes as a big-endian
+/// 48-bit signed integer with sign extension.
+///
+/// # Errors
+///
+/// See [`bytes_to_u48_le`].
+pub fn bytes_to_i48_be(bytes: &[u8]) -> Result<Vec<i64>> {
+    decode_records(bytes, |record| extend_i48(u48_be(record)))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_decodes_24_bit_integers() {
+        let bytes = [0x01, 0x02, 0x83, 0xFF, 0xFF, 0xFE];
+
+        assert_eq!(bytes_to_u24_le(&bytes).unwrap(), vec![0x830201, 0xFEFFFF]);
+        assert_eq!(bytes_to_u24_be(&bytes).unwrap(), vec![0x010283, 0xFFFFFE]);
+        assert_eq!(bytes_to_i24_le(&bytes).unwrap(), vec![-0x7CFDFF, -0x010001]);
+        assert_eq!(bytes_to_i24_be(&bytes).unwrap(), vec![0x010283, -2]);
+    }
+
+    #[test]
+    fn it_decodes_48_bit_integers() {
+        let bytes = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F];
+
+        assert_eq!(bytes_to_u48_le(&bytes).unwrap(), vec![0x7FFF_FFFF_FFFF]);
+        assert_eq!(bytes_to_i48_le(&bytes).unwrap(), vec![0x7FFF_FFFF_FFFF]);
+        assert_eq!(bytes_to_i48_be(&bytes).unwrap(), vec![-129]);
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_width() {
+        assert_eq!(bytes_to_i24_le(&[0; 4]), Err(Error::length_mismatch(4, 3)));
+        assert_eq!(bytes_to_u48_be(&[0; 9]), Err(Error::length_mismatch(9, 6)));
+    }
+}
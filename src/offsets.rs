@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+
+use crate::convert::check_length;
+use crate::{Error, FromBytes, Result};
+
+fn bytes_to_offset_slices_with<'a, T: FromBytes + Into<u64>>(
+    table: &[u8],
+    data: &'a [u8],
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<&'a [u8]>> {
+    check_length::<T>(table)?;
+
+    let size = core::mem::size_of::<T>();
+    let mut slices = Vec::with_capacity((table.len() / size).saturating_sub(1));
+    let mut start = None;
+
+    for (index, chunk) in table.chunks_exact(size).enumerate() {
+        let value = decode(chunk).into();
+        let end = usize::try_from(value)
+            .ok()
+            .filter(|&end| end <= data.len() && start.is_none_or(|start| start <= end))
+            .ok_or(Error::InvalidOffset {
+                offset: index * size,
+                value,
+            })?;
+
+        if let Some(start) = start {
+            slices.push(&data[start..end]);
+        }
+
+        start = Some(end);
+    }
+
+    Ok(slices)
+}
+
+/// Decodes a table of offsets stored as `T` in native byte order and returns the slices of `data`
+/// they delimit.
+///
+/// Container formats often store variable-size blobs behind a table of `n + 1` offsets, where
+/// blob `i` spans from offset `i` to offset `i + 1`. The offsets are relative to the start of
+/// `data`, and each one is checked to be at least the previous one and at most the length of
+/// `data`, so the returned slices can be used without further validation. A table with fewer than
+/// two offsets delimits no slices.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_offset_slices_le;
+///
+/// // Three blobs: "abc", an empty one, and "de".
+/// let table = [0, 0, 0, 0, 3, 0, 0, 0, 3, 0, 0, 0, 5, 0, 0, 0];
+/// let data = b"abcde";
+///
+/// let blobs = bytes_to_offset_slices_le::<u32>(&table, data).unwrap();
+/// assert_eq!(blobs, [&b"abc"[..], b"", b"de"]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `table` is not a multiple of the size of
+/// `T`, or [`Error::InvalidOffset`] with the first offset that is smaller than the previous one or
+/// larger than the length of `data`.
+pub fn bytes_to_offset_slices<'a, T: FromBytes + Into<u64>>(
+    table: &[u8],
+    data: &'a [u8],
+) -> Result<Vec<&'a [u8]>> {
+    bytes_to_offset_slices_with(table, data, T::from_ne_slice)
+}
+
+/// Decodes a table of little-endian offsets and returns the slices of `data` they delimit.
+///
+/// # Errors
+///
+/// See [`bytes_to_offset_slices`].
+pub fn bytes_to_offset_slices_le<'a, T: FromBytes + Into<u64>>(
+    table: &[u8],
+    data: &'a [u8],
+) -> Result<Vec<&'a [u8]>> {
+    bytes_to_offset_slices_with(table, data, T::from_le_slice)
+}
+
+/// Decodes a table of big-endian offsets and returns the slices of `data` they delimit.
+///
+/// # Errors
+///
+/// See [`bytes_to_offset_slices`].
+pub fn bytes_to_offset_slices_be<'a, T: FromBytes + Into<u64>>(
+    table: &[u8],
+    data: &'a [u8],
+) -> Result<Vec<&'a [u8]>> {
+    bytes_to_offset_slices_with(table, data, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_returns_the_slices_between_offsets() {
+        let data = [10, 20, 30, 40];
+
+        assert_eq!(
+            bytes_to_offset_slices_be::<u16>(&[0, 1, 0, 1, 0, 4], &data).unwrap(),
+            vec![&data[1..1], &data[1..4]]
+        );
+        assert!(bytes_to_offset_slices::<u64>(&[], &data)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            bytes_to_offset_slices::<u8>(&[4], &data).unwrap(),
+            Vec::<&[u8]>::new()
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_offsets() {
+        let data = [0; 4];
+
+        assert_eq!(
+            bytes_to_offset_slices_le::<u32>(&[2, 0, 0, 0, 1, 0, 0, 0], &data),
+            Err(Error::InvalidOffset {
+                offset: 4,
+                value: 1
+            })
+        );
+        assert_eq!(
+            bytes_to_offset_slices::<u8>(&[0, 5], &data),
+            Err(Error::InvalidOffset {
+                offset: 1,
+                value: 5
+            })
+        );
+        assert_eq!(
+            bytes_to_offset_slices::<u32>(&[0; 6], &data),
+            Err(Error::length_mismatch(6, 4))
+        );
+    }
+}
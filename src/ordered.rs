@@ -0,0 +1,161 @@
+use core::fmt;
+
+use crate::FromBytes;
+
+macro_rules! ordered_types {
+    ($($name:ident($type:ty, $size:literal, $from:ident, $to:ident, $order:literal);)*) => {
+        $(
+            #[doc = concat!(
+                "A `", stringify!($type), "` stored in ", $order, " byte order, regardless of ",
+                "the byte order of the target platform."
+            )]
+            ///
+            /// Since it is a plain byte array with an alignment of 1, it can be used as a field of
+            /// a `#[derive(FromBytes)]` struct to describe a record layout exactly, and such
+            /// records can be viewed in place with [`bytes_to_slice`](crate::bytes_to_slice) at
+            /// any offset. The value is decoded on every [`get`](Self::get), so the byte order of
+            /// the field is always correct, whichever function is used to convert the record.
+            ///
+            /// Equality and hashing compare the stored bytes, so for floating-point types, `NaN`s
+            /// with the same bits are equal while `0.0` and `-0.0` are not.
+            #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+            #[repr(transparent)]
+            pub struct $name([u8; $size]);
+
+            impl $name {
+                #[doc = concat!("Creates a new `", stringify!($name), "` storing `value`.")]
+                #[inline]
+                pub const fn new(value: $type) -> Self {
+                    Self(value.$to())
+                }
+
+                #[doc = concat!(
+                    "Creates a new `", stringify!($name), "` from its ", $order, " bytes."
+                )]
+                #[inline]
+                pub const fn from_bytes(bytes: [u8; $size]) -> Self {
+                    Self(bytes)
+                }
+
+                /// Returns the stored value in native byte order.
+                #[inline]
+                pub const fn get(self) -> $type {
+                    <$type>::$from(self.0)
+                }
+
+                /// Replaces the stored value with `value`.
+                #[inline]
+                pub fn set(&mut self, value: $type) {
+                    self.0 = value.$to();
+                }
+
+                #[doc = concat!("Returns the ", $order, " bytes of the stored value.")]
+                #[inline]
+                pub const fn to_bytes(self) -> [u8; $size] {
+                    self.0
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.debug_tuple(stringify!($name)).field(&self.get()).finish()
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.get(), f)
+                }
+            }
+
+            impl From<$type> for $name {
+                #[inline]
+                fn from(value: $type) -> Self {
+                    Self::new(value)
+                }
+            }
+
+            impl From<$name> for $type {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    value.get()
+                }
+            }
+
+            // SAFETY: the types only wrap a byte array, which has no padding and no invalid bit
+            // patterns. The bytes are stored in a fixed order, so every requested byte order
+            // copies them unchanged.
+            unsafe impl FromBytes for $name {
+                #[inline]
+                fn from_ne_slice(bytes: &[u8]) -> Self {
+                    Self(bytes.try_into().unwrap())
+                }
+
+                #[inline]
+                fn from_le_slice(bytes: &[u8]) -> Self {
+                    Self::from_ne_slice(bytes)
+                }
+
+                #[inline]
+                fn from_be_slice(bytes: &[u8]) -> Self {
+                    Self::from_ne_slice(bytes)
+                }
+            }
+        )*
+    };
+}
+
+ordered_types! {
+    U16Le(u16, 2, from_le_bytes, to_le_bytes, "little-endian");
+    U16Be(u16, 2, from_be_bytes, to_be_bytes, "big-endian");
+    U32Le(u32, 4, from_le_bytes, to_le_bytes, "little-endian");
+    U32Be(u32, 4, from_be_bytes, to_be_bytes, "big-endian");
+    U64Le(u64, 8, from_le_bytes, to_le_bytes, "little-endian");
+    U64Be(u64, 8, from_be_bytes, to_be_bytes, "big-endian");
+    U128Le(u128, 16, from_le_bytes, to_le_bytes, "little-endian");
+    U128Be(u128, 16, from_be_bytes, to_be_bytes, "big-endian");
+    I16Le(i16, 2, from_le_bytes, to_le_bytes, "little-endian");
+    I16Be(i16, 2, from_be_bytes, to_be_bytes, "big-endian");
+    I32Le(i32, 4, from_le_bytes, to_le_bytes, "little-endian");
+    I32Be(i32, 4, from_be_bytes, to_be_bytes, "big-endian");
+    I64Le(i64, 8, from_le_bytes, to_le_bytes, "little-endian");
+    I64Be(i64, 8, from_be_bytes, to_be_bytes, "big-endian");
+    I128Le(i128, 16, from_le_bytes, to_le_bytes, "little-endian");
+    I128Be(i128, 16, from_be_bytes, to_be_bytes, "big-endian");
+    F32Le(f32, 4, from_le_bytes, to_le_bytes, "little-endian");
+    F32Be(f32, 4, from_be_bytes, to_be_bytes, "big-endian");
+    F64Le(f64, 8, from_le_bytes, to_le_bytes, "little-endian");
+    F64Be(f64, 8, from_be_bytes, to_be_bytes, "big-endian");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bytes_to_slice, bytes_to_vec_le};
+
+    #[test]
+    fn it_stores_values_in_a_fixed_byte_order() {
+        let mut value = U32Be::new(0x0102_0304);
+        assert_eq!(value.to_bytes(), [1, 2, 3, 4]);
+
+        value.set(5);
+        assert_eq!(value.get(), 5);
+        assert_eq!(I16Le::from_bytes([0xFE, 0xFF]).get(), -2);
+        assert_eq!(F32Le::from(1.5).get(), 1.5);
+        assert_eq!(u64::from(U64Be::new(7)), 7);
+    }
+
+    #[test]
+    fn it_ignores_the_requested_byte_order() {
+        let bytes = [0, 1, 0, 2];
+
+        assert_eq!(
+            bytes_to_vec_le::<U16Be>(&bytes).unwrap(),
+            [U16Be::new(1), U16Be::new(2)]
+        );
+        assert_eq!(
+            bytes_to_slice::<U16Be>(&bytes[1..3]).unwrap()[0].get(),
+            0x0100
+        );
+    }
+}
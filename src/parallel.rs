@@ -0,0 +1,145 @@
+#[cfg(not(feature = "safe-only"))]
+use core::mem::MaybeUninit;
+
+use rayon::prelude::*;
+
+use crate::convert::check_length;
+use crate::{FromBytes, Result};
+
+/// The number of bytes decoded by a single parallel task, chosen to fit comfortably in the L2
+/// cache of common CPUs.
+const CHUNK_BYTES: usize = 64 * 1024;
+
+fn par_bytes_to_vec_with<T: FromBytes + Send>(
+    bytes: &[u8],
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<T>> {
+    check_length::<T>(bytes)?;
+
+    let size = core::mem::size_of::<T>();
+    let chunk_len = (CHUNK_BYTES / size).max(1);
+
+    #[cfg(feature = "safe-only")]
+    return Ok(bytes
+        .par_chunks_exact(size)
+        .with_min_len(chunk_len)
+        .map(decode)
+        .collect());
+
+    #[cfg(not(feature = "safe-only"))]
+    par_decode_uninit(bytes, chunk_len, decode)
+}
+
+/// Decodes the elements of `bytes`, whose length must be a multiple of the size of `T`, straight
+/// into the spare capacity of a new vector, in tasks of `chunk_len` elements.
+#[cfg(not(feature = "safe-only"))]
+fn par_decode_uninit<T: FromBytes + Send>(
+    bytes: &[u8],
+    chunk_len: usize,
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<T>> {
+    let size = core::mem::size_of::<T>();
+    let len = bytes.len() / size;
+    let mut values = Vec::with_capacity(len);
+
+    values.spare_capacity_mut()[..len]
+        .par_chunks_mut(chunk_len)
+        .zip(bytes.par_chunks(chunk_len * size))
+        .for_each(|(out, input)| {
+            for (slot, element) in out.iter_mut().zip(input.chunks_exact(size)) {
+                *slot = MaybeUninit::new(decode(element));
+            }
+        });
+
+    // SAFETY: every one of the first `len` elements was initialized above, since the output and
+    // input chunks cover the same number of elements.
+    unsafe { values.set_len(len) };
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a vector of `T` in native byte order, decoding chunks of the input
+/// in parallel on the rayon thread pool, available with the `rayon` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not
+/// a multiple of the size of `T`.
+pub fn par_bytes_to_vec<T: FromBytes + Send>(bytes: &[u8]) -> Result<Vec<T>> {
+    par_bytes_to_vec_with(bytes, T::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as little-endian, in
+/// parallel on the rayon thread pool, available with the `rayon` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not
+/// a multiple of the size of `T`.
+pub fn par_bytes_to_vec_le<T: FromBytes + Send>(bytes: &[u8]) -> Result<Vec<T>> {
+    par_bytes_to_vec_with(bytes, T::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of `T`, decoding each element as big-endian, in parallel
+/// on the rayon thread pool, available with the `rayon` feature.
+///
+/// This is mostly useful for huge big-endian buffers, where byte swapping every element is the
+/// bottleneck of a sequential decode.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::par_bytes_to_vec_be;
+///
+/// let bytes: Vec<u8> = (0..1_000_000u32).flat_map(|value| (value as f32).to_be_bytes()).collect();
+/// let values = par_bytes_to_vec_be::<f32>(&bytes).unwrap();
+///
+/// assert_eq!(values[999_999], 999_999.0);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not
+/// a multiple of the size of `T`.
+pub fn par_bytes_to_vec_be<T: FromBytes + Send>(bytes: &[u8]) -> Result<Vec<T>> {
+    par_bytes_to_vec_with(bytes, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_matches_sequential_conversion() {
+        let bytes: Vec<u8> = (0..CHUNK_BYTES * 3 + 6).map(|i| i as u8).collect();
+
+        assert_eq!(
+            par_bytes_to_vec_be::<u16>(&bytes).unwrap(),
+            crate::bytes_to_vec_be::<u16>(&bytes).unwrap()
+        );
+        assert_eq!(
+            par_bytes_to_vec_le::<u16>(&bytes).unwrap(),
+            crate::bytes_to_vec_le::<u16>(&bytes).unwrap()
+        );
+        assert_eq!(
+            par_bytes_to_vec::<u16>(&bytes).unwrap(),
+            crate::bytes_to_vec::<u16>(&bytes).unwrap()
+        );
+    }
+
+    #[test]
+    fn it_generates_parallel_functions_in_the_prelude() {
+        let bytes = [63, 128, 0, 0, 64, 0, 0, 0];
+
+        assert_eq!(
+            crate::prelude::par_bytes_to_f32_be(&bytes).unwrap(),
+            vec![1.0, 2.0]
+        );
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_type_size() {
+        assert!(par_bytes_to_vec::<u32>(&[0; 7]).is_err());
+        assert_eq!(par_bytes_to_vec::<u32>(&[]).unwrap(), Vec::<u32>::new());
+    }
+}
@@ -0,0 +1,133 @@
+use alloc::vec::Vec;
+
+use bytemuck::{AnyBitPattern, NoUninit, Pod, PodCastError};
+
+use crate::convert::{check_length, is_aligned};
+use crate::{Error, Result};
+
+fn cast_error<T>(bytes: &[u8], error: PodCastError) -> Error {
+    match error {
+        PodCastError::TargetAlignmentGreaterAndInputNotAligned
+        | PodCastError::AlignmentMismatch => Error::Misaligned {
+            align: core::mem::align_of::<T>(),
+        },
+        PodCastError::OutputSliceWouldHaveSlop | PodCastError::SizeMismatch => {
+            Error::length_mismatch(bytes.len(), core::mem::size_of::<T>())
+        }
+    }
+}
+
+/// Converts a byte slice into a vector of any [`bytemuck::Pod`] type in native byte order,
+/// available with the `bytemuck` feature.
+///
+/// Unlike [`bytes_to_vec`](crate::bytes_to_vec), this works for your own `Pod` types, with the
+/// soundness of the conversion guaranteed by the trait. Aligned input is cast with
+/// [`bytemuck::cast_slice`] and copied in one go.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::pod_to_vec;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// #[repr(C)]
+/// struct Sample {
+///     left: i16,
+///     right: i16,
+/// }
+///
+/// unsafe impl bytemuck::Zeroable for Sample {}
+/// unsafe impl bytemuck::Pod for Sample {}
+///
+/// let bytes = [1, 0, 2, 0];
+/// let samples = pod_to_vec::<Sample>(&bytes).unwrap();
+///
+/// assert_eq!(samples.len(), 1);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn pod_to_vec<T: Pod>(bytes: &[u8]) -> Result<Vec<T>> {
+    check_length::<T>(bytes)?;
+
+    if is_aligned::<T>(bytes) {
+        return pod_to_slice(bytes).map(<[T]>::to_vec);
+    }
+
+    Ok(bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        .map(bytemuck::pod_read_unaligned)
+        .collect())
+}
+
+/// Reinterprets a byte slice as a slice of any [`bytemuck::Pod`] type, without copying, available
+/// with the `bytemuck` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// or [`Error::Misaligned`] if `bytes` is not properly aligned for `T`.
+pub fn pod_to_slice<T: AnyBitPattern>(bytes: &[u8]) -> Result<&[T]> {
+    bytemuck::try_cast_slice(bytes).map_err(|error| cast_error::<T>(bytes, error))
+}
+
+/// Borrows a slice of any [`bytemuck::Pod`] type as bytes, available with the `bytemuck` feature.
+pub fn pod_as_bytes<T: NoUninit>(values: &[T]) -> &[u8] {
+    bytemuck::cast_slice(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    #[repr(C)]
+    pub struct Point {
+        x: u16,
+        y: u16,
+    }
+
+    unsafe impl bytemuck::Zeroable for Point {}
+    unsafe impl bytemuck::Pod for Point {}
+
+    crate::bytes_to_pod!(Point);
+
+    #[test]
+    fn it_converts_bytes_to_pod_types() {
+        let points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+        let bytes = pod_as_bytes(&points).to_vec();
+
+        assert_eq!(pod_to_vec::<Point>(&bytes).unwrap(), points);
+        assert_eq!(pod_to_slice::<Point>(&bytes).unwrap(), &points[..]);
+        assert_eq!(bytes_to_point(&bytes).unwrap(), points);
+    }
+
+    #[test]
+    fn it_converts_unaligned_bytes_to_pod_types() {
+        let bytes = [0, 1, 0, 2, 0];
+
+        for offset in 0..2 {
+            let window = &bytes[offset..offset + 4];
+            let expected = Point {
+                x: u16::from_ne_bytes([window[0], window[1]]),
+                y: u16::from_ne_bytes([window[2], window[3]]),
+            };
+
+            assert_eq!(pod_to_vec::<Point>(window).unwrap(), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn it_returns_error_if_bytes_length_is_not_a_multiple_of_type_size() {
+        let bytes = [0; 6];
+
+        assert_eq!(
+            pod_to_vec::<Point>(&bytes),
+            Err(Error::length_mismatch(6, 4))
+        );
+        assert!(bytes_to_point_slice(&bytes).is_err());
+    }
+}
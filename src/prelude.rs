@@ -0,0 +1,52 @@
+//! Ready-made conversion functions for all primitive numeric types.
+//!
+//! This module contains the functions generated by [`bytes_to_type!`](crate::bytes_to_type) and
+//! [`type_to_bytes!`](crate::type_to_bytes) for `u8`, `u16`, `u32`, `u64`, `u128`, `usize`, `i8`,
+//! `i16`, `i32`, `i64`, `i128`, `isize`, `f32` and `f64`, so application code can use them without
+//! invoking the macros. With the `half` feature, it also contains `bytes_to_f16` and
+//! `bytes_to_bf16` and their variants, decoding [`half::f16`] and [`half::bf16`] values.
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::prelude::*;
+//!
+//! let bytes = f32_to_bytes_be(&[1.0, 2.0]);
+//!
+//! assert_eq!(bytes_to_f32_be(&bytes).unwrap(), vec![1.0, 2.0]);
+//! assert_eq!(bytes_to_i128_le(&[255; 16]).unwrap(), vec![-1]);
+//! ```
+
+crate::bytes_to_type!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+crate::type_to_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(feature = "half")]
+crate::bytes_to_type!(half::f16 as bytes_to_f16, half::bf16 as bytes_to_bf16);
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_provides_conversions_for_primitives() {
+        assert_eq!(bytes_to_u8(&[1, 2]).unwrap(), vec![1, 2]);
+        assert_eq!(bytes_to_u64_be(&[0, 0, 0, 0, 0, 0, 0, 1]).unwrap(), vec![1]);
+        assert_eq!(
+            bytes_to_isize_le(&isize_to_bytes_le(&[-7])).unwrap(),
+            vec![-7]
+        );
+        assert_eq!(bytes_to_f64(f64_as_bytes(&[0.5])).unwrap(), vec![0.5]);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn it_provides_conversions_for_half_precision_floats() {
+        assert_eq!(bytes_to_f16_le(&[0, 0x3C]).unwrap(), vec![half::f16::ONE]);
+        assert_eq!(
+            bytes_to_bf16_be(&[0x3F, 0x80]).unwrap(),
+            vec![half::bf16::ONE]
+        );
+    }
+}
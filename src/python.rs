@@ -0,0 +1,88 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use numpy::{Element, PyArray1};
+use pyo3::exceptions::PyValueError;
+use pyo3::types::{PyBytes, PyBytesMethods};
+use pyo3::{Bound, PyResult};
+
+use crate::{bytes_to_vec_endian, Endianness, FromBytes};
+
+fn bytes_to_numpy_with<'py, T: FromBytes + Element>(
+    bytes: &Bound<'py, PyBytes>,
+    endianness: Endianness,
+) -> PyResult<Bound<'py, PyArray1<T>>> {
+    let py = bytes.py();
+    let input = bytes.as_bytes();
+
+    // Python bytes are immutable, so they can be decoded while other threads run Python code.
+    let values: Vec<T> = py
+        .detach(|| bytes_to_vec_endian::<T>(input, endianness))
+        .map_err(|error| PyValueError::new_err(error.to_string()))?;
+
+    Ok(PyArray1::from_vec(py, values))
+}
+
+/// Converts a Python `bytes` object into a one-dimensional NumPy array of `T` in native byte
+/// order, available with the `numpy` feature.
+///
+/// The elements are decoded into a vector without holding the global interpreter lock, and the
+/// array takes ownership of that vector, so the decoded buffer is not copied again. Wrap these
+/// functions in `#[pyfunction]`s to expose them from an extension module.
+///
+/// # Example
+///
+/// ```no_run
+/// use bytes_to_type::bytes_to_numpy_be;
+/// use numpy::PyArray1;
+/// use pyo3::prelude::*;
+/// use pyo3::types::PyBytes;
+///
+/// #[pyfunction]
+/// fn decode_samples<'py>(bytes: &Bound<'py, PyBytes>) -> PyResult<Bound<'py, PyArray1<f32>>> {
+///     bytes_to_numpy_be(bytes)
+/// }
+///
+/// #[pymodule]
+/// fn samples(module: &Bound<'_, PyModule>) -> PyResult<()> {
+///     module.add_function(wrap_pyfunction!(decode_samples, module)?)
+/// }
+/// ```
+///
+/// # Errors
+///
+/// Returns a Python `ValueError` with the message of
+/// [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not a
+/// multiple of the size of `T`.
+pub fn bytes_to_numpy<'py, T: FromBytes + Element>(
+    bytes: &Bound<'py, PyBytes>,
+) -> PyResult<Bound<'py, PyArray1<T>>> {
+    bytes_to_numpy_with(bytes, Endianness::NATIVE)
+}
+
+/// Converts a Python `bytes` object of little-endian elements into a one-dimensional NumPy array
+/// of `T`, available with the `numpy` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_numpy`].
+pub fn bytes_to_numpy_le<'py, T: FromBytes + Element>(
+    bytes: &Bound<'py, PyBytes>,
+) -> PyResult<Bound<'py, PyArray1<T>>> {
+    bytes_to_numpy_with(bytes, Endianness::Little)
+}
+
+/// Converts a Python `bytes` object of big-endian elements into a one-dimensional NumPy array of
+/// `T`, available with the `numpy` feature.
+///
+/// The bytes are swapped into native order with the same code paths as
+/// [`bytes_to_vec_be`](crate::bytes_to_vec_be), vectorized with the `simd` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_numpy`].
+pub fn bytes_to_numpy_be<'py, T: FromBytes + Element>(
+    bytes: &Bound<'py, PyBytes>,
+) -> PyResult<Bound<'py, PyArray1<T>>> {
+    bytes_to_numpy_with(bytes, Endianness::Big)
+}
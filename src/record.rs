@@ -0,0 +1,51 @@
+//! Field kinds accepted by the [`byte_record!`](crate::byte_record) macro.
+//!
+//! Every kind is both a type alias naming the Rust type of the field and a function decoding that
+//! type from exactly `size_of` bytes, which lets the macro map a kind such as `u32_be` to its type
+//! and decoder with a single path.
+
+#![allow(non_camel_case_types)]
+
+use crate::FromBytes;
+
+macro_rules! record_kinds {
+    ($($type:ident),*) => {
+        $crate::paste! {
+            $(
+                pub type [<$type _ne>] = $type;
+                pub type [<$type _le>] = $type;
+                pub type [<$type _be>] = $type;
+
+                #[inline]
+                pub fn [<$type _ne>](bytes: &[u8]) -> $type {
+                    $type::from_ne_slice(bytes)
+                }
+
+                #[inline]
+                pub fn [<$type _le>](bytes: &[u8]) -> $type {
+                    $type::from_le_slice(bytes)
+                }
+
+                #[inline]
+                pub fn [<$type _be>](bytes: &[u8]) -> $type {
+                    $type::from_be_slice(bytes)
+                }
+            )*
+        }
+    };
+}
+
+record_kinds!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+
+pub type u8 = core::primitive::u8;
+pub type i8 = core::primitive::i8;
+
+#[inline]
+pub fn u8(bytes: &[u8]) -> u8 {
+    bytes[0]
+}
+
+#[inline]
+pub fn i8(bytes: &[u8]) -> i8 {
+    bytes[0] as i8
+}
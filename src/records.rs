@@ -0,0 +1,208 @@
+use core::iter::FusedIterator;
+
+use crate::{ByteCursor, Error, Result};
+
+/// An iterator over the fixed-size records of a byte slice.
+///
+/// This struct is created by [`bytes_to_records`].
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    record_size: usize,
+}
+
+impl<'a> Records<'a> {
+    /// Parses each record with `parse`, yielding the value it returns or the error it fails with.
+    ///
+    /// `parse` is handed a [`ByteCursor`] limited to the record, so a read past its end fails with
+    /// [`Error::UnexpectedEnd`] instead of running into the next record. The cursor is positioned
+    /// at the offset of the record in the input, which makes [`ByteCursor::position`] and the
+    /// offsets of errors count from the start of the input. Any bytes `parse` leaves unread, such
+    /// as trailing padding, are skipped.
+    pub fn project<T, F>(self, parse: F) -> Projected<'a, F>
+    where
+        F: FnMut(&mut ByteCursor<'a>) -> Result<T>,
+    {
+        Projected {
+            records: self,
+            parse,
+        }
+    }
+
+    /// Returns the offset of the next record in the input, and the cursor bounded by its end.
+    fn next_cursor(&mut self) -> Option<ByteCursor<'a>> {
+        let end = self.offset + self.record_size;
+
+        if end > self.bytes.len() {
+            return None;
+        }
+
+        let cursor = ByteCursor::at(&self.bytes[..end], self.offset);
+        self.offset = end;
+
+        Some(cursor)
+    }
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = &'a [u8];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [u8]> {
+        self.next_cursor().map(|cursor| cursor.remaining())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.bytes.len() - self.offset) / self.record_size;
+
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Records<'_> {}
+
+impl FusedIterator for Records<'_> {}
+
+/// An iterator parsing the fixed-size records of a byte slice into values.
+///
+/// This struct is created by [`Records::project`].
+#[derive(Debug, Clone)]
+pub struct Projected<'a, F> {
+    records: Records<'a>,
+    parse: F,
+}
+
+impl<'a, T, F> Iterator for Projected<'a, F>
+where
+    F: FnMut(&mut ByteCursor<'a>) -> Result<T>,
+{
+    type Item = Result<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<T>> {
+        let mut cursor = self.records.next_cursor()?;
+
+        Some((self.parse)(&mut cursor))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.records.size_hint()
+    }
+}
+
+impl<'a, T, F> ExactSizeIterator for Projected<'a, F> where
+    F: FnMut(&mut ByteCursor<'a>) -> Result<T>
+{
+}
+
+impl<'a, T, F> FusedIterator for Projected<'a, F> where F: FnMut(&mut ByteCursor<'a>) -> Result<T> {}
+
+/// Splits a byte slice into records of `record_size` bytes, such as the entries of a log file.
+///
+/// Unlike the conversion functions, the records don't have to be a single [`FromBytes`] type:
+/// each one is yielded as a byte slice, and [`Records::project`] parses them with a
+/// [`ByteCursor`] to read fields of mixed types.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_records;
+///
+/// // Each record holds a big-endian `u32` timestamp, a level byte and a little-endian `f32`.
+/// let log = [
+///     0, 0, 0, 10, 2, 0, 0, 128, 63,
+///     0, 0, 0, 11, 4, 0, 0, 32, 64,
+/// ];
+///
+/// assert_eq!(bytes_to_records(&log, 9).unwrap().count(), 2);
+///
+/// let entries: Vec<(u32, u8, f32)> = bytes_to_records(&log, 9)
+///     .unwrap()
+///     .project(|cursor| Ok((cursor.read_be()?, cursor.read()?, cursor.read_le()?)))
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+///
+/// assert_eq!(entries, vec![(10, 2, 1.0), (11, 4, 2.5)]);
+/// ```
+///
+/// [`FromBytes`]: crate::FromBytes
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of `record_size`.
+///
+/// # Panics
+///
+/// Panics if `record_size` is zero.
+pub fn bytes_to_records(bytes: &[u8], record_size: usize) -> Result<Records<'_>> {
+    assert!(record_size != 0, "Record size must be nonzero");
+
+    if !bytes.len().is_multiple_of(record_size) {
+        return Err(Error::length_mismatch(bytes.len(), record_size));
+    }
+
+    Ok(Records {
+        bytes,
+        offset: 0,
+        record_size,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn it_splits_bytes_into_records() {
+        let bytes = [1, 2, 3, 4, 5, 6];
+        let mut records = bytes_to_records(&bytes, 3).unwrap();
+
+        assert_eq!(records.len(), 2);
+        assert_eq!(records.next(), Some(&bytes[..3]));
+        assert_eq!(records.collect::<Vec<_>>(), vec![&bytes[3..]]);
+        assert_eq!(bytes_to_records(&[], 4).unwrap().next(), None);
+        assert_eq!(
+            bytes_to_records(&bytes, 4).unwrap_err(),
+            Error::length_mismatch(6, 4)
+        );
+    }
+
+    #[test]
+    fn it_reports_offsets_from_the_start_of_the_input() {
+        let bytes = [0, 1, 9, 0, 2, 9];
+        let mut parsed = bytes_to_records(&bytes, 3).unwrap().project(|cursor| {
+            cursor
+                .read_be::<u16>()
+                .map(|value| (cursor.position(), value))
+        });
+
+        assert_eq!(parsed.next(), Some(Ok((2, 1))));
+        assert_eq!(parsed.next(), Some(Ok((5, 2))));
+        assert_eq!(parsed.next(), None);
+
+        let error = bytes_to_records(&bytes, 3)
+            .unwrap()
+            .project(|cursor| cursor.read_le::<u32>())
+            .nth(1);
+        assert_eq!(
+            error,
+            Some(Err(Error::UnexpectedEnd {
+                offset: 3,
+                needed: 4,
+                remaining: 3
+            }))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Record size must be nonzero")]
+    fn it_panics_with_zero_record_size() {
+        let _ = bytes_to_records(&[], 0);
+    }
+}
@@ -0,0 +1,179 @@
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::slice::ChunksExact;
+
+use crate::{Error, FromBytes, Result};
+
+/// A lazy iterator decoding elements of `T` from the two halves of a ring buffer.
+///
+/// This struct is created by [`iter_ring`], [`iter_ring_le`] and [`iter_ring_be`].
+#[derive(Debug, Clone)]
+pub struct RingElements<'a, T> {
+    head: ChunksExact<'a, u8>,
+    wrapped: Option<T>,
+    tail: ChunksExact<'a, u8>,
+    decode: fn(&[u8]) -> T,
+}
+
+impl<'a, T: FromBytes> RingElements<'a, T> {
+    fn new(head: &'a [u8], tail: &'a [u8], decode: fn(&[u8]) -> T) -> Result<Self> {
+        let size = core::mem::size_of::<T>();
+        let len = head.len().saturating_add(tail.len());
+
+        if !len.is_multiple_of(size) {
+            return Err(Error::length_mismatch(len, size));
+        }
+
+        let head = head.chunks_exact(size);
+        let split = head.remainder();
+
+        // The element straddling the wrap point is stitched together and decoded right away, so
+        // only its bytes are copied.
+        let (wrapped, tail) = if split.is_empty() {
+            (None, tail)
+        } else {
+            let (rest, tail) = tail.split_at(size - split.len());
+            let mut scratch = Vec::with_capacity(size);
+            scratch.extend_from_slice(split);
+            scratch.extend_from_slice(rest);
+
+            (Some(decode(&scratch)), tail)
+        };
+
+        Ok(Self {
+            head,
+            wrapped,
+            tail: tail.chunks_exact(size),
+            decode,
+        })
+    }
+}
+
+impl<T> Iterator for RingElements<'_, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        self.head
+            .next()
+            .map(self.decode)
+            .or_else(|| self.wrapped.take())
+            .or_else(|| self.tail.next().map(self.decode))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.head.len() + usize::from(self.wrapped.is_some()) + self.tail.len();
+
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for RingElements<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        self.tail
+            .next_back()
+            .map(self.decode)
+            .or_else(|| self.wrapped.take())
+            .or_else(|| self.head.next_back().map(self.decode))
+    }
+}
+
+impl<T> ExactSizeIterator for RingElements<'_, T> {}
+
+impl<T> FusedIterator for RingElements<'_, T> {}
+
+/// Returns an iterator decoding elements of `T` in native byte order from a ring buffer given as
+/// its two halves, `head` followed by `tail`.
+///
+/// The halves are those returned by [`VecDeque::as_slices`](alloc::collections::VecDeque) or
+/// read from a circular DMA buffer, with `head` running up to the end of the buffer and `tail`
+/// starting over at its beginning. The element straddling the wrap point, if any, is decoded
+/// like the others, and no other byte is copied. To collect the elements instead, see
+/// [`bytes_to_vec_gather`](crate::bytes_to_vec_gather).
+///
+/// # Example
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use bytes_to_type::iter_ring_le;
+///
+/// let mut buffer = VecDeque::with_capacity(6);
+/// buffer.extend([9, 9, 9, 1, 0, 2, 0]);
+/// buffer.drain(..3);
+/// buffer.extend([3, 0]);
+///
+/// let (head, tail) = buffer.as_slices();
+/// let values: Vec<u16> = iter_ring_le(head, tail).unwrap().collect();
+///
+/// assert_eq!(values, [1, 2, 3]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the total length of `head` and `tail` is not a multiple
+/// of the size of `T`.
+pub fn iter_ring<'a, T: FromBytes>(head: &'a [u8], tail: &'a [u8]) -> Result<RingElements<'a, T>> {
+    RingElements::new(head, tail, T::from_ne_slice)
+}
+
+/// Returns an iterator decoding elements of `T` as little-endian from a ring buffer given as its
+/// two halves.
+///
+/// # Errors
+///
+/// See [`iter_ring`].
+pub fn iter_ring_le<'a, T: FromBytes>(
+    head: &'a [u8],
+    tail: &'a [u8],
+) -> Result<RingElements<'a, T>> {
+    RingElements::new(head, tail, T::from_le_slice)
+}
+
+/// Returns an iterator decoding elements of `T` as big-endian from a ring buffer given as its
+/// two halves.
+///
+/// # Errors
+///
+/// See [`iter_ring`].
+pub fn iter_ring_be<'a, T: FromBytes>(
+    head: &'a [u8],
+    tail: &'a [u8],
+) -> Result<RingElements<'a, T>> {
+    RingElements::new(head, tail, T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decodes_elements_across_the_wrap_point() {
+        let mut elements = iter_ring_be::<u32>(&[0, 0, 0, 1, 0], &[0, 0, 2, 0, 0, 0, 3]).unwrap();
+
+        assert_eq!(elements.len(), 3);
+        assert_eq!(elements.next_back(), Some(3));
+        assert_eq!(elements.next_back(), Some(2));
+        assert_eq!(elements.next(), Some(1));
+        assert_eq!(elements.next(), None);
+    }
+
+    #[test]
+    fn it_decodes_halves_split_at_element_boundaries() {
+        let elements = iter_ring::<u16>(&[1, 2], &[]).unwrap();
+
+        assert_eq!(elements.collect::<Vec<_>>(), [u16::from_ne_bytes([1, 2])]);
+        assert_eq!(
+            iter_ring_le::<u16>(&[], &[1, 0, 2, 0])
+                .unwrap()
+                .sum::<u16>(),
+            3
+        );
+        assert_eq!(
+            iter_ring_le::<u16>(&[1, 0], &[2]).unwrap_err(),
+            Error::length_mismatch(3, 2)
+        );
+    }
+}
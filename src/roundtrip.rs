@@ -0,0 +1,71 @@
+use alloc::vec::Vec;
+
+use crate::to_bytes::encode;
+use crate::{bytes_to_vec, bytes_to_vec_endian, Endianness, FromBytes};
+
+/// Encodes `values` and decodes them back in native, little-endian and big-endian byte order in
+/// turn, returning the values produced by the last decoding.
+///
+/// This is meant for property tests checking that encoding and decoding is the identity for a
+/// type, including derived structs, whatever the byte order: the result is expected to equal
+/// `values`. Floats are carried bit for bit, so NaNs come back with their payload but don't
+/// compare equal to themselves. With the `arbitrary` feature, the configuration types of this
+/// crate, such as [`Endianness`], [`Converter`](crate::Converter) and
+/// [`ScalarType`](crate::ScalarType), implement `arbitrary::Arbitrary` so they can be generated
+/// by fuzzers and property tests too.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::roundtrip;
+///
+/// let values = [1u32, 0xDEADBEEF, u32::MAX];
+///
+/// assert_eq!(roundtrip(&values), values);
+/// ```
+///
+/// # Panics
+///
+/// Panics if decoding the encoded bytes fails, which would be a bug in this crate.
+pub fn roundtrip<T: FromBytes>(values: &[T]) -> Vec<T> {
+    let mut values = bytes_to_vec(&encode(values, Endianness::NATIVE)).unwrap();
+
+    for endianness in [Endianness::Little, Endianness::Big] {
+        values = bytes_to_vec_endian(&encode(&values, endianness), endianness).unwrap();
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decodes_encoded_values_in_every_byte_order() {
+        assert_eq!(
+            roundtrip(&[-1.5f64, f64::MIN_POSITIVE]),
+            [-1.5, f64::MIN_POSITIVE]
+        );
+        assert!(roundtrip::<u64>(&[]).is_empty());
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn it_generates_arbitrary_configurations() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        use crate::{Converter, ScalarType};
+
+        let mut input = Unstructured::new(&[0x80, 0, 0, 0, 7, 3, 9, 9, 9, 9, 9, 9, 9, 9, 9]);
+        let endianness = Endianness::arbitrary(&mut input).unwrap();
+        let values = [0x0102_0304u32, 5];
+
+        assert_eq!(
+            bytes_to_vec_endian::<u32>(&encode(&values, endianness), endianness).unwrap(),
+            values
+        );
+        Converter::arbitrary(&mut input).unwrap();
+        ScalarType::arbitrary(&mut input).unwrap();
+    }
+}
@@ -0,0 +1,172 @@
+use ndarray::{Array, Array2, Dimension, Ix2, ShapeBuilder};
+
+use crate::{bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le, Error, FromBytes, Result};
+
+fn bytes_to_ndarray_with<T: FromBytes, Sh: ShapeBuilder>(
+    bytes: &[u8],
+    shape: Sh,
+    convert: fn(&[u8]) -> Result<alloc::vec::Vec<T>>,
+) -> Result<Array<T, Sh::Dim>> {
+    let shape = shape.into_shape_with_order();
+    let expected = shape
+        .raw_dim()
+        .size_checked()
+        .and_then(|count| count.checked_mul(core::mem::size_of::<T>()));
+
+    if expected != Some(bytes.len()) {
+        return Err(Error::UnexpectedLength {
+            len: bytes.len(),
+            expected: expected.unwrap_or(usize::MAX),
+        });
+    }
+
+    Ok(Array::from_shape_vec(shape, convert(bytes)?).expect("Shape matches the element count"))
+}
+
+/// Converts a byte slice into an [`ndarray::Array`] of the given shape, decoding elements of `T`
+/// in native byte order, available with the `ndarray` feature.
+///
+/// The shape can be anything `ndarray` accepts as a dimension, such as a tuple or an array of
+/// lengths. The elements are laid out in row-major (C) order, unless the shape is marked as
+/// column-major (Fortran) order with [`ShapeBuilder::f`].
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_ndarray_le;
+/// use ndarray::{array, ShapeBuilder};
+///
+/// let bytes = [1, 0, 2, 0, 3, 0, 4, 0, 5, 0, 6, 0];
+///
+/// assert_eq!(
+///     bytes_to_ndarray_le::<u16, _>(&bytes, (2, 3)).unwrap(),
+///     array![[1, 2, 3], [4, 5, 6]]
+/// );
+/// assert_eq!(
+///     bytes_to_ndarray_le::<u16, _>(&bytes, (2, 3).f()).unwrap(),
+///     array![[1, 3, 5], [2, 4, 6]]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` does not match the number of
+/// elements in the shape.
+pub fn bytes_to_ndarray<T: FromBytes, Sh: ShapeBuilder>(
+    bytes: &[u8],
+    shape: Sh,
+) -> Result<Array<T, Sh::Dim>> {
+    bytes_to_ndarray_with(bytes, shape, bytes_to_vec::<T>)
+}
+
+/// Converts a byte slice into an [`ndarray::Array`] of the given shape, decoding elements of `T`
+/// as little-endian, available with the `ndarray` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` does not match the number of
+/// elements in the shape.
+pub fn bytes_to_ndarray_le<T: FromBytes, Sh: ShapeBuilder>(
+    bytes: &[u8],
+    shape: Sh,
+) -> Result<Array<T, Sh::Dim>> {
+    bytes_to_ndarray_with(bytes, shape, bytes_to_vec_le::<T>)
+}
+
+/// Converts a byte slice into an [`ndarray::Array`] of the given shape, decoding elements of `T`
+/// as big-endian, available with the `ndarray` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` does not match the number of
+/// elements in the shape.
+pub fn bytes_to_ndarray_be<T: FromBytes, Sh: ShapeBuilder>(
+    bytes: &[u8],
+    shape: Sh,
+) -> Result<Array<T, Sh::Dim>> {
+    bytes_to_ndarray_with(bytes, shape, bytes_to_vec_be::<T>)
+}
+
+/// Converts a byte slice into a two-dimensional [`ndarray::Array2`], decoding elements of `T` in
+/// native byte order, available with the `ndarray` feature.
+///
+/// The shape is given as `(rows, cols)`, optionally marked as column-major with
+/// [`ShapeBuilder::f`]. See [`bytes_to_ndarray`] for details.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` is not `rows * cols` elements.
+pub fn bytes_to_array2<T: FromBytes>(
+    bytes: &[u8],
+    shape: impl ShapeBuilder<Dim = Ix2>,
+) -> Result<Array2<T>> {
+    bytes_to_ndarray(bytes, shape)
+}
+
+/// Converts a byte slice into a two-dimensional [`ndarray::Array2`], decoding elements of `T` as
+/// little-endian, available with the `ndarray` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` is not `rows * cols` elements.
+pub fn bytes_to_array2_le<T: FromBytes>(
+    bytes: &[u8],
+    shape: impl ShapeBuilder<Dim = Ix2>,
+) -> Result<Array2<T>> {
+    bytes_to_ndarray_le(bytes, shape)
+}
+
+/// Converts a byte slice into a two-dimensional [`ndarray::Array2`], decoding elements of `T` as
+/// big-endian, available with the `ndarray` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedLength`] if the length of `bytes` is not `rows * cols` elements.
+pub fn bytes_to_array2_be<T: FromBytes>(
+    bytes: &[u8],
+    shape: impl ShapeBuilder<Dim = Ix2>,
+) -> Result<Array2<T>> {
+    bytes_to_ndarray_be(bytes, shape)
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{array, Array3};
+
+    use super::*;
+
+    #[test]
+    fn it_converts_bytes_to_arrays_in_both_orders() {
+        let bytes = [0, 1, 0, 2, 0, 3, 0, 4];
+
+        assert_eq!(
+            bytes_to_array2_be::<u16>(&bytes, (2, 2)).unwrap(),
+            array![[1, 2], [3, 4]]
+        );
+        assert_eq!(
+            bytes_to_array2_be::<u16>(&bytes, (2, 2).f()).unwrap(),
+            array![[1, 3], [2, 4]]
+        );
+
+        let cube: Array3<u8> = bytes_to_ndarray(&bytes, (2, 2, 2)).unwrap();
+        assert_eq!(cube[[1, 0, 1]], 3);
+    }
+
+    #[test]
+    fn it_returns_error_if_shape_does_not_match_length() {
+        assert_eq!(
+            bytes_to_array2::<u16>(&[0; 6], (2, 2)),
+            Err(Error::UnexpectedLength {
+                len: 6,
+                expected: 8
+            })
+        );
+        assert_eq!(
+            bytes_to_ndarray::<u64, _>(&[], [usize::MAX, 2]),
+            Err(Error::UnexpectedLength {
+                len: 0,
+                expected: usize::MAX
+            })
+        );
+    }
+}
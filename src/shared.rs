@@ -0,0 +1,264 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+#[cfg(not(feature = "safe-only"))]
+use core::mem::MaybeUninit;
+
+use crate::convert::check_length;
+#[cfg(feature = "safe-only")]
+use crate::into::decode_elements;
+#[cfg(not(feature = "safe-only"))]
+use crate::into::write_elements;
+use crate::{FromBytes, Result};
+
+/// Checks the length of `bytes` and returns the number of elements of `T` it holds.
+fn element_count<T>(bytes: &[u8]) -> Result<usize> {
+    check_length::<T>(bytes)?;
+
+    Ok(bytes.len() / core::mem::size_of::<T>())
+}
+
+/// Initializes `out`, which must hold exactly the elements of `bytes`, as in [`write_elements`].
+#[cfg(not(feature = "safe-only"))]
+fn fill<T: FromBytes>(
+    bytes: &[u8],
+    out: &mut [MaybeUninit<T>],
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) {
+    assert_eq!(out.len() * core::mem::size_of::<T>(), bytes.len());
+
+    // SAFETY: the length of `bytes` matches `out`, which is valid for writes of all of its
+    // elements.
+    unsafe { write_elements(bytes, out.as_mut_ptr() as *mut T, native, decode) };
+}
+
+fn bytes_to_box_with<T: FromBytes>(
+    bytes: &[u8],
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<Box<[T]>> {
+    let count = element_count::<T>(bytes)?;
+
+    #[cfg(not(feature = "safe-only"))]
+    let values = {
+        let mut values = Box::new_uninit_slice(count);
+        fill(bytes, &mut values, native, decode);
+
+        // SAFETY: all elements were initialized by `fill`.
+        unsafe { values.assume_init() }
+    };
+
+    #[cfg(feature = "safe-only")]
+    let values = {
+        let _ = (count, native);
+        decode_elements(bytes, decode).collect()
+    };
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a `Box<[T]>` in native byte order.
+///
+/// The elements are decoded straight into the final allocation, without building an intermediate
+/// `Vec`.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_box<T: FromBytes>(bytes: &[u8]) -> Result<Box<[T]>> {
+    bytes_to_box_with(bytes, true, T::from_ne_slice)
+}
+
+/// Converts a byte slice into a `Box<[T]>`, decoding each element as little-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_box_le<T: FromBytes>(bytes: &[u8]) -> Result<Box<[T]>> {
+    bytes_to_box_with(bytes, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Converts a byte slice into a `Box<[T]>`, decoding each element as big-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_box_be<T: FromBytes>(bytes: &[u8]) -> Result<Box<[T]>> {
+    bytes_to_box_with(bytes, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+fn bytes_to_rc_with<T: FromBytes>(
+    bytes: &[u8],
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<Rc<[T]>> {
+    let count = element_count::<T>(bytes)?;
+
+    #[cfg(not(feature = "safe-only"))]
+    let values = {
+        let mut values = Rc::new_uninit_slice(count);
+        fill(
+            bytes,
+            Rc::get_mut(&mut values).expect("New Rc is unique"),
+            native,
+            decode,
+        );
+
+        // SAFETY: all elements were initialized by `fill`.
+        unsafe { values.assume_init() }
+    };
+
+    #[cfg(feature = "safe-only")]
+    let values = {
+        let _ = (count, native);
+        decode_elements(bytes, decode).collect()
+    };
+
+    Ok(values)
+}
+
+/// Converts a byte slice into an `Rc<[T]>` in native byte order.
+///
+/// The elements are decoded straight into the reference-counted allocation, saving the copy made
+/// when converting a `Vec` into an `Rc`.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_rc<T: FromBytes>(bytes: &[u8]) -> Result<Rc<[T]>> {
+    bytes_to_rc_with(bytes, true, T::from_ne_slice)
+}
+
+/// Converts a byte slice into an `Rc<[T]>`, decoding each element as little-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_rc_le<T: FromBytes>(bytes: &[u8]) -> Result<Rc<[T]>> {
+    bytes_to_rc_with(bytes, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Converts a byte slice into an `Rc<[T]>`, decoding each element as big-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_rc_be<T: FromBytes>(bytes: &[u8]) -> Result<Rc<[T]>> {
+    bytes_to_rc_with(bytes, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+#[cfg(target_has_atomic = "ptr")]
+fn bytes_to_arc_with<T: FromBytes>(
+    bytes: &[u8],
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<Arc<[T]>> {
+    let count = element_count::<T>(bytes)?;
+
+    #[cfg(not(feature = "safe-only"))]
+    let values = {
+        let mut values = Arc::new_uninit_slice(count);
+        fill(
+            bytes,
+            Arc::get_mut(&mut values).expect("New Arc is unique"),
+            native,
+            decode,
+        );
+
+        // SAFETY: all elements were initialized by `fill`.
+        unsafe { values.assume_init() }
+    };
+
+    #[cfg(feature = "safe-only")]
+    let values = {
+        let _ = (count, native);
+        decode_elements(bytes, decode).collect()
+    };
+
+    Ok(values)
+}
+
+/// Converts a byte slice into an `Arc<[T]>` in native byte order.
+///
+/// The elements are decoded straight into the reference-counted allocation, saving the copy made
+/// when converting a `Vec` into an `Arc`, so the decoded buffer can be shared across threads
+/// right away.
+///
+/// # Example
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use bytes_to_type::bytes_to_arc_le;
+///
+/// let samples: Arc<[i16]> = bytes_to_arc_le(&[1, 0, 255, 255]).unwrap();
+///
+/// assert_eq!(*samples, [1, -1]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+#[cfg(target_has_atomic = "ptr")]
+pub fn bytes_to_arc<T: FromBytes>(bytes: &[u8]) -> Result<Arc<[T]>> {
+    bytes_to_arc_with(bytes, true, T::from_ne_slice)
+}
+
+/// Converts a byte slice into an `Arc<[T]>`, decoding each element as little-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+#[cfg(target_has_atomic = "ptr")]
+pub fn bytes_to_arc_le<T: FromBytes>(bytes: &[u8]) -> Result<Arc<[T]>> {
+    bytes_to_arc_with(bytes, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Converts a byte slice into an `Arc<[T]>`, decoding each element as big-endian.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+#[cfg(target_has_atomic = "ptr")]
+pub fn bytes_to_arc_be<T: FromBytes>(bytes: &[u8]) -> Result<Arc<[T]>> {
+    bytes_to_arc_with(bytes, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_converts_bytes_to_shared_slices() {
+        let bytes = [0, 1, 0, 2];
+
+        assert_eq!(*bytes_to_box_be::<u16>(&bytes).unwrap(), [1, 2]);
+        assert_eq!(*bytes_to_rc_le::<u16>(&bytes).unwrap(), [256, 512]);
+        assert_eq!(*bytes_to_arc::<u8>(&bytes).unwrap(), bytes);
+        assert!(bytes_to_arc_be::<u32>(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn it_returns_error_on_length_mismatch() {
+        assert_eq!(
+            bytes_to_rc::<u16>(&[0; 3]),
+            Err(Error::LengthMismatch {
+                len: 3,
+                size: 2,
+                remainder: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Vectorized byte swapping for the endian-converting conversions, enabled by the `simd` feature.
+//!
+//! On x86 and x86-64, AVX2 swaps 32 bytes at a time and SSSE3 swaps 16 bytes at a time with a
+//! single `pshufb`. With the `std` feature, the best instruction set is detected at runtime;
+//! otherwise, it has to be enabled at compile time with `-C target-feature`. On AArch64, NEON is
+//! always available. Any remaining bytes, and other architectures, fall back to scalar code.
+
+/// Reverses the bytes of every `size`-byte element of `bytes` in place.
+///
+/// `size` must be 2, 4, 8 or 16, and the length of `bytes` must be a multiple of `size`.
+pub(crate) fn swap_bytes(bytes: &mut [u8], size: usize) {
+    debug_assert!(matches!(size, 2 | 4 | 8 | 16));
+    debug_assert!(bytes.len().is_multiple_of(size));
+
+    let done = swap_bytes_vectorized(bytes, size);
+
+    swap_bytes_scalar(&mut bytes[done..], size);
+}
+
+fn swap_bytes_scalar(bytes: &mut [u8], size: usize) {
+    for element in bytes.chunks_exact_mut(size) {
+        element.reverse();
+    }
+}
+
+#[cfg(any(
+    target_arch = "aarch64",
+    all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        any(feature = "std", target_feature = "ssse3")
+    )
+))]
+/// Returns the `pshufb`/`tbl` mask reversing every `size`-byte element of a 16-byte vector.
+fn shuffle_mask(size: usize) -> [u8; 16] {
+    core::array::from_fn(|index| ((index / size) * size + (size - 1 - index % size)) as u8)
+}
+
+/// Swaps as many leading bytes as possible with vector instructions, returning how many bytes
+/// were processed.
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+fn swap_bytes_vectorized(bytes: &mut [u8], size: usize) -> usize {
+    #[cfg(feature = "std")]
+    {
+        if std::is_x86_feature_detected!("avx2") {
+            // SAFETY: AVX2 support was detected at runtime.
+            return unsafe { x86::swap_avx2(bytes, size) };
+        }
+
+        if std::is_x86_feature_detected!("ssse3") {
+            // SAFETY: SSSE3 support was detected at runtime.
+            return unsafe { x86::swap_ssse3(bytes, size) };
+        }
+    }
+
+    #[cfg(all(not(feature = "std"), target_feature = "avx2"))]
+    {
+        // SAFETY: AVX2 is enabled at compile time.
+        return unsafe { x86::swap_avx2(bytes, size) };
+    }
+
+    #[cfg(all(
+        not(feature = "std"),
+        not(target_feature = "avx2"),
+        target_feature = "ssse3"
+    ))]
+    {
+        // SAFETY: SSSE3 is enabled at compile time.
+        return unsafe { x86::swap_ssse3(bytes, size) };
+    }
+
+    #[allow(unreachable_code)]
+    {
+        let _ = (bytes, size);
+        0
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+fn swap_bytes_vectorized(bytes: &mut [u8], size: usize) -> usize {
+    use core::arch::aarch64::{vld1q_u8, vqtbl1q_u8, vst1q_u8};
+
+    let mask = shuffle_mask(size);
+    let chunks = bytes.chunks_exact_mut(16);
+
+    // SAFETY: NEON is always available on AArch64, and every chunk is exactly 16 bytes long.
+    unsafe {
+        let mask = vld1q_u8(mask.as_ptr());
+
+        for chunk in chunks {
+            let vector = vld1q_u8(chunk.as_ptr());
+            vst1q_u8(chunk.as_mut_ptr(), vqtbl1q_u8(vector, mask));
+        }
+    }
+
+    bytes.len() - chunks.into_remainder().len()
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64", target_arch = "aarch64")))]
+fn swap_bytes_vectorized(_bytes: &mut [u8], _size: usize) -> usize {
+    0
+}
+
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(feature = "std", target_feature = "ssse3")
+))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use super::shuffle_mask;
+
+    #[cfg(any(feature = "std", target_feature = "avx2"))]
+    #[target_feature(enable = "avx2")]
+    pub(super) unsafe fn swap_avx2(bytes: &mut [u8], size: usize) -> usize {
+        let mask = shuffle_mask(size);
+        let done = bytes.len() - bytes.len() % 32;
+        let chunks = bytes.chunks_exact_mut(32);
+
+        // `vpshufb` shuffles within each 128-bit lane, so the same mask is used for both lanes.
+        let mask = _mm_loadu_si128(mask.as_ptr() as *const __m128i);
+        let mask = _mm256_broadcastsi128_si256(mask);
+
+        for chunk in chunks {
+            let pointer = chunk.as_mut_ptr() as *mut __m256i;
+
+            _mm256_storeu_si256(
+                pointer,
+                _mm256_shuffle_epi8(_mm256_loadu_si256(pointer), mask),
+            );
+        }
+
+        done
+    }
+
+    #[cfg(any(feature = "std", not(target_feature = "avx2")))]
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn swap_ssse3(bytes: &mut [u8], size: usize) -> usize {
+        let mask = shuffle_mask(size);
+        let done = bytes.len() - bytes.len() % 16;
+        let chunks = bytes.chunks_exact_mut(16);
+
+        let mask = _mm_loadu_si128(mask.as_ptr() as *const __m128i);
+
+        for chunk in chunks {
+            let pointer = chunk.as_mut_ptr() as *mut __m128i;
+
+            _mm_storeu_si128(pointer, _mm_shuffle_epi8(_mm_loadu_si128(pointer), mask));
+        }
+
+        done
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    #[test]
+    fn it_matches_scalar_byte_swapping() {
+        for size in [2, 4, 8, 16] {
+            for count in 0..20 {
+                let original: Vec<u8> = (0..size * count).map(|i| i as u8).collect();
+
+                let mut expected = original.clone();
+                swap_bytes_scalar(&mut expected, size);
+
+                let mut actual = original.clone();
+                swap_bytes(&mut actual, size);
+
+                assert_eq!(actual, expected, "size {}, count {}", size, count);
+            }
+        }
+    }
+
+    #[cfg(all(feature = "std", any(target_arch = "x86", target_arch = "x86_64")))]
+    #[test]
+    fn it_matches_scalar_byte_swapping_with_ssse3() {
+        if !std::is_x86_feature_detected!("ssse3") {
+            return;
+        }
+
+        let original: Vec<u8> = (0..100).collect();
+
+        let mut expected = original.clone();
+        swap_bytes_scalar(&mut expected[..96], 4);
+
+        let mut actual = original.clone();
+        let done = unsafe { x86::swap_ssse3(&mut actual[..96], 4) };
+        swap_bytes_scalar(&mut actual[done..96], 4);
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,106 @@
+use smallvec::SmallVec;
+
+use crate::convert::check_length;
+#[cfg(feature = "safe-only")]
+use crate::into::decode_elements;
+#[cfg(not(feature = "safe-only"))]
+use crate::into::write_elements;
+use crate::{FromBytes, Result};
+
+fn bytes_to_smallvec_with<T: FromBytes, const N: usize>(
+    bytes: &[u8],
+    native: bool,
+    decode: fn(&[u8]) -> T,
+) -> Result<SmallVec<[T; N]>> {
+    check_length::<T>(bytes)?;
+
+    let count = bytes.len() / core::mem::size_of::<T>();
+    let mut values = SmallVec::with_capacity(count);
+
+    #[cfg(not(feature = "safe-only"))]
+    // SAFETY: the length was checked above, and the vector has room for `count` elements, which
+    // are all initialized before the length is updated.
+    unsafe {
+        write_elements(bytes, values.as_mut_ptr(), native, decode);
+        values.set_len(count);
+    }
+
+    #[cfg(feature = "safe-only")]
+    {
+        let _ = native;
+        values.extend(decode_elements(bytes, decode));
+    }
+
+    Ok(values)
+}
+
+/// Converts a byte slice into a [`SmallVec`] of `T` in native byte order, available with the
+/// `smallvec` feature.
+///
+/// Up to `N` elements are stored inline, so short inputs such as headers are decoded without
+/// allocating.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_smallvec_be;
+///
+/// let header = bytes_to_smallvec_be::<u16, 4>(&[0, 1, 0, 2]).unwrap();
+///
+/// assert_eq!(&header[..], &[1, 2]);
+/// assert!(!header.spilled());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_smallvec<T: FromBytes, const N: usize>(bytes: &[u8]) -> Result<SmallVec<[T; N]>> {
+    bytes_to_smallvec_with(bytes, true, T::from_ne_slice)
+}
+
+/// Converts a byte slice into a [`SmallVec`] of `T`, decoding each element as little-endian,
+/// available with the `smallvec` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_smallvec_le<T: FromBytes, const N: usize>(
+    bytes: &[u8],
+) -> Result<SmallVec<[T; N]>> {
+    bytes_to_smallvec_with(bytes, cfg!(target_endian = "little"), T::from_le_slice)
+}
+
+/// Converts a byte slice into a [`SmallVec`] of `T`, decoding each element as big-endian,
+/// available with the `smallvec` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of the size of `T`.
+pub fn bytes_to_smallvec_be<T: FromBytes, const N: usize>(
+    bytes: &[u8],
+) -> Result<SmallVec<[T; N]>> {
+    bytes_to_smallvec_with(bytes, cfg!(target_endian = "big"), T::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_spills_long_inputs_to_the_heap() {
+        let bytes = [1, 0, 2, 0, 3, 0];
+
+        let values = bytes_to_smallvec_le::<u16, 2>(&bytes).unwrap();
+        assert_eq!(&values[..], &[1, 2, 3]);
+        assert!(values.spilled());
+
+        let values = bytes_to_smallvec::<u8, 8>(&bytes).unwrap();
+        assert_eq!(&values[..], &bytes);
+        assert!(!values.spilled());
+
+        assert!(bytes_to_smallvec::<u32, 2>(&bytes).is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use crate::convert::check_length;
+use crate::{Error, FromBytes, Result};
+
+/// Returns the number of elements of `T` that `bytes` holds.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::element_count;
+///
+/// assert_eq!(element_count::<u32>(&[0; 12]).unwrap(), 3);
+/// assert!(element_count::<u32>(&[0; 10]).is_err());
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of
+/// `T`.
+pub fn element_count<T: FromBytes>(bytes: &[u8]) -> Result<usize> {
+    check_length::<T>(bytes)?;
+
+    Ok(bytes.len() / core::mem::size_of::<T>())
+}
+
+/// Splits `bytes` after its first `n` elements of `T`, returning the bytes of those elements and
+/// the remaining bytes.
+///
+/// This replaces slicing such as `&bytes[n * 4..]` when separating a header or a fixed number of
+/// values from a payload: the offset is derived from the size of `T`, and a count too large for
+/// the input, including one whose size in bytes overflows, is an error instead of a panic.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{bytes_to_vec_le, split_at_elements};
+///
+/// let bytes = [1, 0, 2, 0, 9, 9, 9];
+/// let (header, payload) = split_at_elements::<u16>(&bytes, 2).unwrap();
+///
+/// assert_eq!(bytes_to_vec_le::<u16>(header).unwrap(), vec![1, 2]);
+/// assert_eq!(payload, &[9, 9, 9]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` is shorter than `n` elements of `T`.
+pub fn split_at_elements<T: FromBytes>(bytes: &[u8], n: usize) -> Result<(&[u8], &[u8])> {
+    let mid = n.saturating_mul(core::mem::size_of::<T>());
+
+    if mid > bytes.len() {
+        return Err(Error::Truncated {
+            len: bytes.len(),
+            expected: mid,
+        });
+    }
+
+    Ok(bytes.split_at(mid))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_splits_at_element_boundaries() {
+        let bytes = [0; 10];
+
+        assert_eq!(element_count::<u16>(&bytes).unwrap(), 5);
+        assert_eq!(
+            element_count::<u64>(&bytes).unwrap_err(),
+            Error::length_mismatch(10, 8)
+        );
+        assert_eq!(
+            split_at_elements::<u32>(&bytes, 2).unwrap(),
+            (&[0; 8][..], &[0; 2][..])
+        );
+        assert_eq!(
+            split_at_elements::<u32>(&bytes, 3).unwrap_err(),
+            Error::Truncated {
+                len: 10,
+                expected: 12
+            }
+        );
+        assert_eq!(
+            split_at_elements::<u64>(&bytes, usize::MAX).unwrap_err(),
+            Error::Truncated {
+                len: 10,
+                expected: usize::MAX
+            }
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use alloc::vec::Vec;
+
+use crate::{Error, FromBytes, Result};
+
+fn bytes_to_strided_with<T: FromBytes>(
+    bytes: &[u8],
+    offset: usize,
+    stride: usize,
+    decode: fn(&[u8]) -> T,
+) -> Result<Vec<T>> {
+    assert!(stride != 0, "Stride must be nonzero");
+
+    let size = core::mem::size_of::<T>();
+    let mut values = Vec::with_capacity(bytes.len().saturating_sub(offset).div_ceil(stride));
+    let mut position = offset;
+
+    while position < bytes.len() {
+        let element = bytes
+            .get(position..position + size)
+            .ok_or(Error::UnexpectedEnd {
+                offset: position,
+                needed: size,
+                remaining: bytes.len() - position,
+            })?;
+        values.push(decode(element));
+        position = position.saturating_add(stride);
+    }
+
+    Ok(values)
+}
+
+/// Decodes every element of `T` in native byte order that starts `offset` bytes into `bytes` and
+/// then every `stride` bytes, skipping the bytes in between.
+///
+/// This extracts a single channel of interleaved data without decoding the other channels. The
+/// stride is the distance between the starts of consecutive elements, so it is usually the size of
+/// one interleaved record.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_strided_le;
+///
+/// // Records of `x: u16, y: u16, temp: u16`.
+/// let bytes = [1, 0, 2, 0, 20, 0, 3, 0, 4, 0, 21, 0];
+///
+/// assert_eq!(bytes_to_strided_le::<u16>(&bytes, 4, 6).unwrap(), vec![20, 21]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedEnd`] if the input ends within the last element.
+///
+/// # Panics
+///
+/// Panics if `stride` is zero.
+pub fn bytes_to_strided<T: FromBytes>(
+    bytes: &[u8],
+    offset: usize,
+    stride: usize,
+) -> Result<Vec<T>> {
+    bytes_to_strided_with(bytes, offset, stride, T::from_ne_slice)
+}
+
+/// Decodes every little-endian element of `T` that starts `offset` bytes into `bytes` and then
+/// every `stride` bytes.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedEnd`] if the input ends within the last element.
+///
+/// # Panics
+///
+/// Panics if `stride` is zero.
+pub fn bytes_to_strided_le<T: FromBytes>(
+    bytes: &[u8],
+    offset: usize,
+    stride: usize,
+) -> Result<Vec<T>> {
+    bytes_to_strided_with(bytes, offset, stride, T::from_le_slice)
+}
+
+/// Decodes every big-endian element of `T` that starts `offset` bytes into `bytes` and then every
+/// `stride` bytes.
+///
+/// # Errors
+///
+/// Returns [`Error::UnexpectedEnd`] if the input ends within the last element.
+///
+/// # Panics
+///
+/// Panics if `stride` is zero.
+pub fn bytes_to_strided_be<T: FromBytes>(
+    bytes: &[u8],
+    offset: usize,
+    stride: usize,
+) -> Result<Vec<T>> {
+    bytes_to_strided_with(bytes, offset, stride, T::from_be_slice)
+}
+
+/// Returns the number of records of `size` bytes in `bytes`, used by
+/// [`deinterleave!`](crate::deinterleave).
+#[doc(hidden)]
+pub fn record_count(bytes: &[u8], size: usize) -> Result<usize> {
+    if !bytes.len().is_multiple_of(size) {
+        return Err(Error::length_mismatch(bytes.len(), size));
+    }
+
+    Ok(bytes.len() / size)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_strided_elements() {
+        let bytes = [0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0];
+
+        assert_eq!(
+            bytes_to_strided_be::<u16>(&bytes, 2, 6).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            bytes_to_strided::<u8>(&bytes, 3, 2).unwrap(),
+            vec![1, 0, 0, 2]
+        );
+        assert_eq!(
+            bytes_to_strided::<u32>(&bytes, 20, 4).unwrap(),
+            Vec::<u32>::new()
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_truncated_last_element() {
+        assert_eq!(
+            bytes_to_strided_le::<u32>(&[1, 0, 0, 0, 9, 2, 0], 0, 5),
+            Err(Error::UnexpectedEnd {
+                offset: 5,
+                needed: 4,
+                remaining: 2
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Stride must be nonzero")]
+    fn it_panics_on_zero_stride() {
+        let _ = bytes_to_strided::<u8>(&[1], 0, 0);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::FromBytes;
+
+/// Reverses the byte order of every element of `values` in place.
+///
+/// This converts elements decoded in native byte order to the opposite byte order without
+/// reallocating, for example to fix up a [`bytes_to_slice_mut`](crate::bytes_to_slice_mut) view of
+/// big-endian data on a little-endian host. The bytes of primitives are reversed as a whole, using
+/// vector instructions with the `simd` feature, while the fields of derived structs are swapped one
+/// by one.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::swap_bytes_in_place;
+///
+/// let mut values = vec![0x1234u16, 0xABCD];
+/// swap_bytes_in_place(&mut values);
+///
+/// assert_eq!(values, vec![0x3412, 0xCDAB]);
+/// ```
+pub fn swap_bytes_in_place<T: FromBytes>(values: &mut [T]) {
+    let size = core::mem::size_of::<T>();
+
+    if T::IS_PRIMITIVE {
+        // SAFETY: primitives have no padding, and any bytes written to them form valid values.
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(
+                values.as_mut_ptr() as *mut u8,
+                core::mem::size_of_val(values),
+            )
+        };
+
+        #[cfg(feature = "simd")]
+        if matches!(size, 2 | 4 | 8 | 16) {
+            crate::simd::swap_bytes(bytes, size);
+            return;
+        }
+
+        for element in bytes.chunks_exact_mut(size) {
+            element.reverse();
+        }
+
+        return;
+    }
+
+    for value in values {
+        // SAFETY: `FromBytes` types have no padding, so all bytes of `value` are initialized.
+        let bytes = unsafe { core::slice::from_raw_parts(value as *const T as *const u8, size) };
+
+        // Decoding the native bytes in the opposite byte order swaps every field.
+        *value = if cfg!(target_endian = "little") {
+            T::from_be_slice(bytes)
+        } else {
+            T::from_le_slice(bytes)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_swaps_long_slices() {
+        let mut values: [u32; 37] = core::array::from_fn(|index| index as u32);
+        swap_bytes_in_place(&mut values);
+
+        assert!(values
+            .iter()
+            .enumerate()
+            .all(|(index, &value)| value == (index as u32).swap_bytes()));
+
+        let mut bytes = [1u8, 2];
+        swap_bytes_in_place(&mut bytes);
+        assert_eq!(bytes, [1, 2]);
+
+        let mut floats = [1.0f64];
+        swap_bytes_in_place(&mut floats);
+        assert_eq!(floats[0].to_bits(), 1.0f64.to_bits().swap_bytes());
+    }
+}
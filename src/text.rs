@@ -0,0 +1,132 @@
+use alloc::string::String;
+
+use crate::{iter_elements_be, iter_elements_le, Elements, Error, Result};
+
+fn utf16_to_string(units: Elements<'_, u16>) -> Result<String> {
+    let mut string = String::with_capacity(units.len());
+    let mut position = 0;
+
+    for result in char::decode_utf16(units) {
+        match result {
+            Ok(char) => {
+                string.push(char);
+                position += char.len_utf16();
+            }
+            Err(error) => {
+                return Err(Error::InvalidUtf16 {
+                    offset: position * 2,
+                    unit: error.unpaired_surrogate(),
+                })
+            }
+        }
+    }
+
+    Ok(string)
+}
+
+fn utf32_to_string(units: Elements<'_, u32>) -> Result<String> {
+    units
+        .enumerate()
+        .map(|(index, value)| {
+            char::from_u32(value).ok_or(Error::InvalidChar {
+                offset: index * 4,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// Decodes a little-endian UTF-16 byte slice into a [`String`].
+///
+/// This is the encoding of most strings in Windows file formats, such as registry hives and NTFS
+/// metadata.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::bytes_to_string_utf16_le;
+///
+/// let bytes = [0x48, 0, 0x69, 0, 0x3D, 0xD8, 0x80, 0xDE];
+///
+/// assert_eq!(bytes_to_string_utf16_le(&bytes).unwrap(), "Hi🚀");
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is odd, or [`Error::InvalidUtf16`]
+/// with the offset of the first unpaired surrogate.
+pub fn bytes_to_string_utf16_le(bytes: &[u8]) -> Result<String> {
+    utf16_to_string(iter_elements_le(bytes)?)
+}
+
+/// Decodes a big-endian UTF-16 byte slice into a [`String`].
+///
+/// # Errors
+///
+/// See [`bytes_to_string_utf16_le`].
+pub fn bytes_to_string_utf16_be(bytes: &[u8]) -> Result<String> {
+    utf16_to_string(iter_elements_be(bytes)?)
+}
+
+/// Decodes a little-endian UTF-32 byte slice into a [`String`].
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of 4, or
+/// [`Error::InvalidChar`] for the first value that is not a Unicode scalar value.
+pub fn bytes_to_string_utf32_le(bytes: &[u8]) -> Result<String> {
+    utf32_to_string(iter_elements_le(bytes)?)
+}
+
+/// Decodes a big-endian UTF-32 byte slice into a [`String`].
+///
+/// # Errors
+///
+/// See [`bytes_to_string_utf32_le`].
+pub fn bytes_to_string_utf32_be(bytes: &[u8]) -> Result<String> {
+    utf32_to_string(iter_elements_be(bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_decodes_utf16_strings() {
+        let bytes = [0, 0x48, 0xD8, 0x3D, 0xDE, 0x80];
+
+        assert_eq!(bytes_to_string_utf16_be(&bytes).unwrap(), "H🚀");
+        assert_eq!(bytes_to_string_utf16_le(&[]).unwrap(), "");
+    }
+
+    #[test]
+    fn it_returns_error_with_offset_of_unpaired_surrogate() {
+        let bytes = [0x48, 0, 0x3D, 0xD8, 0x48, 0];
+
+        assert_eq!(
+            bytes_to_string_utf16_le(&bytes),
+            Err(Error::InvalidUtf16 {
+                offset: 2,
+                unit: 0xD83D
+            })
+        );
+        assert_eq!(
+            bytes_to_string_utf16_le(&bytes[..3]),
+            Err(Error::length_mismatch(3, 2))
+        );
+    }
+
+    #[test]
+    fn it_decodes_utf32_strings() {
+        let bytes = [0, 0, 0, 0x48, 0, 1, 0xF6, 0x80];
+
+        assert_eq!(bytes_to_string_utf32_be(&bytes).unwrap(), "H🚀");
+        assert_eq!(
+            bytes_to_string_utf32_le(&bytes),
+            Err(Error::InvalidChar {
+                offset: 0,
+                value: 0x48000000
+            })
+        );
+    }
+}
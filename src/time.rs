@@ -0,0 +1,210 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::SystemTime;
+
+use crate::convert::check_length;
+#[cfg(feature = "std")]
+use crate::Error;
+use crate::{FromBytes, Result};
+
+/// The unit of the timestamps decoded by the time conversion functions, such as
+/// [`bytes_to_duration_le`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+pub enum TimeUnit {
+    /// Seconds.
+    Seconds,
+    /// Milliseconds.
+    Millis,
+    /// Microseconds.
+    Micros,
+    /// Nanoseconds.
+    Nanos,
+}
+
+impl TimeUnit {
+    fn duration(self, value: u64) -> Duration {
+        match self {
+            TimeUnit::Seconds => Duration::from_secs(value),
+            TimeUnit::Millis => Duration::from_millis(value),
+            TimeUnit::Micros => Duration::from_micros(value),
+            TimeUnit::Nanos => Duration::from_nanos(value),
+        }
+    }
+}
+
+fn bytes_to_duration_with(
+    bytes: &[u8],
+    unit: TimeUnit,
+    decode: fn(&[u8]) -> u64,
+) -> Result<Vec<Duration>> {
+    check_length::<u64>(bytes)?;
+
+    Ok(bytes
+        .chunks_exact(8)
+        .map(|chunk| unit.duration(decode(chunk)))
+        .collect())
+}
+
+/// Converts a byte slice into a vector of [`Duration`]s, decoding each element as a `u64` count of
+/// `unit` in native byte order.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is
+/// not a multiple of 8.
+pub fn bytes_to_duration(bytes: &[u8], unit: TimeUnit) -> Result<Vec<Duration>> {
+    bytes_to_duration_with(bytes, unit, u64::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of [`Duration`]s, decoding each element as a little-endian
+/// `u64` count of `unit`.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use bytes_to_type::{bytes_to_duration_le, TimeUnit};
+///
+/// let bytes = 1500u64.to_le_bytes();
+///
+/// assert_eq!(
+///     bytes_to_duration_le(&bytes, TimeUnit::Millis).unwrap(),
+///     vec![Duration::from_millis(1500)]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// See [`bytes_to_duration`].
+pub fn bytes_to_duration_le(bytes: &[u8], unit: TimeUnit) -> Result<Vec<Duration>> {
+    bytes_to_duration_with(bytes, unit, u64::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of [`Duration`]s, decoding each element as a big-endian
+/// `u64` count of `unit`.
+///
+/// # Errors
+///
+/// See [`bytes_to_duration`].
+pub fn bytes_to_duration_be(bytes: &[u8], unit: TimeUnit) -> Result<Vec<Duration>> {
+    bytes_to_duration_with(bytes, unit, u64::from_be_slice)
+}
+
+#[cfg(feature = "std")]
+fn bytes_to_system_time_with(
+    bytes: &[u8],
+    unit: TimeUnit,
+    decode: fn(&[u8]) -> i64,
+) -> Result<Vec<SystemTime>> {
+    check_length::<i64>(bytes)?;
+
+    bytes
+        .chunks_exact(8)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let value = decode(chunk);
+            let offset = unit.duration(value.unsigned_abs());
+
+            if value < 0 {
+                SystemTime::UNIX_EPOCH.checked_sub(offset)
+            } else {
+                SystemTime::UNIX_EPOCH.checked_add(offset)
+            }
+            .ok_or(Error::TimeOverflow { index })
+        })
+        .collect()
+}
+
+/// Converts a byte slice into a vector of [`SystemTime`]s, decoding each element as an `i64` count
+/// of `unit` since the Unix epoch in native byte order, available with the `std` feature.
+///
+/// Negative values are times before the epoch.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of 8, or
+/// [`Error::TimeOverflow`] with the index of the first timestamp that the platform cannot
+/// represent as a [`SystemTime`].
+#[cfg(feature = "std")]
+pub fn bytes_to_system_time(bytes: &[u8], unit: TimeUnit) -> Result<Vec<SystemTime>> {
+    bytes_to_system_time_with(bytes, unit, i64::from_ne_slice)
+}
+
+/// Converts a byte slice into a vector of [`SystemTime`]s, decoding each element as a
+/// little-endian `i64` count of `unit` since the Unix epoch, available with the `std` feature.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+///
+/// use bytes_to_type::{bytes_to_system_time_le, TimeUnit};
+///
+/// let bytes = 1_700_000_000i64.to_le_bytes();
+///
+/// assert_eq!(
+///     bytes_to_system_time_le(&bytes, TimeUnit::Seconds).unwrap(),
+///     vec![SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000)]
+/// );
+/// ```
+///
+/// # Errors
+///
+/// See [`bytes_to_system_time`].
+#[cfg(feature = "std")]
+pub fn bytes_to_system_time_le(bytes: &[u8], unit: TimeUnit) -> Result<Vec<SystemTime>> {
+    bytes_to_system_time_with(bytes, unit, i64::from_le_slice)
+}
+
+/// Converts a byte slice into a vector of [`SystemTime`]s, decoding each element as a big-endian
+/// `i64` count of `unit` since the Unix epoch, available with the `std` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_system_time`].
+#[cfg(feature = "std")]
+pub fn bytes_to_system_time_be(bytes: &[u8], unit: TimeUnit) -> Result<Vec<SystemTime>> {
+    bytes_to_system_time_with(bytes, unit, i64::from_be_slice)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_converts_bytes_to_durations() {
+        let bytes = [0, 0, 0, 0, 0, 0, 0, 3];
+
+        assert_eq!(
+            bytes_to_duration_be(&bytes, TimeUnit::Seconds).unwrap(),
+            vec![Duration::from_secs(3)]
+        );
+        assert_eq!(
+            bytes_to_duration_be(&bytes, TimeUnit::Nanos).unwrap(),
+            vec![Duration::from_nanos(3)]
+        );
+        assert!(bytes_to_duration(&bytes[1..], TimeUnit::Micros).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn it_converts_bytes_to_system_times_before_and_after_the_epoch() {
+        let bytes: Vec<u8> = [-1500i64, 2500]
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect();
+
+        assert_eq!(
+            bytes_to_system_time_be(&bytes, TimeUnit::Millis).unwrap(),
+            vec![
+                SystemTime::UNIX_EPOCH - Duration::from_millis(1500),
+                SystemTime::UNIX_EPOCH + Duration::from_millis(2500),
+            ]
+        );
+    }
+}
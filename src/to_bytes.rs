@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+
+use crate::convert::as_bytes;
+use crate::{swap_bytes_in_place, Endianness, FromBytes};
+
+/// Encodes `values` in the given byte order.
+pub(crate) fn encode<T: FromBytes>(values: &[T], endianness: Endianness) -> Vec<u8> {
+    if endianness == Endianness::NATIVE {
+        return as_bytes(values).to_vec();
+    }
+
+    let mut values = values.to_vec();
+    swap_bytes_in_place(&mut values);
+
+    as_bytes(&values).to_vec()
+}
+
+/// A type that can be encoded to raw bytes, the counterpart of [`FromBytes`].
+///
+/// `ToBytes` is implemented for every [`FromBytes`] type, including structs deriving it, so a
+/// single `FromBytes` bound lets generic code both decode and encode values. Decoding the encoded
+/// bytes in the same byte order yields the original values.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::{FromBytes, ToBytes};
+///
+/// fn reencode_be<T: FromBytes>(bytes: &[u8]) -> Vec<u8> {
+///     T::to_byte_vec_be(&T::from_byte_slice_le(bytes).unwrap())
+/// }
+///
+/// assert_eq!(reencode_be::<u16>(&[1, 0, 2, 0]), [0, 1, 0, 2]);
+/// assert_eq!(u32::as_byte_slice(&[7]), 7u32.to_ne_bytes());
+/// ```
+pub trait ToBytes: FromBytes {
+    /// Encodes a slice of `Self` into bytes in native byte order.
+    #[inline]
+    fn to_byte_vec(values: &[Self]) -> Vec<u8> {
+        encode(values, Endianness::NATIVE)
+    }
+
+    /// Encodes a slice of `Self` into bytes, writing each element as little-endian.
+    #[inline]
+    fn to_byte_vec_le(values: &[Self]) -> Vec<u8> {
+        encode(values, Endianness::Little)
+    }
+
+    /// Encodes a slice of `Self` into bytes, writing each element as big-endian.
+    #[inline]
+    fn to_byte_vec_be(values: &[Self]) -> Vec<u8> {
+        encode(values, Endianness::Big)
+    }
+
+    /// Views a slice of `Self` as its bytes in native byte order, without copying.
+    #[inline]
+    fn as_byte_slice(values: &[Self]) -> &[u8] {
+        as_bytes(values)
+    }
+}
+
+impl<T: FromBytes> ToBytes for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_encodes_in_every_byte_order() {
+        let values = [0x0102u16, 0x0304];
+
+        assert_eq!(u16::to_byte_vec_le(&values), [2, 1, 4, 3]);
+        assert_eq!(u16::to_byte_vec_be(&values), [1, 2, 3, 4]);
+        assert_eq!(u16::to_byte_vec(&values), u16::as_byte_slice(&values));
+        assert_eq!(
+            u16::from_byte_slice_be(&u16::to_byte_vec_be(&values)).unwrap(),
+            values
+        );
+        assert_eq!(
+            crate::U32Be::to_byte_vec_le(&[crate::U32Be::new(1)]),
+            [0, 0, 0, 1]
+        );
+    }
+}
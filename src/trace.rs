@@ -0,0 +1,170 @@
+//! Instrumentation of the conversion paths, emitting [`tracing`] events with the `tracing`
+//! feature and compiling to nothing otherwise.
+
+/// The strategy a conversion uses to produce its elements, as returned by
+/// [`conversion_path`](crate::conversion_path) and reported by the `tracing` events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConversionPath {
+    /// The input is borrowed in place, without copying.
+    Borrowed,
+    /// The input cannot be borrowed in place because it is not aligned for the target type, and
+    /// is copied instead.
+    Misaligned,
+    /// The input is aligned for the target type and copied as a whole in native byte order.
+    Copied,
+    /// The input is not aligned for the target type, and is copied as a whole into a freshly
+    /// allocated vector, which is aligned.
+    Realigned,
+    /// The input is copied as a whole and then byte-swapped with vector instructions. Available
+    /// with the `simd` feature.
+    #[cfg(all(feature = "simd", not(feature = "safe-only")))]
+    Swapped,
+    /// The elements are decoded one by one, because they need swapping or the `safe-only`
+    /// feature is enabled.
+    Elementwise,
+}
+
+impl ConversionPath {
+    #[cfg(feature = "tracing")]
+    fn as_str(self) -> &'static str {
+        match self {
+            ConversionPath::Borrowed => "borrowed",
+            ConversionPath::Misaligned => "misaligned",
+            ConversionPath::Copied => "copied",
+            ConversionPath::Realigned => "realigned",
+            #[cfg(all(feature = "simd", not(feature = "safe-only")))]
+            ConversionPath::Swapped => "swapped",
+            ConversionPath::Elementwise => "elementwise",
+        }
+    }
+}
+
+/// Records that `len` bytes were converted to elements of `T` through `path`.
+///
+/// Conversions emit `TRACE` events under the `bytes_to_type` target, with the type, the number of
+/// bytes and elements and the path taken. Missed zero-copy conversions are emitted at the `DEBUG`
+/// level, so they can be monitored without enabling every conversion event.
+#[inline(always)]
+#[cfg_attr(not(feature = "tracing"), allow(clippy::extra_unused_type_parameters))]
+pub(crate) fn conversion<T>(len: usize, path: ConversionPath) {
+    #[cfg(feature = "tracing")]
+    {
+        let elements = len / core::mem::size_of::<T>();
+        let r#type = core::any::type_name::<T>();
+
+        if path == ConversionPath::Misaligned {
+            tracing::debug!(
+                target: "bytes_to_type",
+                r#type,
+                bytes = len,
+                elements,
+                path = path.as_str(),
+                "Input is not aligned for a zero-copy conversion"
+            );
+        } else {
+            tracing::trace!(
+                target: "bytes_to_type",
+                r#type,
+                bytes = len,
+                elements,
+                path = path.as_str(),
+                "Converted bytes"
+            );
+        }
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    let _ = (len, path);
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use alloc::string::{String, ToString};
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+    use std::sync::Mutex;
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::{bytes_to_cow, bytes_to_vec, bytes_to_vec_be, bytes_to_vec_le};
+
+    /// A subscriber recording the `path` field of every event.
+    #[derive(Default)]
+    struct Paths(Arc<Mutex<Vec<String>>>);
+
+    struct PathVisitor<'a>(&'a mut Option<String>);
+
+    impl Visit for PathVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "path" {
+                *self.0 = Some(value.to_string());
+            }
+        }
+
+        fn record_debug(&mut self, _: &Field, _: &dyn core::fmt::Debug) {}
+    }
+
+    impl Subscriber for Paths {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut path = None;
+            event.record(&mut PathVisitor(&mut path));
+            self.0.lock().unwrap().extend(path);
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn it_reports_the_conversion_path() {
+        let paths = Paths::default();
+        let recorded = paths.0.clone();
+        let values = [1u32, 2, 3];
+        // SAFETY: the array holds 12 initialized bytes.
+        let bytes = unsafe { core::slice::from_raw_parts(values.as_ptr() as *const u8, 12) };
+
+        tracing::subscriber::with_default(paths, || {
+            bytes_to_vec::<u32>(bytes).unwrap();
+            bytes_to_vec::<u32>(&bytes[1..9]).unwrap();
+            bytes_to_cow::<u32>(bytes).unwrap();
+            bytes_to_cow::<u32>(&bytes[1..9]).unwrap();
+            bytes_to_vec_be::<u8>(bytes).unwrap();
+            bytes_to_vec_le::<i8>(&bytes[1..]).unwrap();
+        });
+
+        let (copied, realigned) = if cfg!(feature = "safe-only") {
+            ("elementwise", "elementwise")
+        } else {
+            ("copied", "realigned")
+        };
+
+        assert_eq!(
+            *recorded.lock().unwrap(),
+            [
+                copied,
+                realigned,
+                "borrowed",
+                "misaligned",
+                realigned,
+                copied,
+                copied
+            ]
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Decoding of variable-length integers.
+//!
+//! Varints store an integer in groups of 7 bits, least significant group first, with the high bit
+//! of each byte set when more bytes follow. Unsigned varints, as used by Protocol Buffers, are the
+//! same encoding as unsigned LEB128. Signed integers are either zigzag-encoded first, as in
+//! Protocol Buffers, or stored as signed LEB128, the two's complement variant used by DWARF and
+//! WebAssembly.
+//!
+//! The `decode_*` functions decode a single integer from the start of a byte slice, while the
+//! `bytes_to_*` functions decode consecutive integers. Both return the number of bytes consumed.
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::varint;
+//!
+//! let bytes = [0x96, 0x01, 0x03, 0xAC];
+//!
+//! assert_eq!(varint::decode_u64(&bytes).unwrap(), (150, 2));
+//!
+//! // The last byte starts a varint which is not complete yet.
+//! assert_eq!(varint::bytes_to_varint_u64s(&bytes).unwrap(), (vec![150, 3], 3));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::{Error, Result};
+
+/// The maximum number of bytes taken by a 64-bit varint.
+const MAX_LEN: usize = 10;
+
+/// The outcome of decoding a single integer.
+enum Decoded<T> {
+    /// The integer and the number of bytes it takes.
+    Complete(T, usize),
+    /// The input ends before the last byte of the integer.
+    Incomplete,
+    /// The integer does not fit in 64 bits.
+    Overflow,
+}
+
+impl<T> Decoded<T> {
+    fn map<U>(self, f: impl FnOnce(T, usize) -> U) -> Decoded<U> {
+        match self {
+            Decoded::Complete(value, len) => Decoded::Complete(f(value, len), len),
+            Decoded::Incomplete => Decoded::Incomplete,
+            Decoded::Overflow => Decoded::Overflow,
+        }
+    }
+}
+
+/// Decodes the 7-bit groups of a varint at the start of `bytes`, along with its last byte.
+///
+/// `check_last` validates the tenth byte, which only holds the top bit of a 64-bit integer.
+fn decode_raw(bytes: &[u8], check_last: fn(u8) -> bool) -> Decoded<(u64, u8)> {
+    let mut value = 0;
+
+    for (index, &byte) in bytes.iter().take(MAX_LEN).enumerate() {
+        if index == MAX_LEN - 1 && !check_last(byte) {
+            return Decoded::Overflow;
+        }
+
+        value |= u64::from(byte & 0x7F) << (7 * index);
+
+        if byte & 0x80 == 0 {
+            return Decoded::Complete((value, byte), index + 1);
+        }
+    }
+
+    if bytes.len() >= MAX_LEN {
+        return Decoded::Overflow;
+    }
+
+    Decoded::Incomplete
+}
+
+fn decode_unsigned(bytes: &[u8]) -> Decoded<u64> {
+    decode_raw(bytes, |byte| byte <= 1).map(|(value, _), _| value)
+}
+
+fn decode_zigzag(bytes: &[u8]) -> Decoded<i64> {
+    decode_unsigned(bytes).map(|value, _| ((value >> 1) as i64) ^ -((value & 1) as i64))
+}
+
+fn decode_signed_leb128(bytes: &[u8]) -> Decoded<i64> {
+    decode_raw(bytes, |byte| byte == 0 || byte == 0x7F).map(|(value, last), len| {
+        let shift = 7 * len;
+
+        if shift < 64 && last & 0x40 != 0 {
+            (value | (u64::MAX << shift)) as i64
+        } else {
+            value as i64
+        }
+    })
+}
+
+/// Decodes a single value at the start of `bytes` with `decode`.
+fn decode_one<T>(bytes: &[u8], decode: fn(&[u8]) -> Decoded<T>) -> Result<(T, usize)> {
+    match decode(bytes) {
+        Decoded::Complete(value, len) => Ok((value, len)),
+        Decoded::Incomplete => Err(Error::Truncated {
+            len: bytes.len(),
+            expected: bytes.len() + 1,
+        }),
+        Decoded::Overflow => Err(Error::VarintOverflow { offset: 0 }),
+    }
+}
+
+/// Decodes consecutive values from `bytes` with `decode`, stopping before an incomplete value.
+fn decode_all<T>(bytes: &[u8], decode: fn(&[u8]) -> Decoded<T>) -> Result<(Vec<T>, usize)> {
+    let mut values = Vec::new();
+    let mut offset = 0;
+
+    while offset < bytes.len() {
+        match decode(&bytes[offset..]) {
+            Decoded::Complete(value, len) => {
+                values.push(value);
+                offset += len;
+            }
+            Decoded::Incomplete => break,
+            Decoded::Overflow => return Err(Error::VarintOverflow { offset }),
+        }
+    }
+
+    Ok((values, offset))
+}
+
+/// Decodes an unsigned varint or unsigned LEB128 integer from the start of `bytes`, returning it
+/// along with the number of bytes it takes.
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` ends before the last byte of the varint, or
+/// [`Error::VarintOverflow`] if the varint does not fit in a `u64`.
+pub fn decode_u64(bytes: &[u8]) -> Result<(u64, usize)> {
+    decode_one(bytes, decode_unsigned)
+}
+
+/// Decodes a zigzag-encoded signed varint from the start of `bytes`, returning it along with the
+/// number of bytes it takes.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::varint;
+///
+/// assert_eq!(varint::decode_i64(&[0x03]).unwrap(), (-2, 1));
+/// ```
+///
+/// # Errors
+///
+/// See [`decode_u64`].
+pub fn decode_i64(bytes: &[u8]) -> Result<(i64, usize)> {
+    decode_one(bytes, decode_zigzag)
+}
+
+/// Decodes a signed LEB128 integer from the start of `bytes`, returning it along with the number
+/// of bytes it takes.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::varint;
+///
+/// assert_eq!(varint::decode_sleb128(&[0xC0, 0xBB, 0x78]).unwrap(), (-123456, 3));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::Truncated`] if `bytes` ends before the last byte of the integer, or
+/// [`Error::VarintOverflow`] if the integer does not fit in an `i64`.
+pub fn decode_sleb128(bytes: &[u8]) -> Result<(i64, usize)> {
+    decode_one(bytes, decode_signed_leb128)
+}
+
+/// Decodes consecutive unsigned varints or unsigned LEB128 integers from `bytes`.
+///
+/// Returns the decoded values along with the number of bytes consumed. Decoding stops before a
+/// varint that is cut off by the end of the input, whose bytes are not counted as consumed, so
+/// they can be kept until the rest of the varint arrives.
+///
+/// # Errors
+///
+/// Returns [`Error::VarintOverflow`] with the offset of the first varint that does not fit in a
+/// `u64`.
+pub fn bytes_to_varint_u64s(bytes: &[u8]) -> Result<(Vec<u64>, usize)> {
+    decode_all(bytes, decode_unsigned)
+}
+
+/// Decodes consecutive zigzag-encoded signed varints from `bytes`.
+///
+/// # Errors
+///
+/// See [`bytes_to_varint_u64s`].
+pub fn bytes_to_varint_i64s(bytes: &[u8]) -> Result<(Vec<i64>, usize)> {
+    decode_all(bytes, decode_zigzag)
+}
+
+/// Decodes consecutive signed LEB128 integers from `bytes`.
+///
+/// # Errors
+///
+/// Returns [`Error::VarintOverflow`] with the offset of the first integer that does not fit in
+/// an `i64`.
+pub fn bytes_to_sleb128_i64s(bytes: &[u8]) -> Result<(Vec<i64>, usize)> {
+    decode_all(bytes, decode_signed_leb128)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+
+    #[test]
+    fn it_decodes_unsigned_varints() {
+        assert_eq!(decode_u64(&[0x00]).unwrap(), (0, 1));
+        assert_eq!(decode_u64(&[0xAC, 0x02, 0xFF]).unwrap(), (300, 2));
+        assert_eq!(
+            decode_u64(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]).unwrap(),
+            (u64::MAX, 10)
+        );
+    }
+
+    #[test]
+    fn it_decodes_signed_varints() {
+        assert_eq!(
+            bytes_to_varint_i64s(&[0x00, 0x01, 0x02, 0x03, 0xFE, 0x01]).unwrap(),
+            (vec![0, -1, 1, -2, 127], 6)
+        );
+        assert_eq!(
+            bytes_to_sleb128_i64s(&[0x02, 0x7E, 0xFF, 0x00, 0x80, 0x7F]).unwrap(),
+            (vec![2, -2, 127, -128], 6)
+        );
+        assert_eq!(
+            decode_sleb128(&[0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x7F]).unwrap(),
+            (i64::MIN, 10)
+        );
+    }
+
+    #[test]
+    fn it_stops_before_incomplete_varints() {
+        assert_eq!(
+            bytes_to_varint_u64s(&[0x01, 0x80, 0x80]).unwrap(),
+            (vec![1], 1)
+        );
+        assert_eq!(
+            decode_u64(&[0x80, 0x80]),
+            Err(Error::Truncated {
+                len: 2,
+                expected: 3
+            })
+        );
+    }
+
+    #[test]
+    fn it_returns_error_on_varints_overflowing_64_bits() {
+        let mut bytes = vec![0x01];
+        bytes.extend([0xFF; 9]);
+        bytes.push(0x02);
+
+        assert_eq!(
+            bytes_to_varint_u64s(&bytes),
+            Err(Error::VarintOverflow { offset: 1 })
+        );
+        assert_eq!(
+            decode_u64(&[0x80; 11]),
+            Err(Error::VarintOverflow { offset: 0 })
+        );
+    }
+}
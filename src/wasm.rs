@@ -0,0 +1,189 @@
+use alloc::string::ToString;
+use alloc::vec::Vec;
+
+use js_sys::{
+    BigInt64Array, BigUint64Array, Float32Array, Float64Array, Int16Array, Int32Array, Int8Array,
+    Uint16Array, Uint32Array, Uint8Array,
+};
+use wasm_bindgen::{JsError, JsValue};
+
+use crate::from_bytes::sealed;
+use crate::{bytes_to_vec_endian, Endianness, FromBytes, Result};
+
+/// A primitive type with a matching JavaScript typed array, such as `f32` and `Float32Array`,
+/// available with the `wasm` feature.
+///
+/// This trait is sealed and implemented for `u8`, `i8`, `u16`, `i16`, `u32`, `i32`, `u64`, `i64`,
+/// `f32` and `f64`.
+pub trait TypedArrayElement: FromBytes + sealed::Sealed {
+    /// The typed array holding elements of this type.
+    type Array;
+
+    /// Creates a typed array viewing `length` elements of `buffer` from `byte_offset`.
+    #[doc(hidden)]
+    fn view(buffer: &JsValue, byte_offset: u32, length: u32) -> Self::Array;
+
+    /// Creates a typed array holding a copy of `values`.
+    #[doc(hidden)]
+    fn copy(values: &[Self]) -> Self::Array;
+}
+
+macro_rules! impl_typed_array_element {
+    ($($type:ty => $array:ident),*) => {
+        $(
+            impl TypedArrayElement for $type {
+                type Array = $array;
+
+                #[inline]
+                fn view(buffer: &JsValue, byte_offset: u32, length: u32) -> $array {
+                    $array::new_with_byte_offset_and_length(buffer, byte_offset, length)
+                }
+
+                #[inline]
+                fn copy(values: &[$type]) -> $array {
+                    $array::from(values)
+                }
+            }
+        )*
+    };
+}
+
+impl_typed_array_element! {
+    u8 => Uint8Array, i8 => Int8Array, u16 => Uint16Array, i16 => Int16Array,
+    u32 => Uint32Array, i32 => Int32Array, u64 => BigUint64Array, i64 => BigInt64Array,
+    f32 => Float32Array, f64 => Float64Array
+}
+
+/// Returns the number of elements of `T` in `len` bytes starting at `byte_offset` in their
+/// buffer, if a typed array can view them in place.
+fn view_length<T>(len: usize, byte_offset: usize, endianness: Endianness) -> Option<usize> {
+    let size = core::mem::size_of::<T>();
+
+    // Typed arrays are in the byte order of the host, which is little-endian on every platform
+    // running WebAssembly, so aligned little-endian input can be viewed in place.
+    let viewable = endianness == Endianness::Little
+        && cfg!(target_endian = "little")
+        && len.is_multiple_of(size)
+        && byte_offset.is_multiple_of(size);
+
+    viewable.then_some(len / size)
+}
+
+/// Decodes the bytes of a typed array that cannot be viewed in place.
+fn decode_copy<T: FromBytes>(bytes: &[u8], endianness: Endianness) -> Result<Vec<T>> {
+    bytes_to_vec_endian::<T>(bytes, endianness)
+}
+
+fn bytes_to_typed_array_with<T: TypedArrayElement>(
+    bytes: &Uint8Array,
+    endianness: Endianness,
+) -> Result<T::Array, JsError> {
+    let (len, byte_offset) = (bytes.length() as usize, bytes.byte_offset() as usize);
+
+    if let Some(length) = view_length::<T>(len, byte_offset, endianness) {
+        return Ok(T::view(&bytes.buffer(), bytes.byte_offset(), length as u32));
+    }
+
+    let values = decode_copy::<T>(&bytes.to_vec(), endianness)
+        .map_err(|error| JsError::new(&error.to_string()))?;
+
+    Ok(T::copy(&values))
+}
+
+/// Converts a JavaScript `Uint8Array` into the typed array of `T` in native byte order, which is
+/// little-endian on WebAssembly, available with the `wasm` feature.
+///
+/// When the input is aligned for `T`, as when it spans a whole `ArrayBuffer`, the result is a
+/// view sharing the buffer of the input and nothing is copied. Otherwise, the bytes are copied
+/// into WebAssembly memory, decoded, and copied into a new typed array. The `wasm { ... }` form
+/// of [`bytes_to_type!`](crate::bytes_to_type) generates `#[wasm_bindgen]` functions calling this.
+///
+/// # Errors
+///
+/// Returns a JavaScript `Error` with the message of
+/// [`Error::LengthMismatch`](crate::Error::LengthMismatch) if the length of `bytes` is not a
+/// multiple of the size of `T`.
+pub fn bytes_to_typed_array<T: TypedArrayElement>(bytes: &Uint8Array) -> Result<T::Array, JsError> {
+    bytes_to_typed_array_with::<T>(bytes, Endianness::NATIVE)
+}
+
+/// Converts a JavaScript `Uint8Array` of little-endian elements into the typed array of `T`,
+/// available with the `wasm` feature.
+///
+/// # Errors
+///
+/// See [`bytes_to_typed_array`].
+pub fn bytes_to_typed_array_le<T: TypedArrayElement>(
+    bytes: &Uint8Array,
+) -> Result<T::Array, JsError> {
+    bytes_to_typed_array_with::<T>(bytes, Endianness::Little)
+}
+
+/// Converts a JavaScript `Uint8Array` of big-endian elements into the typed array of `T`,
+/// available with the `wasm` feature.
+///
+/// Big-endian elements are always copied, since typed arrays cannot view them in place.
+///
+/// # Errors
+///
+/// See [`bytes_to_typed_array`].
+pub fn bytes_to_typed_array_be<T: TypedArrayElement>(
+    bytes: &Uint8Array,
+) -> Result<T::Array, JsError> {
+    bytes_to_typed_array_with::<T>(bytes, Endianness::Big)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn it_views_aligned_little_endian_input_in_place() {
+        assert_eq!(view_length::<u32>(8, 4, Endianness::Little), Some(2));
+        assert_eq!(view_length::<f64>(0, 0, Endianness::Little), Some(0));
+        assert_eq!(view_length::<u32>(8, 2, Endianness::Little), None);
+        assert_eq!(view_length::<u32>(6, 0, Endianness::Little), None);
+        assert_eq!(view_length::<u16>(4, 0, Endianness::Big), None);
+    }
+
+    #[test]
+    fn it_decodes_input_that_cannot_be_viewed() {
+        assert_eq!(
+            decode_copy::<u16>(&[0, 1, 0, 2], Endianness::Big).unwrap(),
+            vec![1, 2]
+        );
+        assert_eq!(
+            decode_copy::<i64>(&[0; 4], Endianness::Little),
+            Err(Error::length_mismatch(4, 8))
+        );
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    mod bindings {
+        use wasm_bindgen_test::wasm_bindgen_test;
+
+        use super::super::*;
+
+        #[wasm_bindgen_test]
+        fn it_views_typed_arrays_in_place() {
+            let bytes = Uint8Array::from(&[1, 0, 2, 0][..]);
+            let values = bytes_to_typed_array_le::<u16>(&bytes).unwrap();
+
+            assert_eq!(values.to_vec(), [1, 2]);
+            assert_eq!(values.buffer(), bytes.buffer());
+        }
+
+        #[wasm_bindgen_test]
+        fn it_copies_big_endian_typed_arrays() {
+            let bytes = Uint8Array::from(&[0, 0, 0, 1, 0, 0, 0, 2][..]);
+            let values = bytes_to_typed_array_be::<i32>(&bytes).unwrap();
+
+            assert_eq!(values.to_vec(), [1, 2]);
+            assert_ne!(values.buffer(), bytes.buffer());
+            assert!(bytes_to_typed_array::<f32>(&bytes.subarray(0, 3)).is_err());
+        }
+    }
+}
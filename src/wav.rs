@@ -0,0 +1,341 @@
+//! Decoding of WAV files, available with the `wav` feature.
+//!
+//! A WAV file is a RIFF container holding a `fmt ` chunk that describes the samples and a `data`
+//! chunk that holds them, along with optional chunks such as metadata. The functions of this
+//! module walk the chunks and decode the samples of PCM and IEEE float files, either as typed
+//! integers with [`decode`] or as normalized `f32` samples through the [`audio`](crate::audio)
+//! module.
+//!
+//! # Example
+//!
+//! ```
+//! use bytes_to_type::{audio, wav};
+//!
+//! # let mut file = b"RIFF\x2C\x00\x00\x00WAVEfmt \x10\x00\x00\x00".to_vec();
+//! # file.extend_from_slice(&[1, 0, 2, 0, 0x44, 0xAC, 0, 0, 0x10, 0xB1, 2, 0, 4, 0, 16, 0]);
+//! # file.extend_from_slice(b"data\x08\x00\x00\x00");
+//! # file.extend_from_slice(&[0, 0x80, 0xFF, 0x7F, 0, 0, 0, 0]);
+//! let (format, data) = wav::parse(&file).unwrap();
+//! let channels = audio::decode_channels(&data, format.sample_format, format.channels.into());
+//!
+//! assert_eq!(format.sample_rate, 44_100);
+//! assert_eq!(channels.unwrap()[0], vec![-1.0, 0.0]);
+//! ```
+
+use core::fmt;
+
+use crate::audio::SampleFormat;
+use crate::{bytes_to_i24_le, bytes_to_vec_le, ByteCursor, Error, TypedVec};
+
+/// The format tag of integer PCM samples.
+const FORMAT_PCM: u16 = 1;
+/// The format tag of IEEE floating-point samples.
+const FORMAT_IEEE_FLOAT: u16 = 3;
+/// The format tag of files describing their format in an extension of the `fmt ` chunk.
+const FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+
+/// The error type returned by the functions of this module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WavError {
+    /// The samples could not be decoded.
+    Decode(Error),
+    /// The input is not a RIFF `WAVE` file, or one of its chunks is malformed.
+    InvalidFile,
+    /// The file has no chunk with the given identifier, either `fmt ` or `data`.
+    MissingChunk([u8; 4]),
+    /// The samples are neither 8, 16, 24 or 32-bit integers nor 32-bit floats.
+    UnsupportedFormat {
+        /// The format tag of the `fmt ` chunk.
+        format_tag: u16,
+        /// The number of bits per sample.
+        bits_per_sample: u16,
+    },
+}
+
+impl From<Error> for WavError {
+    fn from(error: Error) -> Self {
+        WavError::Decode(error)
+    }
+}
+
+impl fmt::Display for WavError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WavError::Decode(error) => error.fmt(f),
+            WavError::InvalidFile => f.write_str("Invalid WAV file"),
+            WavError::MissingChunk(id) => {
+                write!(
+                    f,
+                    "Missing {:?} chunk",
+                    core::str::from_utf8(id).unwrap_or("?")
+                )
+            }
+            WavError::UnsupportedFormat {
+                format_tag,
+                bits_per_sample,
+            } => write!(
+                f,
+                "Unsupported format {:#x} with {} bits per sample",
+                format_tag, bits_per_sample
+            ),
+        }
+    }
+}
+
+impl core::error::Error for WavError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            WavError::Decode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// The format of the samples of a WAV file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Format {
+    /// The number of interleaved channels.
+    pub channels: u16,
+    /// The number of frames per second.
+    pub sample_rate: u32,
+    /// The encoding of each sample.
+    pub sample_format: SampleFormat,
+}
+
+/// The samples of a WAV file, returned by [`decode`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Wav {
+    /// The format of the samples.
+    pub format: Format,
+    /// The interleaved samples, as [`TypedVec::U8`] for 8-bit files, [`TypedVec::I16`] for 16-bit
+    /// files, [`TypedVec::I32`] for 24 and 32-bit files, and [`TypedVec::F32`] for float files.
+    pub samples: TypedVec,
+}
+
+/// Returns an iterator over the identifier and contents of every chunk in `bytes`.
+fn chunks(bytes: &[u8]) -> impl Iterator<Item = Result<([u8; 4], &[u8]), WavError>> {
+    let mut cursor = ByteCursor::new(bytes);
+
+    core::iter::from_fn(move || {
+        if cursor.is_empty() {
+            return None;
+        }
+
+        let mut read_chunk = || -> crate::Result<([u8; 4], &[u8])> {
+            let id = cursor.read_array::<u8, 4>()?;
+            let len = cursor.read_le::<u32>()? as usize;
+            // Writers that stream audio may leave the length of the last chunk unset.
+            let contents = cursor.read_bytes(len.min(cursor.remaining().len()))?;
+
+            // Chunks are padded to an even length.
+            if len % 2 == 1 && !cursor.is_empty() {
+                cursor.skip(1)?;
+            }
+
+            Ok((id, contents))
+        };
+
+        Some(read_chunk().map_err(|_| WavError::InvalidFile))
+    })
+}
+
+fn parse_format(chunk: &[u8]) -> Result<Format, WavError> {
+    let mut cursor = ByteCursor::new(chunk);
+    let mut read_fields = || -> crate::Result<(u16, u16, u32, u16)> {
+        let format_tag = cursor.read_le::<u16>()?;
+        let channels = cursor.read_le::<u16>()?;
+        let sample_rate = cursor.read_le::<u32>()?;
+        // Skip the byte rate and block alignment, which follow from the other fields.
+        cursor.skip(6)?;
+        let bits_per_sample = cursor.read_le::<u16>()?;
+
+        if format_tag != FORMAT_EXTENSIBLE {
+            return Ok((format_tag, channels, sample_rate, bits_per_sample));
+        }
+
+        // The extension holds its size, the valid bits per sample and the channel mask, followed
+        // by a GUID starting with the actual format tag.
+        cursor.skip(8)?;
+
+        Ok((cursor.read_le()?, channels, sample_rate, bits_per_sample))
+    };
+    let (format_tag, channels, sample_rate, bits_per_sample) =
+        read_fields().map_err(|_| WavError::InvalidFile)?;
+
+    let sample_format = match (format_tag, bits_per_sample) {
+        (FORMAT_PCM, 8) => SampleFormat::U8,
+        (FORMAT_PCM, 16) => SampleFormat::I16Le,
+        (FORMAT_PCM, 24) => SampleFormat::I24Le,
+        (FORMAT_PCM, 32) => SampleFormat::I32Le,
+        (FORMAT_IEEE_FLOAT, 32) => SampleFormat::F32Le,
+        _ => {
+            return Err(WavError::UnsupportedFormat {
+                format_tag,
+                bits_per_sample,
+            })
+        }
+    };
+
+    if channels == 0 {
+        return Err(WavError::InvalidFile);
+    }
+
+    Ok(Format {
+        channels,
+        sample_rate,
+        sample_format,
+    })
+}
+
+/// Walks the chunks of a WAV file, returning the format of the samples along with the contents of
+/// the `data` chunk.
+///
+/// Chunks other than `fmt ` and `data` are skipped.
+///
+/// # Errors
+///
+/// Returns [`WavError::InvalidFile`] if `bytes` is not a well-formed RIFF `WAVE` file,
+/// [`WavError::MissingChunk`] if it has no `fmt ` chunk before the `data` chunk, or no `data`
+/// chunk, and [`WavError::UnsupportedFormat`] for samples other than 8, 16, 24 or 32-bit integers
+/// and 32-bit floats.
+pub fn parse(bytes: &[u8]) -> Result<(Format, &[u8]), WavError> {
+    let body = match bytes {
+        [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', body @ ..] => body,
+        _ => return Err(WavError::InvalidFile),
+    };
+
+    let mut format = None;
+
+    for chunk in chunks(body) {
+        let (id, contents) = chunk?;
+
+        match &id {
+            b"fmt " => format = Some(parse_format(contents)?),
+            b"data" => {
+                let format = format.ok_or(WavError::MissingChunk(*b"fmt "))?;
+
+                return Ok((format, contents));
+            }
+            _ => {}
+        }
+    }
+
+    Err(WavError::MissingChunk(*b"data"))
+}
+
+/// Decodes the samples of a WAV file into a vector of integers or floats matching their format.
+///
+/// # Errors
+///
+/// Returns the errors of [`parse`], or [`WavError::Decode`] wrapping [`Error::LengthMismatch`] if
+/// the `data` chunk doesn't hold a whole number of samples.
+pub fn decode(bytes: &[u8]) -> Result<Wav, WavError> {
+    let (format, data) = parse(bytes)?;
+
+    let samples = match format.sample_format {
+        SampleFormat::U8 => TypedVec::U8(data.to_vec()),
+        SampleFormat::I16Le => TypedVec::I16(bytes_to_vec_le(data)?),
+        SampleFormat::I24Le => TypedVec::I32(bytes_to_i24_le(data)?),
+        SampleFormat::I32Le => TypedVec::I32(bytes_to_vec_le(data)?),
+        SampleFormat::F32Le => TypedVec::F32(bytes_to_vec_le(data)?),
+        _ => unreachable!("WAV samples are little-endian"),
+    };
+
+    Ok(Wav { format, samples })
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::*;
+
+    fn wav(format: &[u8], chunks: &[(&[u8; 4], &[u8])]) -> Vec<u8> {
+        let mut body = b"WAVEfmt ".to_vec();
+        body.extend_from_slice(&(format.len() as u32).to_le_bytes());
+        body.extend_from_slice(format);
+
+        for (id, contents) in chunks {
+            body.extend_from_slice(*id);
+            body.extend_from_slice(&(contents.len() as u32).to_le_bytes());
+            body.extend_from_slice(contents);
+            if contents.len() % 2 == 1 {
+                body.push(0);
+            }
+        }
+
+        let mut file = b"RIFF".to_vec();
+        file.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        file.extend_from_slice(&body);
+        file
+    }
+
+    fn pcm_format(format_tag: u16, channels: u16, bits_per_sample: u16) -> Vec<u8> {
+        let block_align = channels * bits_per_sample / 8;
+        let mut format = Vec::new();
+        format.extend_from_slice(&format_tag.to_le_bytes());
+        format.extend_from_slice(&channels.to_le_bytes());
+        format.extend_from_slice(&8000u32.to_le_bytes());
+        format.extend_from_slice(&(8000 * u32::from(block_align)).to_le_bytes());
+        format.extend_from_slice(&block_align.to_le_bytes());
+        format.extend_from_slice(&bits_per_sample.to_le_bytes());
+        format
+    }
+
+    #[test]
+    fn it_decodes_samples_after_skipping_other_chunks() {
+        let file = wav(
+            &pcm_format(FORMAT_PCM, 1, 24),
+            &[
+                (b"LIST", &[1, 2, 3]),
+                (b"data", &[0xFF, 0xFF, 0xFF, 1, 0, 0]),
+            ],
+        );
+        let wav = decode(&file).unwrap();
+
+        assert_eq!(
+            wav.format,
+            Format {
+                channels: 1,
+                sample_rate: 8000,
+                sample_format: SampleFormat::I24Le
+            }
+        );
+        assert_eq!(wav.samples, TypedVec::I32(vec![-1, 1]));
+    }
+
+    #[test]
+    fn it_reads_the_format_of_extensible_files() {
+        let mut format = pcm_format(FORMAT_EXTENSIBLE, 2, 32);
+        format.extend_from_slice(&[22, 0, 32, 0, 3, 0, 0, 0]);
+        format.extend_from_slice(&FORMAT_IEEE_FLOAT.to_le_bytes());
+        format.extend_from_slice(&[0; 14]);
+        let file = wav(&format, &[(b"data", &1.0f32.to_le_bytes())]);
+
+        assert_eq!(decode(&file).unwrap().samples, TypedVec::F32(vec![1.0]));
+    }
+
+    #[test]
+    fn it_returns_errors_on_invalid_files() {
+        assert_eq!(parse(b"RIFX"), Err(WavError::InvalidFile));
+        assert_eq!(
+            parse(&wav(&pcm_format(FORMAT_PCM, 1, 16), &[])),
+            Err(WavError::MissingChunk(*b"data"))
+        );
+        assert_eq!(
+            parse(&wav(
+                &pcm_format(FORMAT_IEEE_FLOAT, 1, 64),
+                &[(b"data", &[])]
+            )),
+            Err(WavError::UnsupportedFormat {
+                format_tag: FORMAT_IEEE_FLOAT,
+                bits_per_sample: 64
+            })
+        );
+        assert_eq!(
+            decode(&wav(&pcm_format(FORMAT_PCM, 1, 16), &[(b"data", &[0; 3])])),
+            Err(WavError::Decode(Error::length_mismatch(3, 2)))
+        );
+    }
+}
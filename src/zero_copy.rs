@@ -0,0 +1,123 @@
+use alloc::vec::Vec;
+
+use zerocopy::{CastError, FromBytes, Immutable, IntoBytes};
+
+use crate::convert::{check_length, is_aligned};
+use crate::{Error, Result};
+
+/// Converts a byte slice into a vector of any [`zerocopy::FromBytes`] type in native byte order,
+/// available with the `zerocopy` feature.
+///
+/// The layout of `T` is validated at compile time by zerocopy's derives, which makes this suitable
+/// for your own record types. Aligned input is borrowed through zerocopy and copied in one go, and
+/// unaligned input is read element by element.
+///
+/// # Example
+///
+/// ```
+/// use bytes_to_type::zerocopy_to_vec;
+/// use zerocopy::{FromBytes, Immutable, IntoBytes, KnownLayout};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout)]
+/// #[repr(C)]
+/// struct Sample {
+///     left: i16,
+///     right: i16,
+/// }
+///
+/// let bytes = Sample { left: 1, right: -1 }.as_bytes().to_vec();
+///
+/// assert_eq!(zerocopy_to_vec::<Sample>(&bytes).unwrap(), vec![Sample { left: 1, right: -1 }]);
+/// ```
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`.
+pub fn zerocopy_to_vec<T: FromBytes + Immutable + Copy>(bytes: &[u8]) -> Result<Vec<T>> {
+    check_length::<T>(bytes)?;
+
+    if is_aligned::<T>(bytes) {
+        return zerocopy_to_slice(bytes).map(<[T]>::to_vec);
+    }
+
+    Ok(bytes
+        .chunks_exact(core::mem::size_of::<T>())
+        .map(|chunk| T::read_from_bytes(chunk).expect("chunk has the size of T"))
+        .collect())
+}
+
+/// Reinterprets a byte slice as a slice of any [`zerocopy::FromBytes`] type, without copying,
+/// available with the `zerocopy` feature.
+///
+/// # Errors
+///
+/// Returns [`Error::LengthMismatch`] if the length of `bytes` is not a multiple of the size of `T`,
+/// or [`Error::Misaligned`] if `bytes` is not properly aligned for `T`.
+pub fn zerocopy_to_slice<T: FromBytes + Immutable>(bytes: &[u8]) -> Result<&[T]> {
+    <[T]>::ref_from_bytes(bytes).map_err(|error| match error {
+        CastError::Alignment(_) => Error::Misaligned {
+            align: core::mem::align_of::<T>(),
+        },
+        _ => Error::length_mismatch(bytes.len(), core::mem::size_of::<T>()),
+    })
+}
+
+/// Borrows a slice of any [`zerocopy::IntoBytes`] type as bytes, available with the `zerocopy`
+/// feature.
+pub fn zerocopy_as_bytes<T: IntoBytes + Immutable>(values: &[T]) -> &[u8] {
+    values.as_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use zerocopy::KnownLayout;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, FromBytes, IntoBytes, Immutable, KnownLayout)]
+    #[repr(C)]
+    struct Point {
+        x: u16,
+        y: u16,
+    }
+
+    #[test]
+    fn it_converts_bytes_to_zerocopy_types() {
+        let points = vec![Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+        let bytes = zerocopy_as_bytes(&points);
+
+        assert_eq!(zerocopy_to_vec::<Point>(bytes).unwrap(), points);
+        assert_eq!(zerocopy_to_slice::<Point>(bytes).unwrap(), &points[..]);
+    }
+
+    #[test]
+    fn it_converts_unaligned_bytes_to_zerocopy_types() {
+        let bytes = [0, 1, 0, 2, 0];
+
+        for offset in 0..2 {
+            let window = &bytes[offset..offset + 4];
+            let expected = Point {
+                x: u16::from_ne_bytes([window[0], window[1]]),
+                y: u16::from_ne_bytes([window[2], window[3]]),
+            };
+
+            assert_eq!(zerocopy_to_vec::<Point>(window).unwrap(), vec![expected]);
+        }
+    }
+
+    #[test]
+    fn it_returns_errors_for_invalid_input() {
+        let points = [Point { x: 1, y: 2 }, Point { x: 3, y: 4 }];
+        let bytes = zerocopy_as_bytes(&points);
+
+        assert_eq!(
+            zerocopy_to_vec::<Point>(&bytes[..6]),
+            Err(Error::length_mismatch(6, 4))
+        );
+        assert_eq!(
+            zerocopy_to_slice::<Point>(&bytes[1..5]),
+            Err(Error::Misaligned { align: 2 })
+        );
+    }
+}